@@ -3,7 +3,10 @@ use std::process::ExitCode;
 use clap::Parser;
 use miette::Result;
 
-use leafc_cli::LeafcCli;
+use leafc_cli::{
+    LeafcCli,
+    LeafcCommand,
+};
 
 pub struct Leafc;
 
@@ -57,6 +60,9 @@ impl Leafc {
         // parse the command line arguments
         let cli = LeafcCli::parse();
 
+        // emit diagnostics in the user's language
+        leafc_cfg::lang::set_language(leafc_cfg::lang::default_language());
+
         // mutate the settings based on the command line arguments
 
         // leafc_cfg::update_settings_via_cli(&mut settings, &cli);
@@ -66,8 +72,10 @@ impl Leafc {
 
         // log the settings
 
-        // run the driver or repl as appropriate
-        if cli.sources().is_empty() {
+        // run the command, driver or repl as appropriate
+        if let Some(LeafcCommand::Translate(args)) = cli.command() {
+            leafc_driver::translate_run(args)?;
+        } else if cli.sources().is_empty() {
             leafc_repl::entry(&cli)?;
         } else {
             leafc_driver::batch_run(&cli)?;
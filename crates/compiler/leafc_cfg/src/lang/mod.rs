@@ -91,6 +91,25 @@ pub fn init() -> LanguageConfiguration {
     LanguageConfiguration::new()
 }
 
+/// Sets the **language** that the compiler emits its diagnostics and other
+/// messages (e.g. error messages) in. Messages which haven't been translated
+/// into the given language are emitted in **English**.
+///
+/// # Examples
+///
+/// ```rust
+/// use leafc_cfg::lang::{
+///     set_language,
+///     LanguageKind,
+/// };
+///
+/// // Diagnostics are now emitted in German (e.g. `Lexikalischer Fehler`).
+/// set_language(LanguageKind::German);
+/// ```
+pub fn set_language(language: LanguageKind) {
+    rust_i18n::set_locale(language.tag().unwrap_or("en"));
+}
+
 /// Stores the **language configuration** for the project. This includes the
 /// various **languages** that the project is able to support when compiling
 /// from source code, as well as the **current language** that the project is
@@ -247,45 +266,237 @@ pub enum LanguageKind {
     Spanish,
 
     /// The **French** language.
+    ///
+    /// This includes the following **language tags**:
+    ///
+    /// | Language Tag | Description |
+    /// |--------------|-------------|
+    /// | `fr`         | _French_ |
+    /// | `fr-BE`      | _French (Belgium)_ |
+    /// | `fr-CA`      | _French (Canada)_ |
+    /// | `fr-CH`      | _French (Switzerland)_ |
+    /// | `fr-FR`      | _French (France)_ |
+    #[strum(
+        serialize = "fr",
+        serialize = "fr-BE",
+        serialize = "fr-CA",
+        serialize = "fr-CH",
+        serialize = "fr-FR",
+        serialize = "French"
+    )]
     French,
 
     /// The **German** language.
+    ///
+    /// This includes the following **language tags**:
+    ///
+    /// | Language Tag | Description |
+    /// |--------------|-------------|
+    /// | `de`         | _German_ |
+    /// | `de-AT`      | _German (Austria)_ |
+    /// | `de-CH`      | _German (Switzerland)_ |
+    /// | `de-DE`      | _German (Germany)_ |
+    #[strum(
+        serialize = "de",
+        serialize = "de-AT",
+        serialize = "de-CH",
+        serialize = "de-DE",
+        serialize = "German"
+    )]
     German,
 
     /// The **Portuguese** language.
+    ///
+    /// This includes the following **language tags**:
+    ///
+    /// | Language Tag | Description |
+    /// |--------------|-------------|
+    /// | `pt`         | _Portuguese_ |
+    /// | `pt-BR`      | _Portuguese (Brazil)_ |
+    /// | `pt-PT`      | _Portuguese (Portugal)_ |
+    #[strum(
+        serialize = "pt",
+        serialize = "pt-BR",
+        serialize = "pt-PT",
+        serialize = "Portuguese"
+    )]
     Portuguese,
 
     /// The **Italian** language.
+    ///
+    /// This includes the following **language tags**:
+    ///
+    /// | Language Tag | Description |
+    /// |--------------|-------------|
+    /// | `it`         | _Italian_ |
+    /// | `it-CH`      | _Italian (Switzerland)_ |
+    /// | `it-IT`      | _Italian (Italy)_ |
+    #[strum(
+        serialize = "it",
+        serialize = "it-CH",
+        serialize = "it-IT",
+        serialize = "Italian"
+    )]
     Italian,
 
     /// The **Dutch** language.
+    ///
+    /// This includes the following **language tags**:
+    ///
+    /// | Language Tag | Description |
+    /// |--------------|-------------|
+    /// | `nl`         | _Dutch_ |
+    /// | `nl-BE`      | _Dutch (Belgium)_ |
+    /// | `nl-NL`      | _Dutch (Netherlands)_ |
+    #[strum(
+        serialize = "nl",
+        serialize = "nl-BE",
+        serialize = "nl-NL",
+        serialize = "Dutch"
+    )]
     Dutch,
 
     /// The **Swedish** language.
+    ///
+    /// This includes the following **language tags**:
+    ///
+    /// | Language Tag | Description |
+    /// |--------------|-------------|
+    /// | `sv`         | _Swedish_ |
+    /// | `sv-FI`      | _Swedish (Finland)_ |
+    /// | `sv-SE`      | _Swedish (Sweden)_ |
+    #[strum(
+        serialize = "sv",
+        serialize = "sv-FI",
+        serialize = "sv-SE",
+        serialize = "Swedish"
+    )]
     Swedish,
 
     /// The **Danish** language.
+    ///
+    /// This includes the following **language tags**:
+    ///
+    /// | Language Tag | Description |
+    /// |--------------|-------------|
+    /// | `da`         | _Danish_ |
+    /// | `da-DK`      | _Danish (Denmark)_ |
+    #[strum(
+        serialize = "da",
+        serialize = "da-DK",
+        serialize = "Danish"
+    )]
     Danish,
 
     /// The **Norwegian** language.
+    ///
+    /// This includes the following **language tags**:
+    ///
+    /// | Language Tag | Description |
+    /// |--------------|-------------|
+    /// | `no`         | _Norwegian_ |
+    /// | `nb`         | _Norwegian (Bokmål)_ |
+    /// | `nn`         | _Norwegian (Nynorsk)_ |
+    #[strum(
+        serialize = "no",
+        serialize = "nb",
+        serialize = "nn",
+        serialize = "Norwegian"
+    )]
     Norwegian,
 
     /// The **Finnish** language.
+    ///
+    /// This includes the following **language tags**:
+    ///
+    /// | Language Tag | Description |
+    /// |--------------|-------------|
+    /// | `fi`         | _Finnish_ |
+    /// | `fi-FI`      | _Finnish (Finland)_ |
+    #[strum(
+        serialize = "fi",
+        serialize = "fi-FI",
+        serialize = "Finnish"
+    )]
     Finnish,
 
     /// The **Russian** language.
+    ///
+    /// This includes the following **language tags**:
+    ///
+    /// | Language Tag | Description |
+    /// |--------------|-------------|
+    /// | `ru`         | _Russian_ |
+    /// | `ru-RU`      | _Russian (Russia)_ |
+    #[strum(
+        serialize = "ru",
+        serialize = "ru-RU",
+        serialize = "Russian"
+    )]
     Russian,
 
     /// The **Japanese** language.
+    ///
+    /// This includes the following **language tags**:
+    ///
+    /// | Language Tag | Description |
+    /// |--------------|-------------|
+    /// | `ja`         | _Japanese_ |
+    /// | `ja-JP`      | _Japanese (Japan)_ |
+    #[strum(
+        serialize = "ja",
+        serialize = "ja-JP",
+        serialize = "Japanese"
+    )]
     Japanese,
 
     /// The **Chinese** language.
+    ///
+    /// This includes the following **language tags**:
+    ///
+    /// | Language Tag | Description |
+    /// |--------------|-------------|
+    /// | `zh`         | _Chinese_ |
+    /// | `zh-CN`      | _Chinese (China)_ |
+    /// | `zh-TW`      | _Chinese (Taiwan)_ |
+    #[strum(
+        serialize = "zh",
+        serialize = "zh-CN",
+        serialize = "zh-TW",
+        serialize = "Chinese"
+    )]
     Chinese,
 
     /// The **Korean** language.
+    ///
+    /// This includes the following **language tags**:
+    ///
+    /// | Language Tag | Description |
+    /// |--------------|-------------|
+    /// | `ko`         | _Korean_ |
+    /// | `ko-KR`      | _Korean (South Korea)_ |
+    #[strum(
+        serialize = "ko",
+        serialize = "ko-KR",
+        serialize = "Korean"
+    )]
     Korean,
 
     /// The **Swahili** language.
+    ///
+    /// This includes the following **language tags**:
+    ///
+    /// | Language Tag | Description |
+    /// |--------------|-------------|
+    /// | `sw`         | _Swahili_ |
+    /// | `sw-KE`      | _Swahili (Kenya)_ |
+    /// | `sw-TZ`      | _Swahili (Tanzania)_ |
+    #[strum(
+        serialize = "sw",
+        serialize = "sw-KE",
+        serialize = "sw-TZ",
+        serialize = "Swahili"
+    )]
     Swahili,
 
     /// Any language that is **currently supported** by the compiler.
@@ -293,6 +504,27 @@ pub enum LanguageKind {
 }
 
 impl LanguageKind {
+    /// All of the **spoken** languages (i.e. every language but `Any`), in
+    /// declaration order.
+    pub const SPOKEN: [LanguageKind; 16] = [
+        LanguageKind::English,
+        LanguageKind::Spanish,
+        LanguageKind::French,
+        LanguageKind::German,
+        LanguageKind::Portuguese,
+        LanguageKind::Italian,
+        LanguageKind::Dutch,
+        LanguageKind::Swedish,
+        LanguageKind::Danish,
+        LanguageKind::Norwegian,
+        LanguageKind::Finnish,
+        LanguageKind::Russian,
+        LanguageKind::Japanese,
+        LanguageKind::Chinese,
+        LanguageKind::Korean,
+        LanguageKind::Swahili,
+    ];
+
     /// Returns `true` if the language is **spoken** (i.e. it is a **human
     /// language** rather than `Any`).
     /// Otherwise, returns `false`.
@@ -317,6 +549,31 @@ impl LanguageKind {
             LanguageKind::Any => false,
         }
     }
+
+    /// Returns the primary **language tag** of the language (e.g. `fr` for
+    /// French), as used by a `lang` attribute (e.g. `#![lang(fr)]`).
+    /// Returns `None` for `Any`.
+    pub fn tag(&self) -> Option<&'static str> {
+        match self {
+            LanguageKind::English => Some("en"),
+            LanguageKind::Spanish => Some("es"),
+            LanguageKind::French => Some("fr"),
+            LanguageKind::German => Some("de"),
+            LanguageKind::Portuguese => Some("pt"),
+            LanguageKind::Italian => Some("it"),
+            LanguageKind::Dutch => Some("nl"),
+            LanguageKind::Swedish => Some("sv"),
+            LanguageKind::Danish => Some("da"),
+            LanguageKind::Norwegian => Some("no"),
+            LanguageKind::Finnish => Some("fi"),
+            LanguageKind::Russian => Some("ru"),
+            LanguageKind::Japanese => Some("ja"),
+            LanguageKind::Chinese => Some("zh"),
+            LanguageKind::Korean => Some("ko"),
+            LanguageKind::Swahili => Some("sw"),
+            LanguageKind::Any => None,
+        }
+    }
 }
 
 /// Represents the **English** language given the current [**language of
@@ -407,6 +664,15 @@ mod language_test_suite {
     #[case::english_new_zealand(LanguageKind::English, "en-NZ")]
     #[case::english_united_states(LanguageKind::English, "en-US")]
     #[case::english_south_africa(LanguageKind::English, "en-ZA")]
+    #[case::spanish(LanguageKind::Spanish, "es")]
+    #[case::french(LanguageKind::French, "fr")]
+    #[case::french_canada(LanguageKind::French, "fr-CA")]
+    #[case::french_name(LanguageKind::French, "French")]
+    #[case::german(LanguageKind::German, "de")]
+    #[case::norwegian_bokmal(LanguageKind::Norwegian, "nb")]
+    #[case::japanese(LanguageKind::Japanese, "ja")]
+    #[case::chinese_taiwan(LanguageKind::Chinese, "zh-TW")]
+    #[case::swahili(LanguageKind::Swahili, "sw")]
     #[case::english_default(LanguageKind::English, "foo bar baz")]
     fn smoke_lang_string(#[case] language: LanguageKind, #[case] tag: &str) {
         let language_tag: LanguageKind = LanguageKind::from_str(tag).unwrap_or_default();
@@ -436,6 +702,20 @@ mod language_test_suite {
         ]);
     }
 
+    #[test]
+    fn smoke_spoken_languages() {
+        assert_eq!(LanguageKind::SPOKEN.len(), LanguageKind::VARIANTS.len() - 1);
+        assert!(LanguageKind::SPOKEN.iter().all(LanguageKind::is_spoken));
+    }
+
+    #[test]
+    fn smoke_language_tags() {
+        for lang in LanguageKind::SPOKEN {
+            assert_eq!(LanguageKind::from_str(lang.tag().unwrap()), Ok(lang));
+        }
+        assert_eq!(LanguageKind::Any.tag(), None);
+    }
+
     #[test]
     fn test_config_builder() {
         let lang_cfg =
@@ -1,14 +1,21 @@
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{
+    Args,
+    Parser,
+    Subcommand,
+};
 use getset::Getters;
-use leafc_cfg::settings::{
-    meta::{
-        pkg::EXE_NAME,
-        version::LEAFC_VERSION,
+use leafc_cfg::{
+    lang::LanguageKind,
+    settings::{
+        meta::{
+            pkg::EXE_NAME,
+            version::LEAFC_VERSION,
+        },
+        EmitKind,
+        LogLevel,
     },
-    EmitKind,
-    LogLevel,
 };
 
 /// # **Leafc**, an Experimental Compiler
@@ -23,6 +30,10 @@ use leafc_cfg::settings::{
 #[clap(bin_name = EXE_NAME)]
 #[getset(get = "pub")]
 pub struct LeafcCli {
+    /// The command to run instead of compiling the source files.
+    #[clap(subcommand)]
+    pub command: Option<LeafcCommand>,
+
     /// Names of the source files to compile.
     pub sources: Vec<PathBuf>,
 
@@ -77,6 +88,46 @@ impl LeafcCli {
     }
 }
 
+/// The **commands** that can be run instead of compiling source files.
+#[derive(Subcommand, Debug, PartialEq, Eq, Hash)]
+pub enum LeafcCommand {
+    /// Translate the keywords of a source file into another spoken language.
+    ///
+    /// Identifiers, literals, comments and whitespace are left as they are,
+    /// and the translated source is printed to stdout
+    /// (e.g. `leafc translate --to fr hello_world.leaf`).
+    Translate(TranslateArgs),
+}
+
+/// The arguments of the `translate` command.
+#[derive(Args, Debug, Getters, PartialEq, Eq, Hash)]
+#[getset(get = "pub")]
+pub struct TranslateArgs {
+    /// Name of the source file to translate.
+    pub source: PathBuf,
+
+    /// The spoken language to translate into (e.g. `fr` or `French`).
+    #[clap(short, long, value_parser = parse_spoken_language)]
+    pub to: LanguageKind,
+
+    /// The spoken language to translate from (e.g. `en` or `English`).
+    /// [default: the language declared or used by the source file]
+    #[clap(short, long, required = false)]
+    pub from: Option<LanguageKind>,
+}
+
+/// Parses a **spoken** language (i.e. any language but `Any`, which has no
+/// spelling of the keywords of its own).
+fn parse_spoken_language(language: &str) -> Result<LanguageKind, String> {
+    match language.parse::<LanguageKind>() {
+        Ok(kind) if kind.is_spoken() => Ok(kind),
+        _ => Err(format!(
+            "`{language}` isn't a spoken language, expected one of {}",
+            LanguageKind::SPOKEN.map(|kind| kind.to_string()).join(", ")
+        )),
+    }
+}
+
 // TODO: refactor these
 
 // Emit tokens.
@@ -96,3 +147,37 @@ impl LeafcCli {
 // syntactic structure** of the source file.
 // #[clap(short = 'p', long, value_parser, required = false, default_value_t =
 // false)] pub debug_parser: bool,
+
+#[cfg(test)]
+mod cli_test_suite {
+    use clap::Parser;
+    use leafc_cfg::lang::LanguageKind;
+    use rstest::rstest;
+
+    use super::{
+        LeafcCli,
+        LeafcCommand,
+    };
+
+    #[rstest]
+    #[case("fr", Some(LanguageKind::French))]
+    #[case("German", Some(LanguageKind::German))]
+    #[case("any", None)]
+    #[case("Any", None)]
+    #[case("xx", None)]
+    fn test_translate_to_spoken_languages(
+        #[case] to: &str,
+        #[case] expected: Option<LanguageKind>,
+    ) {
+        let cli = LeafcCli::try_parse_from(["leafc", "translate", "--to", to, "hello_world.leaf"]);
+
+        match (cli, expected) {
+            (Ok(cli), Some(expected)) => {
+                let Some(LeafcCommand::Translate(args)) = cli.command else { unreachable!() };
+                assert_eq!(args.to, expected);
+            }
+            (Err(error), None) => assert!(error.to_string().contains("spoken language"), "{error}"),
+            (cli, expected) => panic!("{to}: expected {expected:?}, got {cli:?}"),
+        }
+    }
+}
@@ -4,8 +4,7 @@ use smol_str::SmolStr;
 use strum_macros::EnumCount as EnumCountMacro;
 use thiserror::Error;
 
-/// Prefix appended to all `ConfigError` messages.
-pub const CFG_ERROR_PREFIX: &str = "Config Error";
+use crate::locale;
 
 /// All possible errors that can occur during the compiler's
 /// **configuration** process.
@@ -34,19 +33,16 @@ pub enum CfgError {
     /// ```
     #[error(
         "{} {} {}{} {}",
-        CFG_ERROR_PREFIX.blue(),
+        locale::prefix("leafc::cfg").blue(),
         "-".black(),
-        "Initialization failure: ".red(),
+        locale::message("leafc::cfg::initialization_failure").red(),
         ":".black(),
         .0.yellow().italic()
     )]
     #[diagnostic(
         code(leafc::cfg::initialization_failure),
         url(docsrs),
-        help(
-            "The current compiler configuration is invalid. This is likely a bug in the compiler. \
-             Please report this issue to the developers."
-        )
+        help("{}", locale::help("leafc::cfg::initialization_failure"))
     )]
     Initialization(SmolStr),
 }
@@ -4,8 +4,7 @@ use smol_str::SmolStr;
 use strum_macros::EnumCount as EnumCountMacro;
 use thiserror::Error;
 
-/// Prefix appended to all `CliError` messages.
-pub const CLI_ERROR_PREFIX: &str = "CLI Error";
+use crate::locale;
 
 /// All possible errors that can occur during the **lexical analysis** phase of
 /// the compiler.
@@ -13,11 +12,11 @@ pub const CLI_ERROR_PREFIX: &str = "CLI Error";
 /// # Examples
 ///
 /// ```rust
-/// use leafc_diagnostics::errors::lexer::LexicalError;
+/// use leafc_diagnostics::errors::cli::CliError;
 /// use strum::EnumCount;
 ///
-/// // There are x variants of the `LexicalError` enum.
-/// assert_eq!(1, LexicalError::COUNT);
+/// // There are x variants of the `CliError` enum.
+/// assert_eq!(1, CliError::COUNT);
 /// ```
 #[derive(Debug, Error, EnumCountMacro, Diagnostic, Clone)]
 pub enum CliError {
@@ -34,19 +33,16 @@ pub enum CliError {
     /// ```
     #[error(
         "{} {} {}{} {}",
-        CLI_ERROR_PREFIX.blue(),
+        locale::prefix("leafc::cli").blue(),
         "-".black(),
-        "File not found: ".red(),
+        locale::message("leafc::cli::file_not_found").red(),
         ":".black(),
         .0.yellow().italic()
     )]
     #[diagnostic(
         code(leafc::cli::file_not_found),
         url(docsrs),
-        help(
-            "The file could not be found. Please check that the file exists and that you have \
-             permission to access it."
-        )
+        help("{}", locale::help("leafc::cli::file_not_found"))
     )]
     FileNotFound(SmolStr),
 }
@@ -4,8 +4,7 @@ use smol_str::SmolStr;
 use strum_macros::EnumCount as EnumCountMacro;
 use thiserror::Error;
 
-/// Prefix appended to all `DriverError` messages.
-pub const DRIVER_ERROR_PREFIX: &str = "Driver Error";
+use crate::locale;
 
 /// All possible errors that can occur during the execution of the compiler.
 /// These errors are **not** related to the **source code** itself, but rather
@@ -35,19 +34,16 @@ pub enum DriverError {
     /// ```
     #[error(
         "{} {} {}{} {}",
-        DRIVER_ERROR_PREFIX.blue(),
+        locale::prefix("leafc::driver").blue(),
         "-".black(),
-        "Initialization failure: ".red(),
+        locale::message("leafc::driver::initialization_failure").red(),
         ":".black(),
         .0.yellow().italic()
     )]
     #[diagnostic(
         code(leafc::driver::initialization_failure),
         url(docsrs),
-        help(
-            "The driver failed to initialize. This is likely a bug in the compiler. Please report \
-             this issue to the developers."
-        )
+        help("{}", locale::help("leafc::driver::initialization_failure"))
     )]
     Initialization(SmolStr),
 }
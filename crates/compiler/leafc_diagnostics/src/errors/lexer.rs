@@ -4,8 +4,7 @@ use smol_str::SmolStr;
 use strum_macros::EnumCount as EnumCountMacro;
 use thiserror::Error;
 
-/// Prefix appended to all `LexicalError` messages.
-pub const LEXICAL_ERROR_PREFIX: &str = "Lexical Error";
+use crate::locale;
 
 /// All possible errors that can occur during the **lexical analysis** phase of
 /// the compiler.
@@ -17,9 +16,9 @@ pub const LEXICAL_ERROR_PREFIX: &str = "Lexical Error";
 /// use strum::EnumCount;
 ///
 /// // There are x variants of the `LexicalError` enum.
-/// assert_eq!(1, LexicalError::COUNT);
+/// assert_eq!(9, LexicalError::COUNT);
 /// ```
-#[derive(Debug, Error, EnumCountMacro, Diagnostic, Clone)]
+#[derive(Debug, Error, EnumCountMacro, Diagnostic, Clone, PartialEq, Eq, Hash)]
 pub enum LexicalError {
     /// This error is returned when an **unknown token** is encountered
     /// during **lexical analysis**.
@@ -34,18 +33,442 @@ pub enum LexicalError {
     /// ```
     #[error(
         "{} {} {}{} {}",
-        LEXICAL_ERROR_PREFIX.blue(),
+        locale::prefix("leafc::lexer").blue(),
         "-".black(),
-        "Unknown token".red(),
+        locale::message("leafc::lexer::unknown_token").red(),
         ":".black(),
         .0.yellow().italic()
     )]
     #[diagnostic(
         code(leafc::lexer::unknown_token),
         url(docsrs),
-        help(
-            "The token you've entered is not valid (contained within the grammar of the language)."
-        )
+        help("{}", locale::help("leafc::lexer::unknown_token"))
     )]
     UnknownToken(SmolStr),
+
+    /// This error is returned when a **block comment** (e.g. `/* ... */`) is
+    /// never closed, taking the rest of the file with it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::lexer::LexicalError;
+    ///
+    /// // The error is returned when a block comment is never closed.
+    /// let error = LexicalError::UnterminatedBlockComment;
+    /// ```
+    #[error(
+        "{} {} {}",
+        locale::prefix("leafc::lexer").blue(),
+        "-".black(),
+        locale::message("leafc::lexer::unterminated_block_comment").red()
+    )]
+    #[diagnostic(
+        code(leafc::lexer::unterminated_block_comment),
+        url(docsrs),
+        help("{}", locale::help("leafc::lexer::unterminated_block_comment"))
+    )]
+    UnterminatedBlockComment,
+
+    /// This error is returned when an **escape** within a rune or string
+    /// literal is invalid (e.g. `\q` or `\u{D800}`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::lexer::{
+    ///     EscapeError,
+    ///     LexicalError,
+    /// };
+    ///
+    /// // The error is returned for the unknown escape `\q`.
+    /// let error = LexicalError::InvalidEscape(EscapeError::UnknownEscape('q'));
+    /// ```
+    #[error(
+        "{} {} {}{} {}",
+        locale::prefix("leafc::lexer").blue(),
+        "-".black(),
+        locale::message("leafc::lexer::invalid_escape").red(),
+        ":".black(),
+        .0.yellow().italic()
+    )]
+    #[diagnostic(
+        code(leafc::lexer::invalid_escape),
+        url(docsrs),
+        help("{}", locale::help("leafc::lexer::invalid_escape"))
+    )]
+    InvalidEscape(EscapeError),
+
+    /// This error is returned when a **raw string** (e.g. `r#"..."#`) is never
+    /// closed by a `"` followed by as many `#`s as it was opened with.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::lexer::LexicalError;
+    ///
+    /// // The error is returned when a raw string is never closed.
+    /// let error = LexicalError::UnterminatedRawString;
+    /// ```
+    #[error(
+        "{} {} {}",
+        locale::prefix("leafc::lexer").blue(),
+        "-".black(),
+        locale::message("leafc::lexer::unterminated_raw_string").red()
+    )]
+    #[diagnostic(
+        code(leafc::lexer::unterminated_raw_string),
+        url(docsrs),
+        help("{}", locale::help("leafc::lexer::unterminated_raw_string"))
+    )]
+    UnterminatedRawString,
+
+    /// This error is returned when a **format string** (e.g. `"Bonjour,
+    /// {name}!"`) or one of its holes is never closed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::lexer::LexicalError;
+    ///
+    /// // The error is returned when a format string is never closed.
+    /// let error = LexicalError::UnterminatedFormatString;
+    /// ```
+    #[error(
+        "{} {} {}",
+        locale::prefix("leafc::lexer").blue(),
+        "-".black(),
+        locale::message("leafc::lexer::unterminated_format_string").red()
+    )]
+    #[diagnostic(
+        code(leafc::lexer::unterminated_format_string),
+        url(docsrs),
+        help("{}", locale::help("leafc::lexer::unterminated_format_string"))
+    )]
+    UnterminatedFormatString,
+
+    /// This error is returned when the **value** of a numeric literal doesn't
+    /// fit in its type (e.g. `256u8`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::lexer::{
+    ///     LexicalError,
+    ///     NumberError,
+    /// };
+    ///
+    /// // The error is returned for `256u8`.
+    /// let error = LexicalError::InvalidNumber(NumberError::IntegerOutOfRange("u8"));
+    /// ```
+    #[error(
+        "{} {} {}{} {}",
+        locale::prefix("leafc::lexer").blue(),
+        "-".black(),
+        locale::message("leafc::lexer::invalid_number").red(),
+        ":".black(),
+        .0.yellow().italic()
+    )]
+    #[diagnostic(
+        code(leafc::lexer::invalid_number),
+        url(docsrs),
+        help("{}", locale::help("leafc::lexer::invalid_number"))
+    )]
+    InvalidNumber(NumberError),
+
+    /// This error is returned when a **keyword** isn't part of the spoken
+    /// language the rest of the file is written in (e.g. `while` within a file
+    /// written in French).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::lexer::{
+    ///     LanguageSource,
+    ///     LexicalError,
+    ///     MixedLanguagesError,
+    /// };
+    ///
+    /// // The error is returned for the `while` of `fonction f() { while x {} }`.
+    /// let error = LexicalError::MixedLanguages(Box::new(MixedLanguagesError {
+    ///     keyword:           "while".into(),
+    ///     keyword_languages: vec!["en".into()],
+    ///     languages:         vec!["fr".into()],
+    ///     reason:            LanguageSource::Inferred,
+    /// }));
+    /// ```
+    #[error(
+        "{} {} {}{} {}",
+        locale::prefix("leafc::lexer").blue(),
+        "-".black(),
+        locale::message("leafc::lexer::mixed_languages").red(),
+        ":".black(),
+        .0.yellow().italic()
+    )]
+    #[diagnostic(
+        code(leafc::lexer::mixed_languages),
+        url(docsrs),
+        help("{}", locale::help("leafc::lexer::mixed_languages"))
+    )]
+    MixedLanguages(Box<MixedLanguagesError>),
+
+    /// This error is returned when the **language** declared by a `lang`
+    /// attribute (e.g. `#![lang(fr)]`) isn't a known spoken language.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::lexer::LexicalError;
+    ///
+    /// // The error is returned for `#![lang(xx)]`.
+    /// let error = LexicalError::UnknownLanguage("xx".into());
+    /// ```
+    #[error(
+        "{} {} {}{} {}",
+        locale::prefix("leafc::lexer").blue(),
+        "-".black(),
+        locale::message("leafc::lexer::unknown_language").red(),
+        ":".black(),
+        .0.yellow().italic()
+    )]
+    #[diagnostic(
+        code(leafc::lexer::unknown_language),
+        url(docsrs),
+        help("{}", locale::help("leafc::lexer::unknown_language"))
+    )]
+    UnknownLanguage(SmolStr),
+
+    /// This error is returned when a token can't be **translated** into
+    /// another spoken language (i.e. a keyword without a spelling in that
+    /// language, or an identifier which is a keyword in that language).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::lexer::{
+    ///     LexicalError,
+    ///     TranslationError,
+    /// };
+    ///
+    /// // The error is returned for the identifier `pour` when translating into French.
+    /// let error = LexicalError::Untranslatable(TranslationError::KeywordInLanguage {
+    ///     identifier: "pour".into(),
+    ///     language:   "fr".into(),
+    /// });
+    /// ```
+    #[error(
+        "{} {} {}{} {}",
+        locale::prefix("leafc::lexer").blue(),
+        "-".black(),
+        locale::message("leafc::lexer::untranslatable").red(),
+        ":".black(),
+        .0.yellow().italic()
+    )]
+    #[diagnostic(
+        code(leafc::lexer::untranslatable),
+        url(docsrs),
+        help("{}", locale::help("leafc::lexer::untranslatable"))
+    )]
+    Untranslatable(TranslationError),
+}
+
+/// The ways an **escape** within a rune or string literal can be invalid (see
+/// [`LexicalError::InvalidEscape`]).
+///
+/// # Examples
+///
+/// ```rust
+/// use leafc_diagnostics::errors::lexer::EscapeError;
+///
+/// assert_eq!(EscapeError::UnknownEscape('q').to_string(), "unknown escape `\\q`");
+/// ```
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EscapeError {
+    /// A `\` at the very end of the literal, with nothing to escape.
+    #[error("{}", locale::detail("leafc::lexer::invalid_escape", "lone_slash"))]
+    LoneSlash,
+
+    /// A `\` followed by a character which can't be escaped (e.g. `\q`).
+    #[error(
+        "{}",
+        locale::detail("leafc::lexer::invalid_escape", "unknown_escape")
+            .replace("%{escape}", &.0.to_string())
+    )]
+    UnknownEscape(char),
+
+    /// A `\x` which isn't followed by two hexadecimal digits.
+    #[error("{}", locale::detail("leafc::lexer::invalid_escape", "invalid_hex_escape"))]
+    InvalidHexEscape,
+
+    /// A `\x` escape above `\x7F` outside of a byte literal.
+    #[error("{}", locale::detail("leafc::lexer::invalid_escape", "out_of_range_hex_escape"))]
+    OutOfRangeHexEscape,
+
+    /// A `\u` which isn't followed by hexadecimal digits within braces.
+    #[error("{}", locale::detail("leafc::lexer::invalid_escape", "invalid_unicode_escape"))]
+    InvalidUnicodeEscape,
+
+    /// A `\u{}` without any digits.
+    #[error("{}", locale::detail("leafc::lexer::invalid_escape", "empty_unicode_escape"))]
+    EmptyUnicodeEscape,
+
+    /// A `\u{...}` escape which isn't a valid character (i.e. a surrogate, or
+    /// above `10FFFF`).
+    #[error("{}", locale::detail("leafc::lexer::invalid_escape", "out_of_range_unicode_escape"))]
+    OutOfRangeUnicodeEscape,
+
+    /// A `\u{...}` escape within a byte literal.
+    #[error("{}", locale::detail("leafc::lexer::invalid_escape", "unicode_escape_in_byte_literal"))]
+    UnicodeEscapeInByteLiteral,
+
+    /// A non-ASCII character within a byte literal.
+    #[error("{}", locale::detail("leafc::lexer::invalid_escape", "non_ascii_in_byte_literal"))]
+    NonAsciiInByteLiteral,
+}
+
+/// The ways a **numeric literal** can be invalid (see
+/// [`LexicalError::InvalidNumber`]).
+///
+/// # Examples
+///
+/// ```rust
+/// use leafc_diagnostics::errors::lexer::NumberError;
+///
+/// assert_eq!(
+///     NumberError::IntegerOutOfRange("u8").to_string(),
+///     "integer literal is out of range for `u8`"
+/// );
+/// ```
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumberError {
+    /// An integer which is too large for its type (e.g. `256u8`), which is
+    /// `u128` if it has no suffix.
+    #[error(
+        "{}",
+        locale::detail("leafc::lexer::invalid_number", "integer_out_of_range")
+            .replace("%{type}", .0)
+    )]
+    IntegerOutOfRange(&'static str),
+
+    /// A float which is too large for its type (e.g. `1.0e39f32`), which is
+    /// `f64` if it has no suffix.
+    #[error(
+        "{}",
+        locale::detail("leafc::lexer::invalid_number", "float_out_of_range")
+            .replace("%{type}", .0)
+    )]
+    FloatOutOfRange(&'static str),
+}
+
+/// A **keyword** which isn't part of the spoken languages of the file it's in
+/// (see [`LexicalError::MixedLanguages`]), where languages are given by their
+/// tags (e.g. `fr`).
+///
+/// # Examples
+///
+/// ```rust
+/// use leafc_diagnostics::errors::lexer::{
+///     LanguageSource,
+///     MixedLanguagesError,
+/// };
+///
+/// let error = MixedLanguagesError {
+///     keyword:           "for".into(),
+///     keyword_languages: vec!["en".into(), "da".into(), "no".into()],
+///     languages:         vec!["fr".into()],
+///     reason:            LanguageSource::Declared,
+/// };
+/// assert_eq!(
+///     error.to_string(),
+///     "`for` is English, Danish or Norwegian, but the file is declared to be French"
+/// );
+/// ```
+#[derive(Debug, Error, Clone, PartialEq, Eq, Hash)]
+#[error(
+    "{}",
+    locale::detail("leafc::lexer::mixed_languages", .reason.detail())
+        .replace("%{keyword}", .keyword)
+        .replace("%{keyword_languages}", &locale::languages(.keyword_languages.iter().map(SmolStr::as_str)))
+        .replace("%{languages}", &locale::languages(.languages.iter().map(SmolStr::as_str)))
+)]
+pub struct MixedLanguagesError {
+    /// The **keyword** (e.g. `while`).
+    pub keyword:           SmolStr,
+    /// The languages the keyword is **part of**.
+    pub keyword_languages: Vec<SmolStr>,
+    /// The languages of the **file**.
+    pub languages:         Vec<SmolStr>,
+    /// How the languages of the file are **known**.
+    pub reason:            LanguageSource,
+}
+
+/// How the spoken **languages** of a file are known (see
+/// [`MixedLanguagesError`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LanguageSource {
+    /// The file **declares** its language (e.g. `#![lang(fr)]`).
+    Declared,
+    /// The language is **inferred** from the keywords before it.
+    Inferred,
+    /// The languages are the ones the compiler is configured to **support**.
+    Supported,
+}
+
+impl LanguageSource {
+    /// Returns the **detail** of `leafc::lexer::mixed_languages` describing
+    /// the languages of the file.
+    fn detail(&self) -> &'static str {
+        match self {
+            Self::Declared => "declared",
+            Self::Inferred => "inferred",
+            Self::Supported => "supported",
+        }
+    }
+}
+
+/// The ways a token can be **untranslatable** into another spoken language
+/// (see [`LexicalError::Untranslatable`]), which is given by its tag (e.g.
+/// `fr`).
+///
+/// # Examples
+///
+/// ```rust
+/// use leafc_diagnostics::errors::lexer::TranslationError;
+///
+/// let error = TranslationError::KeywordInLanguage {
+///     identifier: "pour".into(),
+///     language:   "fr".into(),
+/// };
+/// assert_eq!(error.to_string(), "`pour` is a keyword in French");
+/// ```
+#[derive(Debug, Error, Clone, PartialEq, Eq, Hash)]
+pub enum TranslationError {
+    /// A keyword without a **spelling** in the language.
+    #[error(
+        "{}",
+        locale::detail("leafc::lexer::untranslatable", "no_spelling")
+            .replace("%{keyword}", .keyword)
+            .replace("%{language}", &locale::language(.language))
+    )]
+    NoSpelling {
+        /// The **keyword** (e.g. `for`).
+        keyword:  SmolStr,
+        /// The language to translate into.
+        language: SmolStr,
+    },
+
+    /// An identifier which is a **keyword** in the language (e.g. `pour`
+    /// when translating into French).
+    #[error(
+        "{}",
+        locale::detail("leafc::lexer::untranslatable", "keyword_in_language")
+            .replace("%{identifier}", .identifier)
+            .replace("%{language}", &locale::language(.language))
+    )]
+    KeywordInLanguage {
+        /// The **identifier** (e.g. `pour`).
+        identifier: SmolStr,
+        /// The language to translate into.
+        language:   SmolStr,
+    },
 }
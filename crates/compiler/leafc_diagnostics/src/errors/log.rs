@@ -4,8 +4,7 @@ use smol_str::SmolStr;
 use strum_macros::EnumCount as EnumCountMacro;
 use thiserror::Error;
 
-/// Prefix appended to all `LexicalError` messages.
-pub const LOG_ERROR_PREFIX: &str = "Logging System Error";
+use crate::locale;
 
 /// All possible errors that can occur during the **lexical analysis** phase of
 /// the compiler.
@@ -34,19 +33,16 @@ pub enum LogError {
     /// ```
     #[error(
         "{} {} {}{} {}",
-        LOG_ERROR_PREFIX.blue(),
+        locale::prefix("leafc::log").blue(),
         "-".black(),
-        "Log file initialization failed".red(),
+        locale::message("leafc::log::log_file_initialization").red(),
         ":".black(),
         .0.yellow().italic()
     )]
     #[diagnostic(
         code(leafc::log::log_file_initialization),
         url(docsrs),
-        help(
-            "The log file could not be initialized. Please try again (and report this issue if it \
-             persists)."
-        )
+        help("{}", locale::help("leafc::log::log_file_initialization"))
     )]
     LogFileInitialization(SmolStr),
 
@@ -54,19 +50,16 @@ pub enum LogError {
     /// **initialization** of the logging system.
     #[error(
         "{} {} {}{} {}",
-        LOG_ERROR_PREFIX.blue(),
+        locale::prefix("leafc::log").blue(),
         "-".black(),
-        "Log system initialization failed".red(),
+        locale::message("leafc::log::log_system_initialization").red(),
         ":".black(),
         .0.yellow().italic()
     )]
     #[diagnostic(
         code(leafc::log::log_system_initialization),
         url(docsrs),
-        help(
-            "The log system could not be initialized. Please try again (and report this issue if \
-             it persists)."
-        )
+        help("{}", locale::help("leafc::log::log_system_initialization"))
     )]
     LogSystemInitialization(SmolStr),
 
@@ -75,19 +68,16 @@ pub enum LogError {
     /// logging system.
     #[error(
         "{} {} {}{} {}",
-        LOG_ERROR_PREFIX.blue(),
+        locale::prefix("leafc::log").blue(),
         "-".black(),
-        "Log file could not be opened".red(),
+        locale::message("leafc::log::log_file").red(),
         ":".black(),
         .0.yellow().italic()
     )]
     #[diagnostic(
         code(leafc::log::log_file),
         url(docsrs),
-        help(
-            "The log file could not be opened. Please try again (and report this issue if it \
-             persists)."
-        )
+        help("{}", locale::help("leafc::log::log_file"))
     )]
     LogFileOpen(SmolStr),
 }
@@ -30,10 +30,21 @@ use leafc_utils::{
 pub use cfg::CfgError;
 pub use cli::CliError;
 pub use driver::DriverError;
-pub use lexer::LexicalError;
+pub use lexer::{
+    EscapeError,
+    LanguageSource,
+    LexicalError,
+    MixedLanguagesError,
+    NumberError,
+    TranslationError,
+};
 pub use log::LogError;
 pub use repl::ReplError;
-pub use syntax::SyntaxError;
+pub use syntax::{
+    Expected,
+    SuperscriptError,
+    SyntaxError,
+};
 
 /// A **convenience type** for representing an **error** that is emitted by the
 /// compiler. This type is used to **wrap** the [`LeafcError`] enum, which
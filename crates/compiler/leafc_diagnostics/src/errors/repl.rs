@@ -4,8 +4,7 @@ use smol_str::SmolStr;
 use strum_macros::EnumCount as EnumCountMacro;
 use thiserror::Error;
 
-/// Prefix appended to all `ReplError` messages.
-pub const REPL_ERROR_PREFIX: &str = "REPL Error";
+use crate::locale;
 
 /// All possible errors that can occur during the **execution** of the **REPL**.
 ///
@@ -25,19 +24,16 @@ pub enum ReplError {
     /// REPL.
     #[error(
         "{} {} {}{} {}",
-        REPL_ERROR_PREFIX.blue(),
+        locale::prefix("leafc::repl").blue(),
         "-".black(),
-        "Log file could not be opened".red(),
+        locale::message("leafc::repl::log_file_open").red(),
         ":".black(),
         .0.yellow().italic()
     )]
     #[diagnostic(
         code(leafc::repl::log_file_open),
         url(docsrs),
-        help(
-            "The log file could not be opened. Please try again (and report this issue if it \
-             persists)."
-        )
+        help("{}", locale::help("leafc::repl::log_file_open"))
     )]
     LogFileOpen(SmolStr),
 
@@ -46,19 +42,16 @@ pub enum ReplError {
     /// **initialization** of the REPL.
     #[error(
         "{} {} {}{} {}",
-        REPL_ERROR_PREFIX.blue(),
+        locale::prefix("leafc::repl").blue(),
         "-".black(),
-        "History file could not be opened".red(),
+        locale::message("leafc::repl::history_file_open").red(),
         ":".black(),
         .0.yellow().italic()
     )]
     #[diagnostic(
         code(leafc::repl::history_file_open),
         url(docsrs),
-        help(
-            "The history file could not be opened. Please try again (and report this issue if it \
-             persists)."
-        )
+        help("{}", locale::help("leafc::repl::history_file_open"))
     )]
     HistoryFileOpen(SmolStr),
 
@@ -67,19 +60,16 @@ pub enum ReplError {
     /// This error is typically returned during the **execution** of the REPL.
     #[error(
         "{} {} {}{} {}",
-        REPL_ERROR_PREFIX.blue(),
+        locale::prefix("leafc::repl").blue(),
         "-".black(),
-        "Invalid update to REPL settings".red(),
+        locale::message("leafc::repl::invalid_settings_update").red(),
         ":".black(),
         .0.yellow().italic()
     )]
     #[diagnostic(
         code(leafc::repl::invalid_settings_update),
         url(docsrs),
-        help(
-            "The REPL settings could not be updated. Please try again (and report this issue if \
-             it persists)."
-        )
+        help("{}", locale::help("leafc::repl::invalid_settings_update"))
     )]
     InvalidSettingsUpdate(SmolStr),
 }
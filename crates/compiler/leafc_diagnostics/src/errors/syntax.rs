@@ -15,10 +15,16 @@ use smol_str::SmolStr;
 use strum_macros::EnumCount as EnumCountMacro;
 use thiserror::Error;
 
-// use crate::LexicalError;
-
-/// Prefix appended to all `SyntaxError` messages.
-pub const SYNTAX_ERROR_PREFIX: &str = "Syntax Error";
+use crate::{
+    errors::{
+        EscapeError,
+        LexicalError,
+        MixedLanguagesError,
+        NumberError,
+        TranslationError,
+    },
+    locale,
+};
 
 /// All possible **syntax errors** that can occur within the user's source code
 /// from the perspective of the compiler.
@@ -29,8 +35,8 @@ pub const SYNTAX_ERROR_PREFIX: &str = "Syntax Error";
 /// use leafc_diagnostics::errors::syntax::SyntaxError;
 /// use strum::EnumCount;
 ///
-/// // There are x variants of the `LexicalError` enum.
-/// assert_eq!(1, SyntaxError::COUNT);
+/// // There are x variants of the `SyntaxError` enum.
+/// assert_eq!(11, SyntaxError::COUNT);
 /// ```
 #[derive(Debug, Error, EnumCountMacro, Clone, PartialEq, Eq)]
 pub enum SyntaxError {
@@ -47,16 +53,286 @@ pub enum SyntaxError {
     /// ```
     #[error(
         "{} {} {}{} {}",
-        SYNTAX_ERROR_PREFIX.blue(),
+        locale::prefix("leafc::syntax").blue(),
         "-".black(),
-        "Unknown token".red(),
+        locale::message("leafc::lexer::unknown_token").red(),
         ":".black(),
         .0.yellow().italic()
     )]
     UnknownToken(SmolStr),
+
+    /// This error is returned when the **parser** expected a particular
+    /// **token** or **syntactic construct** (e.g. an item or an expression)
+    /// but found something else.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::syntax::{
+    ///     Expected,
+    ///     SyntaxError,
+    /// };
+    ///
+    /// // The error is returned when the parser expected a `;`.
+    /// let error = SyntaxError::Expected(Expected::Token("SEMICOLON".into()));
+    /// ```
+    #[error(
+        "{} {} {}{} {}",
+        locale::prefix("leafc::syntax").blue(),
+        "-".black(),
+        locale::message("leafc::syntax::expected").red(),
+        ":".black(),
+        .0.yellow().italic()
+    )]
+    Expected(Expected),
+
+    /// This error is returned when a **block comment** is never closed (see
+    /// [`LexicalError::UnterminatedBlockComment`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::syntax::SyntaxError;
+    ///
+    /// // The error is returned when a block comment is never closed.
+    /// let error = SyntaxError::UnterminatedBlockComment;
+    /// ```
+    #[error(
+        "{} {} {}",
+        locale::prefix("leafc::syntax").blue(),
+        "-".black(),
+        locale::message("leafc::lexer::unterminated_block_comment").red()
+    )]
+    UnterminatedBlockComment,
+
+    /// This error is returned when an **escape** within a rune or string
+    /// literal is invalid (see [`LexicalError::InvalidEscape`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::{
+    ///     lexer::EscapeError,
+    ///     syntax::SyntaxError,
+    /// };
+    ///
+    /// // The error is returned for the unknown escape `\q`.
+    /// let error = SyntaxError::InvalidEscape(EscapeError::UnknownEscape('q'));
+    /// ```
+    #[error(
+        "{} {} {}{} {}",
+        locale::prefix("leafc::syntax").blue(),
+        "-".black(),
+        locale::message("leafc::lexer::invalid_escape").red(),
+        ":".black(),
+        .0.yellow().italic()
+    )]
+    InvalidEscape(EscapeError),
+
+    /// This error is returned when a **raw string** is never closed (see
+    /// [`LexicalError::UnterminatedRawString`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::syntax::SyntaxError;
+    ///
+    /// // The error is returned when a raw string is never closed.
+    /// let error = SyntaxError::UnterminatedRawString;
+    /// ```
+    #[error(
+        "{} {} {}",
+        locale::prefix("leafc::syntax").blue(),
+        "-".black(),
+        locale::message("leafc::lexer::unterminated_raw_string").red()
+    )]
+    UnterminatedRawString,
+
+    /// This error is returned when a **format string** or one of its holes is
+    /// never closed (see [`LexicalError::UnterminatedFormatString`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::syntax::SyntaxError;
+    ///
+    /// // The error is returned when a format string is never closed.
+    /// let error = SyntaxError::UnterminatedFormatString;
+    /// ```
+    #[error(
+        "{} {} {}",
+        locale::prefix("leafc::syntax").blue(),
+        "-".black(),
+        locale::message("leafc::lexer::unterminated_format_string").red()
+    )]
+    UnterminatedFormatString,
+
+    /// This error is returned when the **value** of a numeric literal doesn't
+    /// fit in its type (see [`LexicalError::InvalidNumber`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::{
+    ///     lexer::NumberError,
+    ///     syntax::SyntaxError,
+    /// };
+    ///
+    /// // The error is returned for `256u8`.
+    /// let error = SyntaxError::InvalidNumber(NumberError::IntegerOutOfRange("u8"));
+    /// ```
+    #[error(
+        "{} {} {}{} {}",
+        locale::prefix("leafc::syntax").blue(),
+        "-".black(),
+        locale::message("leafc::lexer::invalid_number").red(),
+        ":".black(),
+        .0.yellow().italic()
+    )]
+    InvalidNumber(NumberError),
+
+    /// This error is returned when a **superscript** (e.g. `²` or `⁽n⁾`)
+    /// isn't the exponent of the operand right before it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::syntax::{
+    ///     SuperscriptError,
+    ///     SyntaxError,
+    /// };
+    ///
+    /// // The error is returned for the `²` of `x ²`.
+    /// let error = SyntaxError::MisplacedSuperscript(SuperscriptError::NotAdjacent);
+    /// ```
+    #[error(
+        "{} {} {}{} {}",
+        locale::prefix("leafc::syntax").blue(),
+        "-".black(),
+        locale::message("leafc::syntax::misplaced_superscript").red(),
+        ":".black(),
+        .0.yellow().italic()
+    )]
+    MisplacedSuperscript(SuperscriptError),
+
+    /// This error is returned when a **keyword** isn't part of the spoken
+    /// language the rest of the file is written in (see
+    /// [`LexicalError::MixedLanguages`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::{
+    ///     lexer::{
+    ///         LanguageSource,
+    ///         MixedLanguagesError,
+    ///     },
+    ///     syntax::SyntaxError,
+    /// };
+    ///
+    /// // The error is returned for the `while` of `fonction f() { while x {} }`.
+    /// let error = SyntaxError::MixedLanguages(Box::new(MixedLanguagesError {
+    ///     keyword:           "while".into(),
+    ///     keyword_languages: vec!["en".into()],
+    ///     languages:         vec!["fr".into()],
+    ///     reason:            LanguageSource::Inferred,
+    /// }));
+    /// ```
+    #[error(
+        "{} {} {}{} {}",
+        locale::prefix("leafc::syntax").blue(),
+        "-".black(),
+        locale::message("leafc::lexer::mixed_languages").red(),
+        ":".black(),
+        .0.yellow().italic()
+    )]
+    MixedLanguages(Box<MixedLanguagesError>),
+
+    /// This error is returned when the **language** declared by a `lang`
+    /// attribute isn't a known spoken language (see
+    /// [`LexicalError::UnknownLanguage`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::syntax::SyntaxError;
+    ///
+    /// // The error is returned for `#![lang(xx)]`.
+    /// let error = SyntaxError::UnknownLanguage("xx".into());
+    /// ```
+    #[error(
+        "{} {} {}{} {}",
+        locale::prefix("leafc::syntax").blue(),
+        "-".black(),
+        locale::message("leafc::lexer::unknown_language").red(),
+        ":".black(),
+        .0.yellow().italic()
+    )]
+    UnknownLanguage(SmolStr),
+
+    /// This error is returned when a token can't be **translated** into
+    /// another spoken language (see [`LexicalError::Untranslatable`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::{
+    ///     lexer::TranslationError,
+    ///     syntax::SyntaxError,
+    /// };
+    ///
+    /// // The error is returned for the identifier `pour` when translating into French.
+    /// let error = SyntaxError::Untranslatable(TranslationError::KeywordInLanguage {
+    ///     identifier: "pour".into(),
+    ///     language:   "fr".into(),
+    /// });
+    /// ```
+    #[error(
+        "{} {} {}{} {}",
+        locale::prefix("leafc::syntax").blue(),
+        "-".black(),
+        locale::message("leafc::lexer::untranslatable").red(),
+        ":".black(),
+        .0.yellow().italic()
+    )]
+    Untranslatable(TranslationError),
 }
 
 impl SyntaxError {
+    /// Returns the **error code** of the error, which keys its message, help,
+    /// and label within the diagnostic catalogs (the errors mirroring a
+    /// `LexicalError` share its code).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use leafc_diagnostics::errors::syntax::{
+    ///     Expected,
+    ///     SyntaxError,
+    /// };
+    ///
+    /// assert_eq!(SyntaxError::Expected(Expected::Type).code(), "leafc::syntax::expected");
+    /// assert_eq!(
+    ///     SyntaxError::UnterminatedRawString.code(),
+    ///     "leafc::lexer::unterminated_raw_string"
+    /// );
+    /// ```
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::UnknownToken(_) => "leafc::lexer::unknown_token",
+            Self::Expected(_) => "leafc::syntax::expected",
+            Self::UnterminatedBlockComment => "leafc::lexer::unterminated_block_comment",
+            Self::InvalidEscape(_) => "leafc::lexer::invalid_escape",
+            Self::UnterminatedRawString => "leafc::lexer::unterminated_raw_string",
+            Self::UnterminatedFormatString => "leafc::lexer::unterminated_format_string",
+            Self::InvalidNumber(_) => "leafc::lexer::invalid_number",
+            Self::MisplacedSuperscript(_) => "leafc::syntax::misplaced_superscript",
+            Self::MixedLanguages(_) => "leafc::lexer::mixed_languages",
+            Self::UnknownLanguage(_) => "leafc::lexer::unknown_language",
+            Self::Untranslatable(_) => "leafc::lexer::untranslatable",
+        }
+    }
+
     pub fn emit<'a, F: SourceFiles<'a>>(
         &self,
         _files: &F,
@@ -64,28 +340,199 @@ impl SyntaxError {
         span: Span,
         errs: &mut Vec<Diagnostic<FileId>>,
     ) {
+        let code = self.code();
         let diagnostic = Diagnostic::error()
             .with_message(self.to_string())
-            .with_labels(vec![Label::primary(file_id, span)]);
-
-        // TODO: add help messages
-        // if let Some((label, help)) = self.help() {
-        //     diagnostic =
-        //         diagnostic.with_labels(vec![Label::secondary(file_id,
-        // span).with_message(label)]);     diagnostic =
-        // diagnostic.with_notes(vec![help]); }
+            .with_code(code)
+            .with_labels(vec![Label::primary(file_id, span).with_message(locale::label(code))])
+            .with_notes(vec![locale::help(code)]);
 
         errs.push(diagnostic);
     }
 }
 
-// impl From<LexicalError> for SyntaxError {
-//     fn from(error: LexicalError) -> Self {
-//         match error {
-//             LexicalError::UnknownToken(token) => Self::UnknownToken(token),
-//         }
-//     }
-// }
+impl From<LexicalError> for SyntaxError {
+    fn from(error: LexicalError) -> Self {
+        match error {
+            LexicalError::UnknownToken(token) => Self::UnknownToken(token),
+            LexicalError::UnterminatedBlockComment => Self::UnterminatedBlockComment,
+            LexicalError::InvalidEscape(error) => Self::InvalidEscape(error),
+            LexicalError::UnterminatedRawString => Self::UnterminatedRawString,
+            LexicalError::UnterminatedFormatString => Self::UnterminatedFormatString,
+            LexicalError::InvalidNumber(error) => Self::InvalidNumber(error),
+            LexicalError::MixedLanguages(error) => Self::MixedLanguages(error),
+            LexicalError::UnknownLanguage(language) => Self::UnknownLanguage(language),
+            LexicalError::Untranslatable(error) => Self::Untranslatable(error),
+        }
+    }
+}
+
+/// The **token** or **syntactic construct** the parser expected (see
+/// [`SyntaxError::Expected`]).
+///
+/// # Examples
+///
+/// ```rust
+/// use leafc_diagnostics::errors::syntax::Expected;
+///
+/// assert_eq!(Expected::Expression.to_string(), "an expression");
+/// assert_eq!(Expected::Token("R_PAREN".into()).to_string(), "R_PAREN");
+/// ```
+#[derive(Debug, Error, Clone, PartialEq, Eq, Hash)]
+pub enum Expected {
+    /// A **token**, given by the name of its kind (e.g. `R_PAREN`), as the
+    /// syntax kinds are defined downstream of the diagnostics.
+    #[error("{0}")]
+    Token(SmolStr),
+
+    /// Expected an **expression**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "expression"))]
+    Expression,
+
+    /// Expected a **type**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "type"))]
+    Type,
+
+    /// Expected a **pattern**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "pattern"))]
+    Pattern,
+
+    /// Expected a **statement**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "statement"))]
+    Statement,
+
+    /// Expected an **item**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "item"))]
+    Item,
+
+    /// Expected an **item** after a visibility.
+    #[error("{}", locale::detail("leafc::syntax::expected", "item_after_visibility"))]
+    ItemAfterVisibility,
+
+    /// Expected an **item** after attributes (or a visibility).
+    #[error("{}", locale::detail("leafc::syntax::expected", "item_after_attributes"))]
+    ItemAfterAttributes,
+
+    /// Expected an **associated item**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "assoc_item"))]
+    AssocItem,
+
+    /// Expected a list of **associated items**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "assoc_item_list"))]
+    AssocItemList,
+
+    /// Expected a **name**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "name"))]
+    Name,
+
+    /// Expected an **identifier**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "identifier"))]
+    Identifier,
+
+    /// Expected a **token tree**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "token_tree"))]
+    TokenTree,
+
+    /// Expected a **parameter list**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "param_list"))]
+    ParamList,
+
+    /// Expected a **parameter**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "param"))]
+    Param,
+
+    /// Expected a **generic parameter**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "generic_param"))]
+    GenericParam,
+
+    /// Expected a **generic argument**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "generic_arg"))]
+    GenericArg,
+
+    /// Expected a **const argument**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "const_arg"))]
+    ConstArg,
+
+    /// Expected a **where predicate**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "where_pred"))]
+    WherePred,
+
+    /// Expected an enum **variant**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "variant"))]
+    Variant,
+
+    /// Expected a list of enum **variants**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "variant_list"))]
+    VariantList,
+
+    /// Expected a record **field list** (or a `;`).
+    #[error("{}", locale::detail("leafc::syntax::expected", "field_list"))]
+    FieldList,
+
+    /// Expected a **field** declaration.
+    #[error("{}", locale::detail("leafc::syntax::expected", "field"))]
+    Field,
+
+    /// Expected a **field** of a record pattern.
+    #[error("{}", locale::detail("leafc::syntax::expected", "record_pat_field"))]
+    RecordPatField,
+
+    /// Expected a **use tree**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "use_tree"))]
+    UseTree,
+
+    /// Expected the `*` or `{` after the `::` of a **use tree**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "use_tree_list"))]
+    UseTreeList,
+
+    /// Expected the **start** of a use tree (e.g. a path or a `{`).
+    #[error("{}", locale::detail("leafc::syntax::expected", "use_tree_start"))]
+    UseTreeStart,
+
+    /// Expected a **block**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "block"))]
+    Block,
+
+    /// Expected a **loop** or a **block** after a label.
+    #[error("{}", locale::detail("leafc::syntax::expected", "loop_or_block"))]
+    LoopOrBlock,
+
+    /// Expected a list of **match arms**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "match_arm_list"))]
+    MatchArmList,
+
+    /// Expected a **match arm**.
+    #[error("{}", locale::detail("leafc::syntax::expected", "match_arm"))]
+    MatchArm,
+}
+
+/// The ways a **superscript** can be misplaced (see
+/// [`SyntaxError::MisplacedSuperscript`]).
+///
+/// # Examples
+///
+/// ```rust
+/// use leafc_diagnostics::errors::syntax::SuperscriptError;
+///
+/// assert_eq!(
+///     SuperscriptError::NotAdjacent.to_string(),
+///     "an exponent must directly follow its base"
+/// );
+/// ```
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SuperscriptError {
+    /// The superscript has **no base** to raise (e.g. `² + x`).
+    #[error("{}", locale::detail("leafc::syntax::misplaced_superscript", "no_base"))]
+    NoBase,
+
+    /// The superscript is **separated** from its base (e.g. `x ²`).
+    #[error("{}", locale::detail("leafc::syntax::misplaced_superscript", "not_adjacent"))]
+    NotAdjacent,
+
+    /// The superscript follows **another one** (e.g. `x²⁽n⁾`).
+    #[error("{}", locale::detail("leafc::syntax::misplaced_superscript", "consecutive"))]
+    Consecutive,
+}
 
 // /// This error is returned when an **unknown token** is encountered
 // /// during **lexical analysis**.
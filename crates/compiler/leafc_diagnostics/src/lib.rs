@@ -1,4 +1,7 @@
 pub mod errors;
+pub mod locale;
+
+rust_i18n::i18n!("locales");
 
 pub use errors::{
     CfgError,
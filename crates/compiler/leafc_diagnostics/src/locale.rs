@@ -0,0 +1,272 @@
+//! The **localization** of the compiler's diagnostics.
+//!
+//! Every message, help text, and label is looked up in the catalogs of
+//! `locales/` by the **error code** of its diagnostic (e.g. the message of
+//! `leafc::lexer::unknown_token` is `leafc.lexer.unknown_token.message`), in
+//! the current locale of `rust_i18n` (see `leafc_cfg::lang::set_language`).
+//! Locales (or keys) without a translation fall back to English.
+
+use rust_i18n::t;
+
+/// The **locale** used for diagnostics which haven't been translated into the
+/// current locale, whose catalog holds every key.
+pub const FALLBACK_LOCALE: &str = "en";
+
+/// Returns the **translation** of the given key in the current locale.
+///
+/// # Examples
+///
+/// ```rust
+/// use leafc_diagnostics::locale;
+///
+/// assert_eq!(locale::translate("leafc.lexer.prefix"), "Lexical Error");
+/// ```
+pub fn translate(key: &str) -> String {
+    translate_in(&rust_i18n::locale(), key)
+}
+
+/// Returns the **translation** of the given key in the given locale, or in the
+/// fallback locale if it hasn't been translated into the given one.
+///
+/// # Examples
+///
+/// ```rust
+/// use leafc_diagnostics::locale;
+///
+/// assert_eq!(locale::translate_in("de", "leafc.lexer.prefix"), "Lexikalischer Fehler");
+///
+/// // There's no Swahili catalog, so the English message is used.
+/// assert_eq!(locale::translate_in("sw", "leafc.lexer.prefix"), "Lexical Error");
+/// ```
+pub fn translate_in(locale: &str, key: &str) -> String {
+    let translation = t!(key, locale = locale).to_string();
+    if is_missing(&translation, locale, key) && locale != FALLBACK_LOCALE {
+        return t!(key, locale = FALLBACK_LOCALE).to_string();
+    }
+    translation
+}
+
+/// Returns the **prefix** of the errors of the given subsystem (e.g.
+/// `leafc::lexer`).
+pub fn prefix(subsystem: &str) -> String {
+    translate(&key(subsystem, "prefix"))
+}
+
+/// Returns the **message** of the diagnostic with the given error code (e.g.
+/// `leafc::lexer::unknown_token`).
+pub fn message(code: &str) -> String {
+    translate(&key(code, "message"))
+}
+
+/// Returns the **help** text of the diagnostic with the given error code.
+pub fn help(code: &str) -> String {
+    translate(&key(code, "help"))
+}
+
+/// Returns the **label** of the span of the diagnostic with the given error
+/// code.
+pub fn label(code: &str) -> String {
+    translate(&key(code, "label"))
+}
+
+/// Returns the given **detail** of the diagnostic with the given error code
+/// (e.g. the `unknown_escape` detail of `leafc::lexer::invalid_escape`).
+pub fn detail(code: &str, detail: &str) -> String {
+    translate(&key(code, detail))
+}
+
+/// Returns the **name** of the spoken language with the given tag (e.g. `fr`).
+///
+/// # Examples
+///
+/// ```rust
+/// use leafc_diagnostics::locale;
+///
+/// assert_eq!(locale::language("fr"), "French");
+/// ```
+pub fn language(tag: &str) -> String {
+    translate(&format!("leafc.languages.names.{tag}"))
+}
+
+/// Returns the names of the spoken languages with the given tags as a list of
+/// **alternatives** (e.g. `English, Danish or Norwegian`).
+///
+/// # Examples
+///
+/// ```rust
+/// use leafc_diagnostics::locale;
+///
+/// assert_eq!(locale::languages(["en", "da", "no"]), "English, Danish or Norwegian");
+/// ```
+pub fn languages<S: AsRef<str>>(tags: impl IntoIterator<Item = S>) -> String {
+    let names = tags.into_iter().map(|tag| language(tag.as_ref())).collect::<Vec<_>>();
+    match names.as_slice() {
+        [] => String::new(),
+        [name] => name.clone(),
+        [init @ .., last] => {
+            format!("{} {} {last}", init.join(", "), translate("leafc.languages.or"))
+        }
+    }
+}
+
+/// Returns whether the given translation is **missing**, in which case
+/// `rust_i18n` returns the key itself (prefixed by the locale, if it has a
+/// catalog).
+fn is_missing(translation: &str, locale: &str, key: &str) -> bool {
+    translation == key || translation == format!("{locale}.{key}")
+}
+
+/// Returns the catalog **key** of the given part of an error code (e.g.
+/// `leafc.lexer.unknown_token.help`).
+fn key(code: &str, part: &str) -> String {
+    format!("{}.{part}", code.replace("::", "."))
+}
+
+#[cfg(test)]
+mod locale_test_suite {
+    use super::*;
+
+    /// The locales of the **catalogs** of `locales/`.
+    const LOCALES: [&str; 3] = ["en", "de", "fr"];
+
+    /// The **subsystems** whose errors have a prefix.
+    const SUBSYSTEMS: [&str; 7] = [
+        "leafc::cfg",
+        "leafc::cli",
+        "leafc::driver",
+        "leafc::lexer",
+        "leafc::log",
+        "leafc::repl",
+        "leafc::syntax",
+    ];
+
+    /// The **error codes** of every diagnostic, along with whether they're
+    /// reported with a label (i.e. they're locatable).
+    const CODES: [(&str, bool); 20] = [
+        ("leafc::cfg::initialization_failure", false),
+        ("leafc::cli::file_not_found", false),
+        ("leafc::driver::initialization_failure", false),
+        ("leafc::lexer::unknown_token", true),
+        ("leafc::lexer::unterminated_block_comment", true),
+        ("leafc::lexer::invalid_escape", true),
+        ("leafc::lexer::unterminated_raw_string", true),
+        ("leafc::lexer::unterminated_format_string", true),
+        ("leafc::lexer::invalid_number", true),
+        ("leafc::lexer::mixed_languages", true),
+        ("leafc::lexer::unknown_language", true),
+        ("leafc::lexer::untranslatable", true),
+        ("leafc::log::log_file_initialization", false),
+        ("leafc::log::log_system_initialization", false),
+        ("leafc::log::log_file", false),
+        ("leafc::repl::log_file_open", false),
+        ("leafc::repl::history_file_open", false),
+        ("leafc::repl::invalid_settings_update", false),
+        ("leafc::syntax::expected", true),
+        ("leafc::syntax::misplaced_superscript", true),
+    ];
+
+    /// The **tags** of the spoken languages, whose names are translated.
+    const LANGUAGES: [&str; 16] = [
+        "en", "es", "fr", "de", "pt", "it", "nl", "sv", "da", "no", "fi", "ru", "ja", "zh", "ko",
+        "sw",
+    ];
+
+    /// The **details** of the diagnostics (see `EscapeError`, `NumberError`,
+    /// `MixedLanguagesError`, `TranslationError`, `Expected` and
+    /// `SuperscriptError`).
+    const DETAILS: [(&str, &str); 49] = [
+        ("leafc::lexer::invalid_escape", "lone_slash"),
+        ("leafc::lexer::invalid_escape", "unknown_escape"),
+        ("leafc::lexer::invalid_escape", "invalid_hex_escape"),
+        ("leafc::lexer::invalid_escape", "out_of_range_hex_escape"),
+        ("leafc::lexer::invalid_escape", "invalid_unicode_escape"),
+        ("leafc::lexer::invalid_escape", "empty_unicode_escape"),
+        ("leafc::lexer::invalid_escape", "out_of_range_unicode_escape"),
+        ("leafc::lexer::invalid_escape", "unicode_escape_in_byte_literal"),
+        ("leafc::lexer::invalid_escape", "non_ascii_in_byte_literal"),
+        ("leafc::lexer::invalid_number", "integer_out_of_range"),
+        ("leafc::lexer::invalid_number", "float_out_of_range"),
+        ("leafc::lexer::mixed_languages", "declared"),
+        ("leafc::lexer::mixed_languages", "inferred"),
+        ("leafc::lexer::mixed_languages", "supported"),
+        ("leafc::lexer::untranslatable", "no_spelling"),
+        ("leafc::lexer::untranslatable", "keyword_in_language"),
+        ("leafc::syntax::expected", "expression"),
+        ("leafc::syntax::expected", "type"),
+        ("leafc::syntax::expected", "pattern"),
+        ("leafc::syntax::expected", "statement"),
+        ("leafc::syntax::expected", "item"),
+        ("leafc::syntax::expected", "item_after_visibility"),
+        ("leafc::syntax::expected", "item_after_attributes"),
+        ("leafc::syntax::expected", "assoc_item"),
+        ("leafc::syntax::expected", "assoc_item_list"),
+        ("leafc::syntax::expected", "name"),
+        ("leafc::syntax::expected", "identifier"),
+        ("leafc::syntax::expected", "token_tree"),
+        ("leafc::syntax::expected", "param_list"),
+        ("leafc::syntax::expected", "param"),
+        ("leafc::syntax::expected", "generic_param"),
+        ("leafc::syntax::expected", "generic_arg"),
+        ("leafc::syntax::expected", "const_arg"),
+        ("leafc::syntax::expected", "where_pred"),
+        ("leafc::syntax::expected", "variant"),
+        ("leafc::syntax::expected", "variant_list"),
+        ("leafc::syntax::expected", "field_list"),
+        ("leafc::syntax::expected", "field"),
+        ("leafc::syntax::expected", "record_pat_field"),
+        ("leafc::syntax::expected", "use_tree"),
+        ("leafc::syntax::expected", "use_tree_list"),
+        ("leafc::syntax::expected", "use_tree_start"),
+        ("leafc::syntax::expected", "block"),
+        ("leafc::syntax::expected", "loop_or_block"),
+        ("leafc::syntax::expected", "match_arm_list"),
+        ("leafc::syntax::expected", "match_arm"),
+        ("leafc::syntax::misplaced_superscript", "no_base"),
+        ("leafc::syntax::misplaced_superscript", "not_adjacent"),
+        ("leafc::syntax::misplaced_superscript", "consecutive"),
+    ];
+
+    /// Returns whether the given key has been translated into the given
+    /// locale (rather than falling back).
+    fn is_translated(locale: &str, key: &str) -> bool {
+        !is_missing(&t!(key, locale = locale), locale, key)
+    }
+
+    #[test]
+    fn test_every_catalog_is_complete() {
+        for locale in LOCALES {
+            let subsystems = SUBSYSTEMS.iter().map(|subsystem| (*subsystem, "prefix"));
+            let codes = CODES.iter().flat_map(|(code, labelled)| {
+                let parts: &[&str] =
+                    if *labelled { &["message", "help", "label"] } else { &["message", "help"] };
+                parts.iter().map(move |part| (*code, *part))
+            });
+
+            for (code, part) in subsystems.chain(codes).chain(DETAILS) {
+                assert!(is_translated(locale, &key(code, part)), "{locale}: {code} {part}");
+            }
+
+            for tag in LANGUAGES {
+                assert!(is_translated(locale, &format!("leafc.languages.names.{tag}")), "{locale}: {tag}");
+            }
+            assert!(is_translated(locale, "leafc.languages.or"), "{locale}: or");
+        }
+    }
+
+    #[test]
+    fn test_translations() {
+        let key = key("leafc::lexer::unknown_token", "message");
+
+        assert_eq!(translate_in("en", &key), "Unknown token");
+        assert_eq!(translate_in("de", &key), "Unbekanntes Token");
+        assert_eq!(translate_in("fr", &key), "Lexème inconnu");
+    }
+
+    #[test]
+    fn test_fallback_to_english() {
+        let key = key("leafc::syntax::expected", "help");
+
+        assert_eq!(translate_in("sw", &key), translate_in("en", &key));
+        assert!(!is_translated("sw", &key));
+    }
+}
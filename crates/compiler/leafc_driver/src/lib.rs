@@ -67,14 +67,25 @@ use indicatif::{
 };
 use leafc_cfg::{
     cli::CommandLineConfiguration,
+    lang::{
+        self,
+        LanguageKind,
+    },
     settings::EmitKind,
 };
-use leafc_cli::LeafcCli;
+use leafc_cli::{
+    LeafcCli,
+    TranslateArgs,
+};
 use leafc_diagnostics::errors::{
     cli::CliError,
     driver::DriverError,
 };
-use leafc_lexer::lexer::TokenStream;
+use leafc_lexer::{
+    lexer::TokenStream,
+    translate::translate,
+    LanguageChecker,
+};
 use miette::{
     IntoDiagnostic,
     Result,
@@ -135,8 +146,11 @@ impl LeafcDriver {
     /// If the driver fails to run the compilation pipeline, this function will
     /// return an error indicating the cause of the failure to compile.
     pub fn compile(&self, text_source: &str, lossless: bool) -> Result<()> {
-        // lex the source code and produce a token stream
-        let tokens = TokenStream::new(text_source, lossless);
+        // lex the source code and produce a token stream (only recognizing the keywords
+        // of the supported languages)
+        let languages = lang::init();
+        let tokens =
+            TokenStream::with_languages(text_source, lossless, &languages.supported_languages);
 
         // if we are emitting the tokens, then log them
         if self.emit_kinds().contains(&EmitKind::TokenStream) {
@@ -146,6 +160,14 @@ impl LeafcDriver {
 
         log::info!("{tokens}"); // always log the tokens (for now)
 
+        // infer the spoken language of the source code, and make sure that all of its
+        // keywords are written in that language
+        let check = LanguageChecker::from_config(&languages).check(tokens.tokens());
+        if let Some(error) = check.errors().first() {
+            return Err(error.node.clone().into());
+        }
+        log::info!("language: {:?}", check.language());
+
         Ok(())
     }
 
@@ -290,6 +312,49 @@ pub fn batch_run(cli: &LeafcCli) -> Result<ExitCode> {
     Ok(ExitCode::SUCCESS)
 }
 
+/// Runs the **translate** command, translating the keywords of the source
+/// file into another spoken language and printing the translated source.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use clap::Parser;
+/// use leafc_cli::{
+///     LeafcCli,
+///     LeafcCommand,
+/// };
+///
+/// // Execute `leafc translate --to fr hello_world.leaf`.
+/// let cli = LeafcCli::parse_from(["leafc", "translate", "--to", "fr", "hello_world.leaf"]);
+/// if let Some(LeafcCommand::Translate(args)) = cli.command() {
+///     leafc_driver::translate_run(args);
+/// }
+/// ```
+///
+/// # Errors
+///
+/// This function will return an error if the source file can't be read, if
+/// its keywords aren't all written in the same language, or if some of its
+/// tokens can't be translated (e.g. an identifier which is a keyword in the
+/// language it's translated into).
+pub fn translate_run(args: &TranslateArgs) -> Result<ExitCode> {
+    let file = args.source();
+    let text_source = std::fs::read_to_string(file).into_diagnostic().map_err(|error| {
+        CliError::FileNotFound(format!("{:?}: {}", file.green(), error.bright_yellow()).into())
+    })?;
+
+    let from = args.from().unwrap_or(LanguageKind::Any);
+    let translation = translate(&text_source, &[from], *args.to());
+    if let Some(error) = translation.errors().first() {
+        return Err(error.node.clone().into());
+    }
+    log::info!("translated {} from {:?} to {}", get_filename(file)?, translation.from(), args.to());
+
+    print!("{}", translation.text());
+
+    Ok(ExitCode::SUCCESS)
+}
+
 fn get_filename(file: &PathBuf) -> Result<&str> {
     let filename = file
         .file_name()
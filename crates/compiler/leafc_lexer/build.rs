@@ -0,0 +1,268 @@
+//! Generates the **keyword** tables of the lexer from `keywords.toml`.
+//!
+//! The keyword table is checked first, so that every keyword is spelled in
+//! every spoken language, and no spelling is shared by two keywords. Then the
+//! following files are written to `OUT_DIR`:
+//!
+//! * `keywords.rs`: the spellings of `KEYWORDS`, included by
+//!   `src/language_check/keywords.rs`,
+//! * `kinds.rs`: the keyword variants of `TokenKind`,
+//! * `keyword_table.md`: the keyword table of the book,
+//! * `tm_keywords.json`: the keyword patterns of the TextMate grammar.
+//!
+//! The last three are kept in sync with the sources by the `sourcegen` test.
+
+use std::{
+    collections::BTreeMap,
+    env,
+    error::Error,
+    fmt::Write,
+    fs,
+    path::Path,
+};
+
+use cargo_emit::rerun_if_changed;
+use leafc_cfg::lang::LanguageKind;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// The **keyword table**.
+const KEYWORD_TABLE: &str = "keywords.toml";
+
+/// The **separator** of the sections of `TokenKind`.
+const SECTION: &str = "///////////////////////////////////////////////////////////////////////////\
+                       /////////////////////////////////////";
+
+#[derive(Deserialize)]
+struct KeywordTable {
+    keywords: Vec<Keyword>,
+}
+
+#[derive(Deserialize)]
+struct Keyword {
+    /// The `TokenKind` variant of the keyword (e.g. `FOR_KW`).
+    kind:        String,
+    /// Whether the keyword is **reserved** (i.e. not yet used by the language).
+    #[serde(default)]
+    reserved:    bool,
+    /// What the keyword is **used** for.
+    description: String,
+    /// The **TextMate scope** of the keyword (e.g. `keyword.control`).
+    scope:       String,
+    /// Further **documentation** of the `TokenKind` variant.
+    notes:       Option<String>,
+    /// The **spellings** of the keyword in each spoken language.
+    #[serde(flatten)]
+    spellings:   BTreeMap<String, Vec<String>>,
+}
+
+/// A **pattern** of the TextMate grammar.
+#[derive(Serialize)]
+struct Pattern {
+    name:    String,
+    #[serde(rename = "match")]
+    pattern: String,
+}
+
+impl Keyword {
+    /// Returns the distinct **spellings** of the keyword, along with the
+    /// languages they're spelled in, in the order of [`LanguageKind::SPOKEN`].
+    fn lexemes(&self) -> Vec<(&str, Vec<LanguageKind>)> {
+        let mut lexemes: Vec<(&str, Vec<LanguageKind>)> = vec![];
+        for language in LanguageKind::SPOKEN {
+            for lexeme in &self.spellings[&language.to_string()] {
+                match lexemes.iter_mut().find(|(spelling, _)| spelling == lexeme) {
+                    Some((_, languages)) => languages.push(language),
+                    None => lexemes.push((lexeme, vec![language])),
+                }
+            }
+        }
+        lexemes
+    }
+
+    /// Returns the **name** of the keyword, as used by the `KEYWORDS!` and
+    /// `RESERVED_KEYWORDS!` macros (e.g. `FOR`).
+    fn name(&self) -> &str {
+        self.kind.trim_end_matches("_KW")
+    }
+
+    /// Returns the **English** spelling of the keyword.
+    fn english(&self) -> &str {
+        &self.spellings[&LanguageKind::English.to_string()][0]
+    }
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    rerun_if_changed!(KEYWORD_TABLE);
+
+    let table: KeywordTable = toml::from_str(&fs::read_to_string(KEYWORD_TABLE)?)?;
+    check(&table.keywords);
+
+    let out_dir = env::var("OUT_DIR")?;
+    let out_dir = Path::new(&out_dir);
+    fs::write(out_dir.join("keywords.rs"), keywords(&table.keywords))?;
+    fs::write(out_dir.join("kinds.rs"), kinds(&table.keywords))?;
+    fs::write(out_dir.join("keyword_table.md"), keyword_table(&table.keywords))?;
+    fs::write(out_dir.join("tm_keywords.json"), tm_keywords(&table.keywords))?;
+
+    Ok(())
+}
+
+/// Checks that every keyword is spelled in **every spoken language** (and in
+/// no other language), and that no spelling is **shared** by two keywords.
+fn check(keywords: &[Keyword]) {
+    let languages: Vec<String> = LanguageKind::SPOKEN.iter().map(ToString::to_string).collect();
+    let mut errors = vec![];
+    let mut kinds: BTreeMap<&str, &str> = BTreeMap::new();
+
+    for keyword in keywords {
+        for language in &languages {
+            if keyword.spellings.get(language).map_or(true, Vec::is_empty) {
+                errors.push(format!("`{}` has no {language} spelling", keyword.kind));
+            }
+        }
+        for language in keyword.spellings.keys().filter(|key| !languages.contains(key)) {
+            errors.push(format!(
+                "`{}` is spelled in `{language}`, which isn't a spoken language",
+                keyword.kind
+            ));
+        }
+        for lexeme in keyword.spellings.values().flatten() {
+            match kinds.insert(lexeme, &keyword.kind) {
+                Some(kind) if kind != keyword.kind => {
+                    errors.push(format!("`{lexeme}` spells both `{kind}` and `{}`", keyword.kind));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        panic!("{KEYWORD_TABLE} is invalid:\n\n* {}\n", errors.join("\n* "));
+    }
+}
+
+/// Generates the spellings of `KEYWORDS`, in the order of the keyword table.
+fn keywords(keywords: &[Keyword]) -> String {
+    let mut out = String::from("&[\n");
+    for keyword in keywords {
+        for (lexeme, languages) in keyword.lexemes() {
+            let languages: Vec<String> = languages.iter().map(ToString::to_string).collect();
+            writeln!(
+                out,
+                "    Keyword {{ kind: {}, lexeme: {lexeme:?}, languages: &[{}] }},",
+                keyword.kind,
+                languages.join(", "),
+            )
+            .unwrap();
+        }
+    }
+    out.push(']');
+    out
+}
+
+/// Generates the keyword variants of `TokenKind`, with the reserved keywords
+/// first.
+fn kinds(keywords: &[Keyword]) -> String {
+    let mut out = String::new();
+    let sections = [
+        (
+            true,
+            "Reserved keywords (e.g. `export`, `final`, `throw`, etc.) are not allowed\n    // as \
+             identifiers.",
+        ),
+        (false, "Keywords (e.g. `as`, `if`, `else`, etc.) are not allowed as identifiers."),
+    ];
+    for (reserved, header) in sections {
+        writeln!(out, "    {SECTION}\n    // {header}\n    {SECTION}").unwrap();
+        for keyword in keywords.iter().filter(|keyword| keyword.reserved == reserved) {
+            for line in keyword.notes.iter().flat_map(|notes| notes.lines()) {
+                writeln!(out, "    ///{}{line}", if line.is_empty() { "" } else { " " }).unwrap();
+            }
+            let docs = if reserved { "RESERVED_KEYWORDS" } else { "KEYWORDS" };
+            writeln!(out, "    #[doc = {docs}!({:?})]", keyword.name()).unwrap();
+            for (lexeme, languages) in keyword.lexemes() {
+                let languages: Vec<String> = languages.iter().map(ToString::to_string).collect();
+                writeln!(out, "    #[token({lexeme:?})] // {}", languages.join(", ")).unwrap();
+            }
+            writeln!(out, "    {},\n", keyword.kind).unwrap();
+        }
+    }
+    out
+}
+
+/// Generates the keyword table of the book, along with the spellings of each
+/// keyword.
+fn keyword_table(keywords: &[Keyword]) -> String {
+    let mut out = String::from(
+        "# Keyword Table\n\n<!-- Generated from `crates/compiler/leafc_lexer/keywords.toml`, do \
+         not edit by hand. -->\n\nEvery **keyword** of Leaf, by its English spelling. Reserved \
+         keywords aren't used by the language yet, but can't be used as identifiers either.\n\n| \
+         Keyword | Kind | Description |\n| ------- | ---- | ----------- |\n",
+    );
+    for keyword in keywords {
+        writeln!(out, "| `{}` | `{}` | {} |", keyword.english(), keyword.kind, keyword.description)
+            .unwrap();
+    }
+
+    out.push_str(
+        "\n## Spellings\n\nThe spellings of each keyword in the **spoken languages** (a file only \
+         recognizes the keywords of its own language).\n\n| Keyword |",
+    );
+    let languages = &LanguageKind::SPOKEN[1..];
+    for language in languages {
+        write!(out, " {language} |").unwrap();
+    }
+    out.push_str("\n| ------- |");
+    out.push_str(&" --- |".repeat(languages.len()));
+    out.push('\n');
+    for keyword in keywords {
+        write!(out, "| `{}` |", keyword.english()).unwrap();
+        for language in languages {
+            let spellings: Vec<String> = keyword.spellings[&language.to_string()]
+                .iter()
+                .map(|lexeme| format!("`{lexeme}`"))
+                .collect();
+            write!(out, " {} |", spellings.join(", ")).unwrap();
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Generates the keyword patterns of the TextMate grammar, one for each scope.
+fn tm_keywords(keywords: &[Keyword]) -> String {
+    let mut scopes: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for keyword in keywords {
+        let lexemes = keyword.lexemes().into_iter().map(|(lexeme, _)| lexeme);
+        scopes.entry(&keyword.scope).or_default().extend(lexemes);
+    }
+
+    let patterns: Vec<Pattern> = scopes
+        .into_iter()
+        .map(|(scope, mut lexemes)| {
+            // Oniguruma takes the first alternative which matches, so longer
+            // spellings (e.g. `no es`) must come before their prefixes (e.g. `no`).
+            lexemes.sort_by_key(|lexeme| std::cmp::Reverse(lexeme.chars().count()));
+            let lexemes: Vec<String> = lexemes.iter().map(|lexeme| escape(lexeme)).collect();
+            Pattern {
+                name:    format!("{scope}.leaf"),
+                pattern: format!("\\b({})\\b", lexemes.join("|")),
+            }
+        })
+        .collect();
+    serde_json::to_string(&patterns).unwrap()
+}
+
+/// Escapes the **regex** metacharacters of the given spelling.
+fn escape(lexeme: &str) -> String {
+    lexeme.chars().fold(String::new(), |mut escaped, c| {
+        if "\\.+*?()|[]{}^$".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+        escaped
+    })
+}
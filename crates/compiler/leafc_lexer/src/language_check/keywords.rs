@@ -0,0 +1,58 @@
+//! The **spoken languages** each keyword is spelled in.
+//!
+//! Each entry mirrors a `#[token(...)]` attribute of a keyword in
+//! [`TokenKind`], along with the languages noted next to it (e.g. `pour` is the
+//! French spelling of `FOR_KW`). A spelling may be shared by several languages
+//! (e.g. `for` is English, Danish, Norwegian and Finnish).
+//!
+//! Both are generated from the keyword table of the lexer (`keywords.toml`).
+
+use leafc_cfg::lang::LanguageKind::{
+    self,
+    *,
+};
+
+use crate::TokenKind::{
+    self,
+    *,
+};
+
+/// A **keyword** spelling, along with the spoken languages it's spelled that
+/// way in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Keyword {
+    /// The **kind** of the keyword (e.g. `FOR_KW`).
+    pub kind:      TokenKind,
+    /// The **spelling** of the keyword (e.g. `pour`).
+    pub lexeme:    &'static str,
+    /// The **spoken languages** the keyword is spelled this way in.
+    pub languages: &'static [LanguageKind],
+}
+
+/// Returns the keyword spelled `lexeme`, if any.
+///
+/// # Example:
+///
+/// ```rust
+/// use leafc_cfg::lang::LanguageKind;
+/// use leafc_lexer::{
+///     language_check::keywords::keyword,
+///     TokenKind,
+/// };
+///
+/// let pour = keyword("pour").unwrap();
+/// assert_eq!(pour.kind, TokenKind::FOR_KW);
+/// assert_eq!(pour.languages, &[LanguageKind::French]);
+///
+/// assert_eq!(keyword("pourquoi"), None);
+/// ```
+pub fn keyword(lexeme: &str) -> Option<&'static Keyword> {
+    KEYWORDS.iter().find(|keyword| keyword.lexeme == lexeme)
+}
+
+/// Every **keyword** spelling, in the order of [`TokenKind`].
+///
+/// The spellings are generated from the keyword table (`keywords.toml`) by the
+/// build script, which checks that every keyword is spelled in every spoken
+/// language.
+pub static KEYWORDS: &[Keyword] = include!(concat!(env!("OUT_DIR"), "/keywords.rs"));
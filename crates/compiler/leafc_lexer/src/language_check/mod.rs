@@ -1,81 +1,361 @@
-use leafc_cfg::lang::LanguageKind;
+use std::{
+    ops::Range,
+    str::FromStr,
+};
 
-use crate::token::TokenSet;
+use getset::Getters;
+use leafc_cfg::lang::{
+    LanguageConfiguration,
+    LanguageKind,
+};
+use leafc_diagnostics::errors::{
+    LanguageSource,
+    LexicalError,
+    MixedLanguagesError,
+};
+use leafc_utils::codemap::{
+    Span,
+    Spanned,
+};
+use smol_str::SmolStr;
+
+use crate::token::{
+    Token,
+    TokenKind,
+};
+
+pub mod keywords;
 
 /// ## [**`LanguageChecker`**][LanguageChecker]
 ///
-/// A **language checker** is a **container** for a **spoken language** and all
-/// of its **legal tokens**.
-///
-/// The **job** of the **language checker** is to **check** a given set of
-/// **tokens** and see if they are all considered to be valid within a given
-/// **spoken language**. It additionally will and **report** any **errors**,
-/// that is, **invalid** tokens it finds during the `check` operation.
+/// A **language checker** infers the **spoken language** a file is written in
+/// from its **keywords**, and reports each keyword which isn't part of it (e.g.
+/// a `while` within a file otherwise written in French).
 ///
-/// A token is deemed **invalid** if it is **not** a **valid** token of the
-/// given spoken language. The **language checker** is **used** during the phase
-/// of **lexical analysis** to **check** the **tokens** of the **input string**.
+/// A file may be written in any of the checker's **languages** (i.e. the
+/// [supported languages][LanguageConfiguration::supported_languages] of the
+/// project), unless it **declares** its own language with a `lang` inner
+/// attribute (e.g. `#![lang(fr)]`), which is then the only one allowed.
 ///
-///
-/// When an invalid token is found, the checker emits an error to a diagnostic
-/// sink and continues checking the rest of the tokens.
+/// Since some keywords are spelled the same way in several languages (e.g.
+/// `for` is English, Danish, Norwegian and Finnish), the language is narrowed
+/// down keyword by keyword, and a keyword is only reported once none of the
+/// remaining languages spell it that way.
 ///
 /// # Example:
 ///
 /// ```rust
-/// // TODO: refactor example to use rayon and interned strings, and
-///
-/// // new calls builder internally
-/// use leafc_lexer::token::LanguageChecker;
+/// use leafc_cfg::lang::LanguageKind;
+/// use leafc_lexer::{
+///     lossy_lex,
+///     LanguageChecker,
+/// };
 ///
-/// let lang_checker = LanguageChecker::builder()
-///     .lang(LanguageKind::English)
-///     .tokens(vec!["false".into(), "true".into(), "Faux".into(), "falsch".into()])
-///     .build();
+/// let tokens = lossy_lex("fonction principale() { pour x dans xs {} }");
+/// let check = LanguageChecker::default().check(tokens.tokens());
 ///
-/// assert_eq!(lang_checker.lang(), LanguageKind::English);
+/// assert_eq!(check.language(), Some(LanguageKind::French));
+/// assert!(check.errors().is_empty());
 ///
-/// let valid = lang_checker.check_tokens();
-/// assert_eq!(valid, false);
+/// let tokens = lossy_lex("fonction principale() { while vrai {} }");
+/// let check = LanguageChecker::default().check(tokens.tokens());
 ///
-/// let lang_checker = LanguageChecker::new(English, vec!["false", "true", "Faux", "falsch"]);
+/// assert_eq!(check.language(), Some(LanguageKind::French));
+/// assert_eq!(check.errors().len(), 1);
 /// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LanguageChecker {
-    pub lang:         LanguageKind,
-    pub tokens:       TokenSet,
-    pub valid_tokens: TokenSet,
+    /// The **languages** a file may be written in (unless it declares its own
+    /// language), in [`LanguageKind`] order.
+    languages: Vec<LanguageKind>,
+}
+
+impl Default for LanguageChecker {
+    /// Creates a checker allowing **every** spoken language.
+    fn default() -> Self {
+        Self::new(&[LanguageKind::Any])
+    }
 }
 
 impl LanguageChecker {
-    // pub fn new(lang: LanguageKind, tokens: TokenSet) -> Self {
-    //     debug_assert!(lang.is_spoken()); // don't want the language to be `any`
-    //     Self { lang, tokens }
-    // }
+    /// Creates a checker allowing the given `languages` (where
+    /// [`LanguageKind::Any`] allows every spoken language).
+    pub fn new(languages: &[LanguageKind]) -> Self {
+        let mut languages = if languages.contains(&LanguageKind::Any) {
+            LanguageKind::SPOKEN.to_vec()
+        } else {
+            languages.to_vec()
+        };
+        languages.sort_unstable();
+        languages.dedup();
+
+        Self { languages }
+    }
+
+    /// Creates a checker allowing the **supported languages** of the given
+    /// language configuration.
+    pub fn from_config(config: &LanguageConfiguration) -> Self {
+        Self::new(&config.supported_languages)
+    }
 
-    pub fn lang(&self) -> LanguageKind {
-        self.lang
+    /// Returns the **languages** a file may be written in (unless it declares
+    /// its own language).
+    pub fn languages(&self) -> &[LanguageKind] {
+        &self.languages
     }
 
-    pub fn tokens(&self) -> &TokenSet {
-        &self.tokens
+    /// **Checks** the given tokens of a file, inferring the spoken language it
+    /// is written in and reporting the keywords which aren't part of it.
+    pub fn check<'t>(&self, tokens: impl IntoIterator<Item = &'t Token>) -> LanguageCheck {
+        let tokens = tokens
+            .into_iter()
+            .filter(|token| !token.kind().is_whitespace() && !token.kind().is_comment())
+            .collect::<Vec<_>>();
+
+        let InnerAttrs { len, declared, tag, mut errors } = inner_attrs(&tokens);
+
+        let mut languages = declared.map_or_else(|| self.languages.clone(), |lang| vec![lang]);
+        let mut inferred = false;
+        for token in &tokens[len..] {
+            if !token.kind().is_keyword() {
+                continue;
+            }
+            let Some(keyword) = keywords::keyword(token.lexeme()) else {
+                continue;
+            };
+
+            let common = languages
+                .iter()
+                .copied()
+                .filter(|lang| keyword.languages.contains(lang))
+                .collect::<Vec<_>>();
+            if common.is_empty() {
+                let reason = if declared.is_some() {
+                    LanguageSource::Declared
+                } else if inferred {
+                    LanguageSource::Inferred
+                } else {
+                    LanguageSource::Supported
+                };
+                errors.push(Spanned {
+                    span: *token.span(),
+                    node: LexicalError::MixedLanguages(Box::new(MixedLanguagesError {
+                        keyword: keyword.lexeme.into(),
+                        keyword_languages: tags(keyword.languages),
+                        languages: tags(&languages),
+                        reason,
+                    })),
+                });
+            } else {
+                languages = common;
+                inferred = true;
+            }
+        }
+
+        LanguageCheck { languages, declared: declared.is_some(), tag, errors }
+    }
+
+    /// **Scopes** the keywords of the given tokens of a file to the checker's
+    /// languages (or to the language the file declares), turning the keywords
+    /// of every other language into **identifiers** (e.g. the Danish `vente`
+    /// within an English file).
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use leafc_cfg::lang::LanguageKind;
+    /// use leafc_lexer::{
+    ///     lossy_lex,
+    ///     LanguageChecker,
+    ///     TokenKind,
+    /// };
+    ///
+    /// let mut tokens = lossy_lex("vente := final");
+    /// let checker = LanguageChecker::new(&[LanguageKind::English]);
+    /// checker.scope_keywords(tokens.tokens_mut());
+    ///
+    /// assert_eq!(tokens.tokens()[0].kind(), &TokenKind::IDENTIFIER);
+    /// assert_eq!(tokens.tokens()[2].kind(), &TokenKind::FINAL_KW);
+    /// ```
+    pub fn scope_keywords<'t>(&self, tokens: impl IntoIterator<Item = &'t mut Token>) {
+        let mut tokens = tokens.into_iter().collect::<Vec<_>>();
+
+        // The keywords within the inner attributes (e.g. the `ja` of `#![lang(ja)]`)
+        // are left as they are, as they're never checked either.
+        let significant = tokens
+            .iter()
+            .map(|token| &**token)
+            .filter(|token| !token.kind().is_whitespace() && !token.kind().is_comment())
+            .collect::<Vec<_>>();
+        let InnerAttrs { len, declared, .. } = inner_attrs(&significant);
+        let attrs_end = significant[..len].last().map_or(0, |token| {
+            let Range { end, .. } = (*token.span()).into();
+            end
+        });
+
+        let languages = declared.map_or_else(|| self.languages.clone(), |lang| vec![lang]);
+        for token in &mut tokens {
+            let Range { start, .. } = (*token.span()).into();
+            let inactive = token
+                .languages()
+                .map_or(false, |langs| !langs.iter().any(|lang| languages.contains(lang)));
+            if start >= attrs_end && inactive {
+                token.set_kind(TokenKind::IDENTIFIER);
+            }
+        }
+    }
+
+    /// Returns the **languages** whose keywords are recognized within the file
+    /// made of the given tokens (see [`LanguageChecker::scope_keywords`]), i.e.
+    /// the language it declares, or the checker's languages otherwise.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use leafc_cfg::lang::LanguageKind;
+    /// use leafc_lexer::{
+    ///     lossy_lex,
+    ///     LanguageChecker,
+    /// };
+    ///
+    /// let checker = LanguageChecker::new(&[LanguageKind::English]);
+    ///
+    /// let tokens = lossy_lex("#![lang(fr)] fonction f() {}");
+    /// assert_eq!(checker.active_languages(tokens.tokens()), [LanguageKind::French]);
+    ///
+    /// let tokens = lossy_lex("fn f() {}");
+    /// assert_eq!(checker.active_languages(tokens.tokens()), [LanguageKind::English]);
+    /// ```
+    pub fn active_languages<'t>(
+        &self,
+        tokens: impl IntoIterator<Item = &'t Token>,
+    ) -> Vec<LanguageKind> {
+        let tokens = tokens
+            .into_iter()
+            .filter(|token| !token.kind().is_whitespace() && !token.kind().is_comment())
+            .collect::<Vec<_>>();
+        inner_attrs(&tokens).declared.map_or_else(|| self.languages.clone(), |lang| vec![lang])
     }
+}
+
+/// The **result** of [checking][LanguageChecker::check] the spoken language of
+/// a file.
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+#[getset(get = "pub")]
+pub struct LanguageCheck {
+    /// The **languages** the file could be written in (i.e. those spelling
+    /// each of its keywords), in [`LanguageKind`] order.
+    languages: Vec<LanguageKind>,
+
+    /// Whether the file **declares** its language with a `lang` attribute
+    /// (e.g. `#![lang(fr)]`).
+    declared: bool,
+
+    /// The **span** of the language tag the file declares (e.g. the `fr` of
+    /// `#![lang(fr)]`), if any.
+    tag: Option<Span>,
+
+    /// The **keywords** which aren't part of the file's language (and the
+    /// unknown languages declared by `lang` attributes), in the order they
+    /// occur.
+    errors: Vec<Spanned<LexicalError>>,
+}
+
+impl LanguageCheck {
+    /// Returns the spoken **language** the file is written in.
+    ///
+    /// When several languages spell all of its keywords the same way (e.g. a
+    /// file whose only keyword is `for`), the first one in [`LanguageKind`]
+    /// order is used (i.e. English before Danish).
+    pub fn language(&self) -> Option<LanguageKind> {
+        self.languages.first().copied()
+    }
+}
+
+/// The **inner attributes** at the start of a file (e.g. `#![lang(fr)]`),
+/// whose arguments aren't keywords (e.g. `ja` is the Finnish spelling of
+/// `and`).
+struct InnerAttrs {
+    /// The **number** of (non-trivia) tokens making up the inner attributes.
+    len:      usize,
+    /// The spoken **language** declared by a `lang` attribute, if any.
+    declared: Option<LanguageKind>,
+    /// The **span** of the declared language's tag, if any.
+    tag:      Option<Span>,
+    /// The **unknown languages** declared by `lang` attributes.
+    errors:   Vec<Spanned<LexicalError>>,
+}
+
+/// Reads the **inner attributes** at the start of the given (non-trivia)
+/// tokens.
+fn inner_attrs(tokens: &[&Token]) -> InnerAttrs {
+    let mut attrs =
+        InnerAttrs { len: 0, declared: None, tag: None, errors: Vec::new() };
+    while let Some(len) = inner_attr_len(&tokens[attrs.len..]) {
+        if let Some((tag, span)) = lang_attr_tag(&tokens[attrs.len..attrs.len + len]) {
+            match LanguageKind::from_str(&tag) {
+                Ok(language) if language.is_spoken() => {
+                    attrs.declared = Some(language);
+                    attrs.tag = Some(span);
+                }
+                _ => attrs.errors.push(Spanned { span, node: LexicalError::UnknownLanguage(tag) }),
+            }
+        }
+        attrs.len += len;
+    }
+    attrs
+}
+
+/// Returns the **length** of the inner attribute (e.g. `#![lang(fr)]`) at the
+/// start of `tokens`, if any.
+fn inner_attr_len(tokens: &[&Token]) -> Option<usize> {
+    let kinds = tokens.iter().take(3).map(|token| *token.kind()).collect::<Vec<_>>();
+    if kinds != [TokenKind::HASH, TokenKind::BANG, TokenKind::L_BRACKET] {
+        return None;
+    }
+
+    let mut depth = 0_usize;
+    for (i, token) in tokens.iter().enumerate().skip(2) {
+        match token.kind() {
+            TokenKind::L_BRACKET => depth += 1,
+            TokenKind::R_BRACKET if depth == 1 => return Some(i + 1),
+            TokenKind::R_BRACKET => depth -= 1,
+            _ => (),
+        }
+    }
+    None
+}
+
+/// Returns the **language tag** of a `lang` attribute (e.g. the `fr` of
+/// `#![lang(fr)]`), along with its span.
+///
+/// The tag is made up of all of the tokens within the parentheses, as a tag
+/// like `fr-CA` is lexed as several tokens.
+fn lang_attr_tag(attr: &[&Token]) -> Option<(SmolStr, Span)> {
+    match attr {
+        [_, _, _, name, l_paren, tag @ .., r_paren, _]
+            if *name.kind() == TokenKind::IDENTIFIER &&
+                name.lexeme() == "lang" &&
+                *l_paren.kind() == TokenKind::L_PAREN &&
+                *r_paren.kind() == TokenKind::R_PAREN =>
+        {
+            let Range { start, .. } = (*l_paren.span()).into();
+            let Range { end, .. } = (*r_paren.span()).into();
+            let text = tag.iter().map(|token| token.lexeme().as_str()).collect::<String>();
+            Some((text.into(), Span::new(start + 1..end - 1)))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the **tags** of the given languages (e.g. `en`), which the
+/// diagnostics render as the names of the languages.
+pub(crate) fn tags(languages: &[LanguageKind]) -> Vec<SmolStr> {
+    languages.iter().map(|language| tag(*language)).collect()
+}
 
-    // pub fn check_tokens(&self, tokens: &[Token]) -> VecDeque<Token> {
-    //     let mut tokens = tokens.iter().copied().collect::<VecDeque<_>>();
-    //     // let mut tokens = tokens.par_iter().copied().collect::<VecDeque<_>>();
-    //     let mut i = 0;
-    //     while i < tokens.len() {
-    //         let token = tokens[i];
-    //         if !self.tokens().contains(&token) {
-    //             tokens.remove(i);
-    //         } else {
-    //             i += 1;
-    //         }
-    //     }
-    //     tokens
-    // }
-
-    // pub fn check_token(&self, token: &Token) -> bool {
-    //     self.tokens().contains(token)
-    // }
+/// Returns the **tag** of the given language (or its name, for
+/// [`LanguageKind::Any`]).
+pub(crate) fn tag(language: LanguageKind) -> SmolStr {
+    language.tag().map_or_else(|| language.to_string().into(), Into::into)
 }
@@ -6,7 +6,12 @@ use getset::{
     MutGetters,
     Setters,
 };
-use leafc_utils::codemap::Span;
+use leafc_cfg::lang::LanguageKind;
+use leafc_diagnostics::errors::LexicalError;
+use leafc_utils::codemap::{
+    Span,
+    Spanned,
+};
 use logos::Logos;
 use smartstring::alias::String;
 use smol_str::SmolStr;
@@ -16,12 +21,19 @@ use std::{
     ops::{
         Add,
         AddAssign,
+        Range,
     },
 };
 
-use crate::token::{
-    Token,
-    TokenKind,
+use crate::{
+    language_check::LanguageChecker,
+    number,
+    token::{
+        kinds::block_comment_len,
+        Token,
+        TokenKind,
+    },
+    unescape,
 };
 
 /// Performs a **lossy** lexing of the input string (i.e. a **minimal
@@ -210,6 +222,13 @@ pub struct TokenStream {
     /// Whether or not the token stream is **lossless** (e.g. a **full
     /// fidelity** representation of the input text source).
     lossless: bool,
+
+    /// The **lexical errors** found in the input string (e.g. an unterminated
+    /// block comment or an invalid escape), in the order they occur.
+    ///
+    /// **NOTE**: Unknown tokens are not included, as they are already
+    /// represented by [`TokenKind::ERROR`] tokens in the stream.
+    errors: Vec<Spanned<LexicalError>>,
 }
 
 impl fmt::Display for TokenStream {
@@ -228,28 +247,50 @@ impl fmt::Display for TokenStream {
 
 impl TokenStream {
     pub fn new(input: &str, lossless: bool) -> Self {
-        let mut lex = TokenKind::lexer(input);
-
-        let mut tokens = VecDeque::new();
-
-        while let Some(token) = lex.next() {
-            if !lossless && (token.is_whitespace() || token.is_comment()) {
-                continue;
-            }
-
-            tokens.push_back(Token::new(token, SmolStr::new(lex.slice()), Span::new(lex.span())));
-        }
+        let mut lexer = RawLexer { input, lossless, tokens: VecDeque::new(), errors: Vec::new() };
+        lexer.lex_tokens(0, false);
 
         Self {
             input: input.to_string().into(),
-            tokens,
+            tokens: lexer.tokens,
             cursor: TokenOffset::new(0),
             curr_line: None,
             curr_offset: None,
             lossless,
+            errors: lexer.errors,
         }
     }
 
+    /// Lexes the input string, only recognizing the **keywords** of the given
+    /// spoken `languages` (or of the language the input declares with a `lang`
+    /// attribute, e.g. `#![lang(fr)]`), where [`LanguageKind::Any`] recognizes
+    /// the keywords of every language (as [`TokenStream::new`] does).
+    ///
+    /// The keywords of every other language are lexed as **identifiers**
+    /// instead, so they don't shadow the names of the program (e.g. `vente`,
+    /// the Danish spelling of `await`, within an English program).
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use leafc_cfg::lang::LanguageKind;
+    /// use leafc_lexer::{
+    ///     lexer::TokenStream,
+    ///     TokenKind,
+    /// };
+    ///
+    /// let tokens = TokenStream::with_languages("vente", false, &[LanguageKind::English]);
+    /// assert_eq!(tokens.tokens()[0].kind(), &TokenKind::IDENTIFIER);
+    ///
+    /// let tokens = TokenStream::with_languages("vente", false, &[LanguageKind::Danish]);
+    /// assert_eq!(tokens.tokens()[0].kind(), &TokenKind::AWAIT_KW);
+    /// ```
+    pub fn with_languages(input: &str, lossless: bool, languages: &[LanguageKind]) -> Self {
+        let mut stream = Self::new(input, lossless);
+        LanguageChecker::new(languages).scope_keywords(stream.tokens_mut());
+        stream
+    }
+
     /// Peek at the **next token** in the token stream.
     ///
     /// # Example:
@@ -327,3 +368,217 @@ impl Iterator for TokenStream {
         // Some(*token)
     }
 }
+
+/// The **lexer** behind a [`TokenStream`], which runs `logos` over the input
+/// and fixes up the tokens it can't get right on its own.
+///
+/// In particular, a string with a **hole** in it (e.g. `"Bonjour, {name}!"`)
+/// is split up into a **format string** (see
+/// [`TokenKind::FORMAT_STRING_START`]), and the holes are lexed as usual.
+struct RawLexer<'a> {
+    input:    &'a str,
+    lossless: bool,
+    tokens:   VecDeque<Token>,
+    errors:   Vec<Spanned<LexicalError>>,
+}
+
+/// Where the **literal fragment** of a format string ends (see
+/// [`scan_fragment`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FragmentEnd {
+    /// At the `{` opening a hole.
+    Hole,
+    /// At the closing `"` of the string.
+    Quote,
+    /// At the end of the input.
+    Eof,
+}
+
+impl RawLexer<'_> {
+    /// Lexes the input from `pos` until its end or, if `in_hole` is set, until
+    /// the `}` closing the hole of a format string.
+    ///
+    /// Returns the offset it stopped at.
+    fn lex_tokens(&mut self, pos: usize, in_hole: bool) -> usize {
+        let input = self.input;
+        let mut lex = TokenKind::lexer(&input[pos..]);
+
+        // The offset of the lexer's input within `input` (the lexer is
+        // restarted part way through whenever a token has to be split).
+        let mut offset = pos;
+
+        // The number of unclosed `{`s within the current hole.
+        let mut depth = 0_usize;
+
+        while let Some(mut token) = lex.next() {
+            let span = lex.span();
+            let (start, mut end) = (offset + span.start, offset + span.end);
+
+            // `logos` can backtrack into the middle of a multi-byte character (e.g. in
+            // `⁰··`, as `·` and `¹` share their first byte), so the token is lexed again
+            // up to the last character boundary.
+            if !input.is_char_boundary(end) {
+                let boundary = (start..end).rev().find(|&i| input.is_char_boundary(i)).unwrap();
+                let mut relex = TokenKind::lexer(&input[start..boundary]);
+                match relex.next() {
+                    Some(relexed) => {
+                        token = relexed;
+                        end = start + relex.span().end;
+                    }
+                    None => {
+                        token = TokenKind::ERROR;
+                        end = start + input[start..].chars().next().map_or(0, char::len_utf8);
+                    }
+                }
+                offset = end;
+                lex = TokenKind::lexer(&input[offset..]);
+            }
+
+            // A float can't end with a `.` which is immediately followed by another `.`
+            // (e.g. `1..2` is the range `1 .. 2`, not the floats `1.` and `.2`).
+            if token == TokenKind::FLOAT &&
+                input[start..end].ends_with('.') &&
+                input[end..].starts_with('.')
+            {
+                token = TokenKind::INTEGER;
+                end -= 1;
+                offset = end;
+                lex = TokenKind::lexer(&input[offset..]);
+            }
+
+            if in_hole {
+                match token {
+                    TokenKind::L_BRACE => depth += 1,
+                    TokenKind::R_BRACE if depth == 0 => return start,
+                    TokenKind::R_BRACE => depth -= 1,
+                    _ => (),
+                }
+            }
+
+            let lexeme = &input[start..end];
+            if token == TokenKind::STRING &&
+                lexeme.starts_with('"') &&
+                scan_fragment(&lexeme[1..]).1 == FragmentEnd::Hole
+            {
+                offset = self.lex_format_string(start);
+                lex = TokenKind::lexer(&input[offset..]);
+                continue;
+            }
+
+            if token.is_comment() &&
+                lexeme.starts_with("/*") &&
+                block_comment_len(&lexeme[2..]).is_none()
+            {
+                let error = LexicalError::UnterminatedBlockComment;
+                self.errors.push(Spanned { span: Span::new(start..end), node: error });
+            }
+            if token.is_number() ||
+                matches!(token, TokenKind::RUNE | TokenKind::STRING | TokenKind::RAW_STRING)
+            {
+                self.validate(token, start..end);
+            }
+
+            self.push(token, start..end);
+        }
+
+        input.len()
+    }
+
+    /// Lexes the format string starting at `start` (i.e. at its opening `"`),
+    /// along with the tokens in its holes.
+    ///
+    /// Returns the offset after the format string.
+    fn lex_format_string(&mut self, start: usize) -> usize {
+        self.push(TokenKind::FORMAT_STRING_START, start..start + 1);
+
+        let mut pos = start + 1;
+        loop {
+            let (len, fragment_end) = scan_fragment(&self.input[pos..]);
+            if len > 0 {
+                self.validate(TokenKind::STRING_FRAGMENT, pos..pos + len);
+                self.push(TokenKind::STRING_FRAGMENT, pos..pos + len);
+                pos += len;
+            }
+
+            match fragment_end {
+                FragmentEnd::Quote => {
+                    self.push(TokenKind::FORMAT_STRING_END, pos..pos + 1);
+                    return pos + 1;
+                }
+                FragmentEnd::Hole => {
+                    self.push(TokenKind::L_BRACE, pos..pos + 1);
+                    pos = self.lex_tokens(pos + 1, true);
+                    if !self.input[pos..].starts_with('}') {
+                        break;
+                    }
+                    self.push(TokenKind::R_BRACE, pos..pos + 1);
+                    pos += 1;
+                }
+                FragmentEnd::Eof => break,
+            }
+        }
+
+        let error = LexicalError::UnterminatedFormatString;
+        self.errors.push(Spanned { span: Span::new(start..pos), node: error });
+        pos
+    }
+
+    /// Reports the invalid escapes (or out of range value) in the literal at
+    /// `range`.
+    fn validate(&mut self, kind: TokenKind, range: Range<usize>) {
+        let start = range.start;
+        let lexeme = &self.input[range];
+        let errors = if kind.is_number() {
+            number::validate(kind, lexeme).into_iter().collect()
+        } else {
+            unescape::validate(kind, lexeme)
+        };
+        self.errors.extend(errors.into_iter().map(|error| {
+            let Range { start: error_start, end: error_end } = error.span.into();
+            Spanned { span: Span::new(start + error_start..start + error_end), ..error }
+        }));
+    }
+
+    /// Adds the token at `range`, unless it is trivia and the stream is lossy.
+    fn push(&mut self, kind: TokenKind, range: Range<usize>) {
+        if !self.lossless && (kind.is_whitespace() || kind.is_comment()) {
+            return;
+        }
+
+        let lexeme = SmolStr::new(&self.input[range.clone()]);
+        self.tokens.push_back(Token::new(kind, lexeme, Span::new(range)));
+    }
+}
+
+/// Scans the **literal fragment** at the start of `text` (i.e. after the
+/// opening `"` or a hole of a format string), returning its length in bytes and
+/// where it ends.
+///
+/// A `{` opens a hole unless it is doubled (i.e. `{{`), or followed by a `}`, a
+/// `:` or a `"` (e.g. the placeholders `{}` and `{:?}`).
+fn scan_fragment(text: &str) -> (usize, FragmentEnd) {
+    // The delimiters are all ASCII, so the text can be scanned byte by byte.
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => return (i, FragmentEnd::Quote),
+            // The braces of a `\u{...}` escape don't open a hole.
+            b'\\' if text[i + 1..].starts_with("u{") => {
+                i = match text[i..].find(['}', '"']) {
+                    Some(j) if bytes[i + j] == b'}' => i + j + 1,
+                    Some(j) => i + j,
+                    None => bytes.len(),
+                };
+            }
+            b'\\' => i += 2,
+            b'{' => match bytes.get(i + 1) {
+                Some(b'{') => i += 2,
+                Some(b'}' | b':' | b'"') | None => i += 1,
+                Some(_) => return (i, FragmentEnd::Hole),
+            },
+            _ => i += 1,
+        }
+    }
+    (bytes.len(), FragmentEnd::Eof)
+}
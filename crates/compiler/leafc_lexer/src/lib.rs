@@ -24,8 +24,17 @@ pub mod token;
 
 pub mod language_check;
 
+pub mod number;
+
+pub mod translate;
+
+pub mod unescape;
+
 pub use {
-    language_check::LanguageChecker,
+    language_check::{
+        LanguageCheck,
+        LanguageChecker,
+    },
     lexer::{
         lossless_lex,
         lossy_lex,
@@ -0,0 +1,609 @@
+//! **Evaluation** of numeric literals.
+//!
+//! The lexer only finds where a numeric literal **starts** and **ends**, so its
+//! value (and whether that value fits in the type given by its suffix) is
+//! worked out here, both while lexing (see
+//! [`TokenStream::errors`][crate::lexer::TokenStream::errors]) and when later
+//! phases need the **value** of a literal (see [`parse_number`]).
+//!
+//! The following literals are supported:
+//!
+//! * **Integers** in decimal, binary (`0b`), octal (`0o`) and hexadecimal
+//!   (`0x`), with `_` separators and an optional type suffix (e.g. `255_u8`).
+//! * **Floats** in decimal (with an optional `e` exponent), and in binary,
+//!   octal and hexadecimal (with an optional `p` exponent, which is a power of
+//!   two), with an optional `f32` or `f64` suffix.
+//! * **Superscript** integers and floats (e.g. `⁻¹²`, `⁰ˣᶠᶠ` or `²·⁵`), which
+//!   are **normalized** to their regular forms (see [`normalize_superscript`])
+//!   before they're evaluated.
+//! * **Mathematical constants** (e.g. `π`, `τ` or `-∞32`), whose values are the
+//!   nearest `f64`s to the constants. Infinity and NaN may have a `16` or `32`
+//!   suffix, which makes them an `f16` or `f32`.
+//!
+//! The value of a float is always given as an `f64`, which is **rounded** to an
+//! `f32` by an `as` cast (e.g. `std::f64::consts::PI as f32` is
+//! `std::f32::consts::PI`). Rounding a decimal to an `f64` and then to an `f32`
+//! may differ from rounding it to an `f32` directly, so the range of an `f32`
+//! literal is checked against its text instead.
+//!
+//! A `-` in front of a literal is an operator rather than part of the literal,
+//! so the lexer accepts a signed integer one past its type's maximum (e.g.
+//! `128i8`), which is only in range once negated (see
+//! [`NumericValue::check_negated`]).
+//!
+//! As the digits of a hexadecimal float include `f`, a trailing `f32` or `f64`
+//! is part of its digits rather than a suffix.
+
+use std::fmt;
+
+use leafc_diagnostics::errors::{
+    LexicalError,
+    NumberError,
+};
+use leafc_utils::codemap::{
+    Span,
+    Spanned,
+};
+
+use crate::TokenKind;
+
+/// The **type suffix** of an integer literal (e.g. the `u8` of `255u8`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntSuffix {
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    U128,
+    I128,
+    Usize,
+    Isize,
+}
+
+impl IntSuffix {
+    /// All the integer suffixes, longest first (so that the first one a
+    /// literal ends with is its suffix).
+    const ALL: [Self; 12] = [
+        Self::U128,
+        Self::I128,
+        Self::Usize,
+        Self::Isize,
+        Self::U16,
+        Self::I16,
+        Self::U32,
+        Self::I32,
+        Self::U64,
+        Self::I64,
+        Self::U8,
+        Self::I8,
+    ];
+
+    /// Returns the suffix as it is written (e.g. `"u8"`).
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::U8 => "u8",
+            Self::I8 => "i8",
+            Self::U16 => "u16",
+            Self::I16 => "i16",
+            Self::U32 => "u32",
+            Self::I32 => "i32",
+            Self::U64 => "u64",
+            Self::I64 => "i64",
+            Self::U128 => "u128",
+            Self::I128 => "i128",
+            Self::Usize => "usize",
+            Self::Isize => "isize",
+        }
+    }
+
+    /// Returns the **largest magnitude** an integer with this suffix may have,
+    /// depending on whether it is `negative`.
+    ///
+    /// The largest magnitude of a negative signed integer is one more than its
+    /// type's maximum (e.g. `128` for `-128i8`). `usize` and `isize` are
+    /// assumed to be 64 bits wide.
+    pub const fn max_magnitude(self, negative: bool) -> u128 {
+        match (self, negative) {
+            (Self::U8, _) => u8::MAX as u128,
+            (Self::I8, false) => i8::MAX as u128,
+            (Self::I8, true) => i8::MIN.unsigned_abs() as u128,
+            (Self::U16, _) => u16::MAX as u128,
+            (Self::I16, false) => i16::MAX as u128,
+            (Self::I16, true) => i16::MIN.unsigned_abs() as u128,
+            (Self::U32, _) => u32::MAX as u128,
+            (Self::I32, false) => i32::MAX as u128,
+            (Self::I32, true) => i32::MIN.unsigned_abs() as u128,
+            (Self::U64 | Self::Usize, _) => u64::MAX as u128,
+            (Self::I64 | Self::Isize, false) => i64::MAX as u128,
+            (Self::I64 | Self::Isize, true) => i64::MIN.unsigned_abs() as u128,
+            (Self::U128, _) => u128::MAX,
+            (Self::I128, false) => i128::MAX as u128,
+            (Self::I128, true) => i128::MIN.unsigned_abs(),
+        }
+    }
+}
+
+impl fmt::Display for IntSuffix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The **type suffix** of a float literal (e.g. the `f32` of `1.5f32`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FloatSuffix {
+    /// Only infinity and NaN can be `f16`s (e.g. `∞16` or `nan16`).
+    F16,
+    F32,
+    F64,
+}
+
+impl FloatSuffix {
+    /// Returns the suffix as it is written (e.g. `"f32"`).
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::F16 => "f16",
+            Self::F32 => "f32",
+            Self::F64 => "f64",
+        }
+    }
+}
+
+impl fmt::Display for FloatSuffix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The **value** of a numeric literal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumericValue {
+    /// The value of an **integer** (e.g. `0xFF_u8`), along with its suffix.
+    ///
+    /// Only superscript integers may be **negative** (e.g. `⁻¹`), in which
+    /// case `value` is the magnitude of the integer.
+    Int { value: u128, negative: bool, suffix: Option<IntSuffix> },
+    /// The value of a **float** (e.g. `1.5e3f32`), along with its suffix.
+    Float { value: f64, suffix: Option<FloatSuffix> },
+}
+
+impl NumericValue {
+    /// Checks that an integer still fits in its type once the `-` operator in
+    /// front of it (if `negated`) is taken into account.
+    ///
+    /// [`parse_number`] can't tell whether a literal is negated, so it accepts
+    /// a signed integer one past its type's maximum (e.g. `128i8`), which is
+    /// only in range as `-128i8`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`NumberError::IntegerOutOfRange`] if the integer doesn't fit
+    /// in its type.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use leafc_lexer::{
+    ///     number::parse_number,
+    ///     TokenKind,
+    /// };
+    ///
+    /// let value = parse_number(TokenKind::INTEGER, "128i8").unwrap();
+    /// assert!(value.check_negated(true).is_ok());
+    /// assert!(value.check_negated(false).is_err());
+    /// ```
+    pub fn check_negated(&self, negated: bool) -> Result<(), NumberError> {
+        match *self {
+            NumericValue::Int { value, negative, suffix: Some(suffix) }
+                if value > suffix.max_magnitude(negative != negated) =>
+            {
+                Err(NumberError::IntegerOutOfRange(suffix.as_str()))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Evaluates the numeric literal `lexeme`, which was lexed as a token of the
+/// given `kind`.
+///
+/// # Errors
+///
+/// Returns a [`LexicalError::InvalidNumber`] spanning the whole literal if its
+/// value doesn't fit in its type (e.g. `256u8`, or an integer beyond
+/// `u128::MAX`). A signed integer may be one past its type's maximum, as it
+/// may be negated (see [`NumericValue::check_negated`]).
+///
+/// # Panics
+///
+/// Panics if `kind` is not a [`TokenKind::INTEGER`], [`TokenKind::FLOAT`],
+/// [`TokenKind::INTEGER_SUP`], [`TokenKind::FLOAT_SUP`] or a mathematical
+/// constant (e.g. [`TokenKind::PI`]).
+///
+/// # Example:
+///
+/// ```rust
+/// use leafc_lexer::{
+///     number::{
+///         parse_number,
+///         IntSuffix,
+///         NumericValue,
+///     },
+///     TokenKind,
+/// };
+///
+/// let value = parse_number(TokenKind::INTEGER, "0xFF_u8").unwrap();
+/// assert_eq!(value, NumericValue::Int {
+///     value:    255,
+///     negative: false,
+///     suffix:   Some(IntSuffix::U8),
+/// });
+///
+/// let value = parse_number(TokenKind::FLOAT_SUP, "²·⁵").unwrap();
+/// assert_eq!(value, NumericValue::Float { value: 2.5, suffix: None });
+///
+/// let value = parse_number(TokenKind::TAU, "τ").unwrap();
+/// assert_eq!(value, NumericValue::Float { value: std::f64::consts::TAU, suffix: None });
+///
+/// assert!(parse_number(TokenKind::INTEGER, "256u8").is_err());
+/// ```
+pub fn parse_number(kind: TokenKind, lexeme: &str) -> Result<NumericValue, Spanned<LexicalError>> {
+    let error = |error| Spanned {
+        span: Span::new(0..lexeme.len()),
+        node: LexicalError::InvalidNumber(error),
+    };
+
+    match kind {
+        TokenKind::INTEGER | TokenKind::INTEGER_SUP => {
+            let text = normalize(kind, lexeme);
+            let (negative, text) = strip_sign(&text);
+            let suffix = IntSuffix::ALL.into_iter().find(|suffix| text.ends_with(suffix.as_str()));
+            let text = &text[..text.len() - suffix.map_or(0, |suffix| suffix.as_str().len())];
+            let ty = suffix.map_or("u128", IntSuffix::as_str);
+
+            let (radix, digits) = strip_radix(text);
+            let value = parse_int(digits, radix)
+                .filter(|&value| suffix.map_or(true, |suffix| value <= suffix.max_magnitude(true)))
+                .ok_or_else(|| error(NumberError::IntegerOutOfRange(ty)))?;
+            Ok(NumericValue::Int { value, negative, suffix })
+        }
+        TokenKind::FLOAT | TokenKind::FLOAT_SUP => {
+            let text = normalize(kind, lexeme);
+            let (negative, text) = strip_sign(&text);
+            let (radix, text) = strip_radix(text);
+            let suffix = match radix {
+                16 => None,
+                _ if text.ends_with("f32") => Some(FloatSuffix::F32),
+                _ if text.ends_with("f64") => Some(FloatSuffix::F64),
+                _ => None,
+            };
+            let text = &text[..text.len() - suffix.map_or(0, |suffix| suffix.as_str().len())];
+            let ty = suffix.map_or("f64", FloatSuffix::as_str);
+
+            let value = parse_float(text, radix);
+            let in_range = match suffix {
+                // Round the decimal to the nearest `f32` directly, so that e.g.
+                // `3.4028235e38f32` (which is slightly above `f32::MAX`) is
+                // still in range.
+                Some(FloatSuffix::F32) if radix == 10 => {
+                    text.parse::<f32>().map_or(false, f32::is_finite)
+                }
+                Some(FloatSuffix::F32) => (value as f32).is_finite(),
+                _ => value.is_finite(),
+            };
+            if !in_range {
+                return Err(error(NumberError::FloatOutOfRange(ty)));
+            }
+            Ok(NumericValue::Float { value: if negative { -value } else { value }, suffix })
+        }
+        TokenKind::PI |
+        TokenKind::EULER |
+        TokenKind::PHI |
+        TokenKind::TAU |
+        TokenKind::CATALAN |
+        TokenKind::EULERGAMMA |
+        TokenKind::INF |
+        TokenKind::NAN => {
+            let (negative, text) = strip_sign(lexeme);
+            let suffix = match text {
+                _ if text.ends_with("16") => Some(FloatSuffix::F16),
+                _ if text.ends_with("32") => Some(FloatSuffix::F32),
+                _ => None,
+            };
+            let value = math_constant(kind);
+            Ok(NumericValue::Float { value: if negative { -value } else { value }, suffix })
+        }
+        kind => panic!("`{kind:?}` is not a numeric literal"),
+    }
+}
+
+/// Returns the **error** in the numeric literal `lexeme` of the given `kind`
+/// (see [`parse_number`]), if its value doesn't fit in its type.
+pub fn validate(kind: TokenKind, lexeme: &str) -> Option<Spanned<LexicalError>> {
+    parse_number(kind, lexeme).err()
+}
+
+/// Rewrites the **superscript** numeric literal `lexeme` (e.g. `⁻⁰ˣᶠᶠ` or
+/// `²·⁵`) in its regular form (e.g. `-0xff` or `2.5`).
+///
+/// Superscript octal literals are written `ᵒ⁰` (rather than `⁰ᵒ`), which is
+/// normalized to the regular `0o` prefix.
+///
+/// # Example:
+///
+/// ```rust
+/// use leafc_lexer::number::normalize_superscript;
+///
+/// assert_eq!(normalize_superscript("⁻⁰ˣᴬᶠ"), "-0xAf");
+/// assert_eq!(normalize_superscript("ᵒ⁰⁷·¹"), "0o7.1");
+/// ```
+pub fn normalize_superscript(lexeme: &str) -> String {
+    let text = lexeme
+        .chars()
+        .map(|c| match c {
+            '⁰' => '0',
+            '¹' => '1',
+            '²' => '2',
+            '³' => '3',
+            '⁴' => '4',
+            '⁵' => '5',
+            '⁶' => '6',
+            '⁷' => '7',
+            '⁸' => '8',
+            '⁹' => '9',
+            'ᴬ' => 'A',
+            'ᴮ' => 'B',
+            'ᶜ' => 'c',
+            'ᴰ' => 'D',
+            'ᴱ' => 'E',
+            'ᶠ' => 'f',
+            'ᵇ' => 'b',
+            'ᵒ' => 'o',
+            'ˣ' => 'x',
+            '·' => '.',
+            '⁺' => '+',
+            '⁻' => '-',
+            c => c,
+        })
+        .collect::<String>();
+
+    let (sign, digits) = text.split_at(usize::from(text.starts_with(['+', '-'])));
+    match digits.strip_prefix("o0") {
+        Some(digits) => format!("{sign}0o{digits}"),
+        None => text,
+    }
+}
+
+/// Returns the **value** of the mathematical constant `kind`.
+fn math_constant(kind: TokenKind) -> f64 {
+    match kind {
+        TokenKind::PI => std::f64::consts::PI,
+        TokenKind::EULER => std::f64::consts::E,
+        TokenKind::PHI => 1.618_033_988_749_895,
+        TokenKind::TAU => std::f64::consts::TAU,
+        TokenKind::CATALAN => 0.915_965_594_177_219,
+        TokenKind::EULERGAMMA => 0.577_215_664_901_532_9,
+        TokenKind::INF => f64::INFINITY,
+        _ => f64::NAN,
+    }
+}
+
+/// Returns the text of `lexeme` without superscripts or `_` separators.
+fn normalize(kind: TokenKind, lexeme: &str) -> String {
+    let text = match kind {
+        TokenKind::INTEGER_SUP | TokenKind::FLOAT_SUP => normalize_superscript(lexeme),
+        _ => lexeme.to_string(),
+    };
+    text.replace('_', "")
+}
+
+/// Splits a leading `+` or `-` (only found on superscript literals) off `text`,
+/// returning whether it was a `-`.
+fn strip_sign(text: &str) -> (bool, &str) {
+    match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    }
+}
+
+/// Splits the radix prefix (e.g. `0x`) off `text`, returning the radix.
+fn strip_radix(text: &str) -> (u32, &str) {
+    let radix = match text.get(..2) {
+        Some("0b" | "0B") => 2,
+        Some("0o" | "0O") => 8,
+        Some("0x" | "0X") => 16,
+        _ => return (10, text),
+    };
+    (radix, &text[2..])
+}
+
+/// Parses the `digits` of an integer, returning `None` if it overflows.
+fn parse_int(digits: &str, radix: u32) -> Option<u128> {
+    digits.chars().try_fold(0_u128, |value, c| {
+        value.checked_mul(u128::from(radix))?.checked_add(u128::from(c.to_digit(radix)?))
+    })
+}
+
+/// Parses the (unsigned and unsuffixed) `text` of a float.
+fn parse_float(text: &str, radix: u32) -> f64 {
+    if radix == 10 {
+        // The lexer only accepts valid decimal floats.
+        return text.parse().unwrap_or(f64::NAN);
+    }
+
+    let (mantissa, exponent) = text.split_once(['p', 'P']).unwrap_or((text, "0"));
+    let exponent = exponent.parse::<i32>().unwrap_or_default();
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let radix_f = f64::from(radix);
+    let int = int_part
+        .chars()
+        .filter_map(|c| c.to_digit(radix))
+        .fold(0.0, |value, digit| value * radix_f + f64::from(digit));
+    let frac = frac_part
+        .chars()
+        .rev()
+        .filter_map(|c| c.to_digit(radix))
+        .fold(0.0, |value, digit| (value + f64::from(digit)) / radix_f);
+    (int + frac) * 2_f64.powi(exponent)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions_sorted::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn int(value: u128, suffix: Option<IntSuffix>) -> NumericValue {
+        NumericValue::Int { value, negative: false, suffix }
+    }
+
+    fn float(value: f64, suffix: Option<FloatSuffix>) -> NumericValue {
+        NumericValue::Float { value, suffix }
+    }
+
+    #[rstest]
+    #[case(TokenKind::INTEGER, "0", int(0, None))]
+    #[case(TokenKind::INTEGER, "1_000_000", int(1_000_000, None))]
+    #[case(TokenKind::INTEGER, "255u8", int(255, Some(IntSuffix::U8)))]
+    #[case(TokenKind::INTEGER, "127_i8", int(127, Some(IntSuffix::I8)))]
+    #[case(TokenKind::INTEGER, "128i8", int(128, Some(IntSuffix::I8)))]
+    #[case(TokenKind::INTEGER, "0b1010_u32", int(10, Some(IntSuffix::U32)))]
+    #[case(TokenKind::INTEGER, "0o777", int(511, None))]
+    #[case(TokenKind::INTEGER, "0xFFu8", int(255, Some(IntSuffix::U8)))]
+    #[case(TokenKind::INTEGER, "0xdead_beef_usize", int(0xdead_beef, Some(IntSuffix::Usize)))]
+    #[case(TokenKind::INTEGER, "340282366920938463463374607431768211455", int(u128::MAX, None))]
+    #[case(TokenKind::INTEGER, "170141183460469231731687303715884105727i128", int(i128::MAX as u128, Some(IntSuffix::I128)))]
+    #[case(TokenKind::FLOAT, "0.", float(0.0, None))]
+    #[case(TokenKind::FLOAT, ".25", float(0.25, None))]
+    #[case(TokenKind::FLOAT, "1.e+0", float(1.0, None))]
+    #[case(TokenKind::FLOAT, "0.15e+0_2", float(15.0, None))]
+    #[case(TokenKind::FLOAT, "123.0_f32", float(123.0, Some(FloatSuffix::F32)))]
+    #[case(TokenKind::FLOAT, "3.4028235e38f32", float(3.4028235e38, Some(FloatSuffix::F32)))]
+    // Just below halfway between `f32::MAX` and infinity, but rounded up to it as an `f64`.
+    #[case(
+        TokenKind::FLOAT,
+        "340282356779733661637539395458142568447.0f32",
+        float(3.4028235677973366e38, Some(FloatSuffix::F32))
+    )]
+    #[case(TokenKind::FLOAT, "6.67428e-11f64", float(6.67428e-11, Some(FloatSuffix::F64)))]
+    #[case(TokenKind::FLOAT, "0b1.1", float(1.5, None))]
+    #[case(TokenKind::FLOAT, "0o7.4p1f32", float(15.0, Some(FloatSuffix::F32)))]
+    #[case(TokenKind::FLOAT, "0x1.8p-1", float(0.75, None))]
+    #[case(TokenKind::FLOAT, "0x0.f32", float(0.0 + 15.0 / 16.0 + 3.0 / 256.0 + 2.0 / 4096.0, None))]
+    #[case(TokenKind::INTEGER_SUP, "¹²", int(12, None))]
+    #[case(TokenKind::INTEGER_SUP, "⁻¹", NumericValue::Int { value: 1, negative: true, suffix: None })]
+    #[case(TokenKind::INTEGER_SUP, "⁰ᵇ¹⁰¹", int(5, None))]
+    #[case(TokenKind::INTEGER_SUP, "ᵒ⁰¹⁷", int(15, None))]
+    #[case(TokenKind::INTEGER_SUP, "⁰ˣᶠᶠ", int(255, None))]
+    #[case(TokenKind::FLOAT_SUP, "²·⁵", float(2.5, None))]
+    #[case(TokenKind::FLOAT_SUP, "⁻¹·", float(-1.0, None))]
+    #[case(TokenKind::FLOAT_SUP, "⁰ᵇ¹·¹", float(1.5, None))]
+    #[case(TokenKind::PI, "π", float(std::f64::consts::PI, None))]
+    #[case(TokenKind::PI, "pi", float(std::f64::consts::PI, None))]
+    #[case(TokenKind::EULER, "𝑒", float(std::f64::consts::E, None))]
+    #[case(TokenKind::PHI, "φ", float((1.0 + 5_f64.sqrt()) / 2.0, None))]
+    #[case(TokenKind::TAU, "𝝉", float(2.0 * std::f64::consts::PI, None))]
+    #[case(TokenKind::CATALAN, "catalan", float(0.915_965_594_177_219, None))]
+    #[case(TokenKind::EULERGAMMA, "γ", float(0.577_215_664_901_532_9, None))]
+    #[case(TokenKind::INF, "∞", float(f64::INFINITY, None))]
+    #[case(TokenKind::INF, "-Inf32", float(f64::NEG_INFINITY, Some(FloatSuffix::F32)))]
+    #[case(TokenKind::INF, "+∞16", float(f64::INFINITY, Some(FloatSuffix::F16)))]
+    fn test_parse_number(
+        #[case] kind: TokenKind,
+        #[case] lexeme: &str,
+        #[case] expected: NumericValue,
+    ) {
+        assert_eq!(parse_number(kind, lexeme), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("nan", None)]
+    #[case("-NaN16", Some(FloatSuffix::F16))]
+    #[case("NAN32", Some(FloatSuffix::F32))]
+    fn test_parse_nan(#[case] lexeme: &str, #[case] expected: Option<FloatSuffix>) {
+        let value = parse_number(TokenKind::NAN, lexeme);
+        assert!(
+            matches!(value, Ok(NumericValue::Float { value, suffix }) if value.is_nan() && suffix == expected)
+        );
+    }
+
+    #[rstest]
+    #[case(TokenKind::PI, "π", std::f32::consts::PI)]
+    #[case(TokenKind::EULER, "euler", std::f32::consts::E)]
+    #[case(TokenKind::TAU, "τ", std::f32::consts::TAU)]
+    fn test_math_constant_as_f32(
+        #[case] kind: TokenKind,
+        #[case] lexeme: &str,
+        #[case] expected: f32,
+    ) {
+        let Ok(NumericValue::Float { value, .. }) = parse_number(kind, lexeme) else {
+            panic!("`{lexeme}` is not a float");
+        };
+        assert_eq!(value as f32, expected);
+    }
+
+    #[rstest]
+    #[case(TokenKind::INTEGER, "256u8", NumberError::IntegerOutOfRange("u8"))]
+    #[case(TokenKind::INTEGER, "129i8", NumberError::IntegerOutOfRange("i8"))]
+    #[case(TokenKind::INTEGER, "0x1_0000_u16", NumberError::IntegerOutOfRange("u16"))]
+    #[case(
+        TokenKind::INTEGER,
+        "0b1_0000_0000_0000_0000_0000_0000_0000_0000i32",
+        NumberError::IntegerOutOfRange("i32")
+    )]
+    #[case(
+        TokenKind::INTEGER,
+        "340282366920938463463374607431768211456",
+        NumberError::IntegerOutOfRange("u128")
+    )]
+    #[case(
+        TokenKind::INTEGER_SUP,
+        "⁰ˣ¹⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰",
+        NumberError::IntegerOutOfRange("u128")
+    )]
+    #[case(TokenKind::FLOAT, "1.0e39f32", NumberError::FloatOutOfRange("f32"))]
+    #[case(TokenKind::FLOAT, "1.0e309", NumberError::FloatOutOfRange("f64"))]
+    fn test_parse_number_out_of_range(
+        #[case] kind: TokenKind,
+        #[case] lexeme: &str,
+        #[case] error: NumberError,
+    ) {
+        let expected =
+            Spanned { span: Span::new(0..lexeme.len()), node: LexicalError::InvalidNumber(error) };
+        assert_eq!(parse_number(kind, lexeme), Err(expected));
+    }
+
+    #[rstest]
+    #[case("-128i8", None)]
+    #[case("128i8", Some("i8"))]
+    #[case("127i8", None)]
+    #[case("-9223372036854775808i64", None)]
+    #[case("9223372036854775808i64", Some("i64"))]
+    #[case("-0x8000_0000_i32", None)]
+    #[case("0x8000_0000_i32", Some("i32"))]
+    #[case("-170141183460469231731687303715884105728i128", None)]
+    #[case("-255u8", None)]
+    fn test_check_negated(#[case] literal: &str, #[case] error: Option<&'static str>) {
+        let (negated, lexeme) = strip_sign(literal);
+        let value = parse_number(TokenKind::INTEGER, lexeme).unwrap();
+        assert_eq!(
+            value.check_negated(negated),
+            error.map_or(Ok(()), |ty| Err(NumberError::IntegerOutOfRange(ty)))
+        );
+    }
+
+    #[rstest]
+    #[case("⁰¹²³⁴⁵⁶⁷⁸⁹", "0123456789")]
+    #[case("⁺⁰ˣᴬᴮᶜᴰᴱᶠ", "+0xABcDEf")]
+    #[case("⁻ᵒ⁰⁷", "-0o7")]
+    #[case("⁰ᵇ¹·⁰", "0b1.0")]
+    fn test_normalize_superscript(#[case] lexeme: &str, #[case] expected: &str) {
+        assert_eq!(normalize_superscript(lexeme), expected);
+    }
+}
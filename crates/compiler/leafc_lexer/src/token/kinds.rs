@@ -1,5 +1,8 @@
 use derive_more::Display;
-use logos::Logos;
+use logos::{
+    Lexer,
+    Logos,
+};
 
 /// The **kind** of a token in the source code. This is the **primary** way
 /// that the lexer communicates with the parser.
@@ -22,7 +25,9 @@ pub enum TokenKind {
     WHITESPACE,
 
     #[doc = GENERAL!("IDENTIFIER")]
-    #[regex(r"_?[\p{XID_Start}\p{Emoji_Presentation}][\p{XID_Continue}\p{Emoji_Presentation}]*")]
+    // An apostrophe may join two parts of an identifier (e.g. `ligne_d'impression`), but never
+    // starts or ends one, so it can't be confused with a rune or a lifetime.
+    #[regex(r"_?[\p{XID_Start}\p{Emoji_Presentation}][\p{XID_Continue}\p{Emoji_Presentation}]*('[\p{XID_Continue}\p{Emoji_Presentation}]+)*")]
     IDENTIFIER,
 
     ////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -31,18 +36,26 @@ pub enum TokenKind {
     #[doc = COMMENTS!("COMMENT")]
     #[regex("//[^\r\n]*", priority = 2)]
     #[regex("//[^\n]*", priority = 1)]
+    #[token("/*", block_comment)]
+    #[token("/**/")]
+    #[token("/***", block_comment)]
     COMMENT,
 
     #[doc = COMMENTS!("DOC_COMMENT")]
     #[regex("///[^\r\n]*", priority = 4)]
     #[regex("///[^\n]*", priority = 3)]
+    #[token("/**", block_comment)]
+    #[token("/*!", block_comment)]
     DOC_COMMENT,
 
     ////////////////////////////////////////////////////////////////////////////////////////////////////////////////
     // Literals
     ////////////////////////////////////////////////////////////////////////////////////////////////////////////////
     #[doc = LITERALS!("RUNE")]
-    #[regex("b?'[^']*'")]
+    // A single (possibly escaped) character, so a lifetime followed by a later `'` on the
+    // same line (e.g. `<'a, 'b>`) isn't mistaken for a rune. The `XID_Start` class is repeated
+    // so logos can still tell a rune like `'ä'` apart from the lifetime `'ä`.
+    #[regex(r#"b?'([^'\\\n]|[\p{XID_Start}\p{Emoji_Presentation}]|\\(x[0-9a-fA-F][0-9a-fA-F]|u\{[0-9a-fA-F]*\}|.))'"#)]
     RUNE,
 
     #[doc = LITERALS!("STRING")]
@@ -50,19 +63,27 @@ pub enum TokenKind {
     STRING,
 
     #[doc = LITERALS!("RAW_STRING")]
-    #[regex(r#"r#[^#]*#"#)]
+    #[regex(r#"b?r#*""#, raw_string)]
     RAW_STRING,
 
+    #[doc = LITERALS!("FORMAT_STRING")]
+    // Format strings aren't matched by `logos` itself. Instead, the `TokenStream` splits up any
+    // `STRING` with a hole in it into the tokens below, and lexes the holes as usual.
+    FORMAT_STRING_START,
+
+    /// A **literal fragment** of a format string (e.g. the `Bonjour, ` of
+    /// `"Bonjour, {name}!"`).
+    STRING_FRAGMENT,
+
+    /// The closing `"` of a format string.
+    FORMAT_STRING_END,
+
     #[doc = LITERALS!("INTEGER")]
+    #[regex("[0-9][0-9_]*(u8|i8|u16|i16|u32|i32|u64|i64|u128|i128|usize|isize)?", priority = 1)] // decimal
+    #[regex("(0b|0B)[0-1][0-1_]*(u8|i8|u16|i16|u32|i32|u64|i64|u128|i128|usize|isize)?")] // binary
+    #[regex("(0o|0O)[0-7][0-7_]*(u8|i8|u16|i16|u32|i32|u64|i64|u128|i128|usize|isize)?")] // octal
     #[regex(
-        "[+-]?[0-9][0-9_]*(u8|i8|u16|i16|u32|i32|u64|i64|u128|i128|usize|isize)?",
-        priority = 1
-    )] // decimal
-    #[regex("[+-]?(0b|0B)[0-1][0-1_]*(u8|i8|u16|i16|u32|i32|u64|i64|u128|i128|usize|isize)?")] // binary
-    #[regex("[+-]?(0o|0O)[0-7][0-7_]*(u8|i8|u16|i16|u32|i32|u64|i64|u128|i128|usize|isize)?")] // octal
-    #[regex(
-        "[+-]?(0x|0X)[0-9a-fA-F][0-9a-fA-F_]*\
-         (u8|i8|u16|i16|u32|i32|u64|i64|u128|i128|usize|isize)?",
+        "(0x|0X)[0-9a-fA-F][0-9a-fA-F_]*(u8|i8|u16|i16|u32|i32|u64|i64|u128|i128|usize|isize)?",
         priority = 1
     )]
     // hexadecimal
@@ -70,16 +91,17 @@ pub enum TokenKind {
 
     #[doc = LITERALS!("FLOAT")]
     #[regex(
-        r#"[+-]?([0-9][0-9_]*)?\.([0-9][0-9_]*)?([eE][+-]?[0-9][0-9_]*)?(f32|f64)?"#,
+        r#"[0-9][0-9_]*\.([0-9][0-9_]*)?([eE][+-]?[0-9][0-9_]*)?(f32|f64)?"#,
         priority = 2
     )] // decimal
+    #[regex(r#"\.[0-9][0-9_]*([eE][+-]?[0-9][0-9_]*)?(f32|f64)?"#, priority = 2)] // decimal (no integer part)
     #[regex(
-        r#"[+-]?(0x|0X)[0-9a-fA-F][0-9a-fA-F_]*\.[0-9a-fA-F][0-9a-fA-F_]*([pP][+-]?[0-9][0-9_]?)?(f32|f64)?"#,
+        r#"(0x|0X)[0-9a-fA-F][0-9a-fA-F_]*\.[0-9a-fA-F][0-9a-fA-F_]*([pP][+-]?[0-9][0-9_]?)?(f32|f64)?"#,
         priority = 2
     )] // hexadecimal
-    #[regex(r#"[+-]?0b[0-1][0-1_]*\.[0-1][0-1_]*([pP][+-]?[0-9][0-9_]?)?(f32|f64)?"#)]
+    #[regex(r#"0b[0-1][0-1_]*\.[0-1][0-1_]*([pP][+-]?[0-9][0-9_]?)?(f32|f64)?"#)]
     // binary
-    #[regex(r#"[+-]?0o[0-7][0-7_]*\.[0-7][0-7_]*([pP][+-]?[0-9][0-9_]?)?(f32|f64)?"#)] // octal
+    #[regex(r#"0o[0-7][0-7_]*\.[0-7][0-7_]*([pP][+-]?[0-9][0-9_]?)?(f32|f64)?"#)] // octal
     FLOAT,
 
     #[doc = LITERALS!("LIFETIME")]
@@ -160,7 +182,6 @@ pub enum TokenKind {
 
     #[doc = MATHEMATICAL_SYMBOLS!("CATALAN")]
     #[token("catalan")]
-    #[token("K")]
     #[token("𝑘")]
     CATALAN,
 
@@ -180,6 +201,175 @@ pub enum TokenKind {
     #[regex("[+-]?(nan|NaN|NAN)(16|32)?")]
     NAN,
 
+    /// A **square root sign** `√`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Square root** (e.g. `√x`).
+    #[token("√")]
+    SQRT,
+
+    /// A **cube root sign** `∛`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Cube root** (e.g. `∛x`).
+    #[token("∛")]
+    CBRT,
+
+    /// A **fourth root sign** `∜`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Fourth root** (e.g. `∜x`).
+    #[token("∜")]
+    FOURTH_ROOT,
+
+    /// An **intersection sign** `∩`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Intersection** (e.g. `a ∩ b`).
+    #[token("∩")]
+    INTERSECTION,
+
+    /// A **union sign** `∪`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Union** (e.g. `a ∪ b`).
+    #[token("∪")]
+    UNION,
+
+    /// A **subset sign** `⊂`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Proper subset** (e.g. `a ⊂ b`).
+    #[token("⊂")]
+    SUBSET,
+
+    /// A **superset sign** `⊃`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Proper superset** (e.g. `a ⊃ b`).
+    #[token("⊃")]
+    SUPERSET,
+
+    /// A **not a subset sign** `⊄`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Not a proper subset** (e.g. `a ⊄ b`).
+    #[token("⊄")]
+    NOT_SUBSET,
+
+    /// A **not a superset sign** `⊅`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Not a proper superset** (e.g. `a ⊅ b`).
+    #[token("⊅")]
+    NOT_SUPERSET,
+
+    /// A **subset or equal sign** `⊆`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Subset** (e.g. `a ⊆ b`).
+    #[token("⊆")]
+    SUBSET_EQ,
+
+    /// A **superset or equal sign** `⊇`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Superset** (e.g. `a ⊇ b`).
+    #[token("⊇")]
+    SUPERSET_EQ,
+
+    /// An **approximately equal sign** `≈`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Approximate equality** (e.g. `0.1 + 0.2 ≈ 0.3`).
+    #[token("≈")]
+    APPROX_EQ,
+
+    /// An **identical to sign** `≡`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Identity** (e.g. `a ≡ b`).
+    #[token("≡")]
+    IDENTICAL,
+
+    /// A **proportional to sign** `∝`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Proportionality** (e.g. `force ∝ mass`).
+    #[token("∝")]
+    PROPORTIONAL,
+
+    /// An **angle sign** `∠`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Angle** (e.g. `∠z`).
+    #[token("∠")]
+    ANGLE,
+
+    /// An **integral sign** `∫`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Integral** (e.g. `∫f`).
+    #[token("∫")]
+    INTEGRAL,
+
+    /// A **contour integral sign** `∮`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Contour integral** (e.g. `∮f`).
+    #[token("∮")]
+    CONTOUR_INTEGRAL,
+
+    /// A **surface integral sign** `∯`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Surface integral** (e.g. `∯f`).
+    #[token("∯")]
+    SURFACE_INTEGRAL,
+
+    /// A **volume integral sign** `∰`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Volume integral** (e.g. `∰f`).
+    #[token("∰")]
+    VOLUME_INTEGRAL,
+
+    /// A **therefore sign** `∴`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Implication** (e.g. `raining ∴ wet`).
+    #[token("∴")]
+    THEREFORE,
+
+    /// A **because sign** `∵`.
+    ///
+    /// **Usage**:
+    ///
+    /// * **Converse implication** (e.g. `wet ∵ raining`).
+    #[token("∵")]
+    BECAUSE,
+
+    // region: keywords (generated from `keywords.toml` by the `sourcegen` test, do not edit by hand)
     ////////////////////////////////////////////////////////////////////////////////////////////////////////////////
     // Reserved keywords (e.g. `export`, `final`, `throw`, etc.) are not allowed
     // as identifiers.
@@ -188,7 +378,7 @@ pub enum TokenKind {
     #[token("abstract")] // English, Dutch
     #[token("abstracto")] // Spanish
     #[token("abstrait")] // French
-    #[token("abstrakt")] // German, Danish, Norwegian, Swedish
+    #[token("abstrakt")] // German, Swedish, Danish, Norwegian
     #[token("abstrato")] // Portuguese
     #[token("astratto")] // Italian
     #[token("abstrakti")] // Finnish
@@ -206,8 +396,8 @@ pub enum TokenKind {
     #[token("asynchron")] // German
     #[token("assíncrono")] // Portuguese
     #[token("asincrono")] // Italian
-    #[token("asynkron")] // Danish, Norwegian, Swedish
     #[token("asynchroon")] // Dutch
+    #[token("asynkron")] // Swedish, Danish, Norwegian
     #[token("asynk")] // Finnish
     #[token("асинхронный")] // Russian
     #[token("非同期")] // Japanese
@@ -223,10 +413,10 @@ pub enum TokenKind {
     #[token("erwarten")] // German
     #[token("aguardam")] // Portuguese
     #[token("attendere")] // Italian
+    #[token("wachten")] // Dutch
+    #[token("vänta")] // Swedish
     #[token("vente")] // Danish
     #[token("avvente")] // Norwegian
-    #[token("vänta")] // Swedish
-    #[token("wachten")] // Dutch
     #[token("odottaa")] // Finnish
     #[token("Ждите")] // Russian
     #[token("待つ")] // Japanese
@@ -236,11 +426,23 @@ pub enum TokenKind {
     AWAIT_KW,
 
     #[doc = RESERVED_KEYWORDS!("CASE")]
-    #[token("case")] // TODO: add more languages
+    #[token("case")] // English
+    #[token("caso")] // Spanish, Portuguese, Italian
+    #[token("cas")] // French
+    #[token("fall")] // German, Swedish
+    #[token("geval")] // Dutch
+    #[token("tilfælde")] // Danish
+    #[token("tilfelle")] // Norwegian
+    #[token("tapaus")] // Finnish
+    #[token("случай")] // Russian
+    #[token("ケース")] // Japanese
+    #[token("情况")] // Chinese
+    #[token("경우")] // Korean
+    #[token("kesi")] // Swahili
     CASE_KW,
 
     #[doc = RESERVED_KEYWORDS!("EXTERN")]
-    #[token("extern")] // English, Danish, Norwegian, Swedish, German, Dutch
+    #[token("extern")] // English, German, Dutch, Swedish, Danish, Norwegian
     #[token("externo")] // Spanish, Portuguese
     #[token("externe")] // French
     #[token("esterno")] // Italian
@@ -253,8 +455,8 @@ pub enum TokenKind {
     EXTERN_KW,
 
     #[doc = RESERVED_KEYWORDS!("FINAL")]
-    #[token("final")] // English, Spanish, Portuguese, Danish, Norwegian, Swedish, German, Dutch
-    #[token("finale")] // French
+    #[token("final")] // English, Spanish, German, Portuguese, Dutch, Swedish, Danish, Norwegian
+    #[token("finale")] // French, Italian
     #[token("finaali")] // Finnish
     #[token("конечный")] // Russian
     #[token("最終")] // Japanese
@@ -269,15 +471,17 @@ pub enum TokenKind {
     #[token("import")] // English
     #[token("importar")] // Spanish
     #[token("importer")] // French
+    #[token("importieren")] // German
+    #[token("importação")] // Portuguese
+    #[token("importare")] // Italian
     #[token("importeren")] // Dutch
     #[token("importera")] // Swedish
-    #[token("importere")] // Norwegian
-    #[token("importare")] // Italian
-    #[token("importação")] // Portuguese
+    #[token("importere")] // Danish, Norwegian
     #[token("tuonti")] // Finnish
     #[token("импорт")] // Russian
     #[token("インポート")] // Japanese
     #[token("导入")] // Chinese
+    #[token("가져오기")] // Korean
     #[token("kuagiza")] // Swahili
     IMPORT_KW,
 
@@ -285,12 +489,13 @@ pub enum TokenKind {
     #[token("let")] // English
     #[token("dejar")] // Spanish
     #[token("laisser")] // French
+    #[token("lass")] // German
+    #[token("deixar")] // Portuguese
+    #[token("lasciare")] // Italian
     #[token("laten")] // Dutch
     #[token("låta")] // Swedish
     #[token("laat")] // Danish
     #[token("la")] // Norwegian
-    #[token("lasciare")] // Italian
-    #[token("deixar")] // Portuguese
     #[token("jättää")] // Finnish
     #[token("пусть")] // Russian
     #[token("させる")] // Japanese
@@ -376,6 +581,7 @@ pub enum TokenKind {
     #[token("doorgaan")] // Dutch
     #[token("fortsätta")] // Swedish
     #[token("fortsætte")] // Danish
+    #[token("fortsette")] // Norwegian
     #[token("jatkaa")] // Finnish
     #[token("продолжать")] // Russian
     #[token("続行")] // Japanese
@@ -385,11 +591,10 @@ pub enum TokenKind {
     CONTINUE_KW,
 
     #[doc = KEYWORDS!("DEFAULT")]
-    #[token("default")]
-    // English
-    // #[token("aplazar")] // Spanish
-    // #[token("reporter")] // French
-    // #[token("verschieben")] // German
+    #[token("default")] // English
+    #[token("predeterminado")] // Spanish
+    #[token("défaut")] // French
+    #[token("standard")] // German
     #[token("valor normal")] // Portuguese
     #[token("valore normale")] // Italian
     #[token("normale waarde")] // Dutch
@@ -446,14 +651,15 @@ pub enum TokenKind {
     #[token("dyn")] // English
     #[token("dinámico")] // Spanish
     #[token("dynamique")] // French
+    #[token("dynamisch")] // German, Dutch
     #[token("dinâmico")] // Portuguese
     #[token("dinamico")] // Italian
-    #[token("dynamisch")] // German, Dutch
+    #[token("dynamisk")] // Swedish, Danish, Norwegian
     #[token("dynaaminen")] // Finnish
-    #[token("dynamisk")] // Danish, Norwegian, Swedish
     #[token("динамический")] // Russian
     #[token("ダイナミック")] // Japanese
     #[token("动态")] // Chinese
+    #[token("동적")] // Korean
     #[token("yenye nguvu")] // Swahili
     DYN_KW,
 
@@ -466,7 +672,7 @@ pub enum TokenKind {
     #[token("altrimenti")] // Italian
     #[token("anders")] // Dutch
     #[token("annars")] // Swedish
-    #[token("ellers")] // Danish
+    #[token("ellers")] // Danish, Norwegian
     #[token("muuten")] // Finnish
     #[token("иначе")] // Russian
     #[token("それ以外")] // Japanese
@@ -479,6 +685,7 @@ pub enum TokenKind {
     #[token("enum")] // English
     #[token("enumera")] // Spanish, Swedish
     #[token("énumération")] // French
+    #[token("aufzählung")] // German
     #[token("enumeração")] // Portuguese
     #[token("enumerazione")] // Italian
     #[token("enummer")] // Dutch, Danish, Norwegian
@@ -510,18 +717,26 @@ pub enum TokenKind {
     FALLTHROUGH_KW,
 
     #[doc = KEYWORDS!("FALSE")]
-    #[regex("false|False")] // English
-    #[regex("falso|Falso")] // Spanish, Italian, Portuguese
-    #[regex("faux|Faux")] // French
-    #[regex("falsch|Falsch")] // German
-    #[regex("vals|Vals")] // Dutch
-    #[regex("falsk|Falsk")] // Norwegian, Swedish, Danish
-    #[regex("väärä|Väärä")] // Finnish
+    #[token("false")] // English
+    #[token("False")] // English
+    #[token("falso")] // Spanish, Portuguese, Italian
+    #[token("Falso")] // Spanish, Portuguese, Italian
+    #[token("faux")] // French
+    #[token("Faux")] // French
+    #[token("falsch")] // German
+    #[token("Falsch")] // German
+    #[token("vals")] // Dutch
+    #[token("Vals")] // Dutch
+    #[token("falsk")] // Swedish, Danish, Norwegian
+    #[token("Falsk")] // Swedish, Danish, Norwegian
+    #[token("väärä")] // Finnish
+    #[token("Väärä")] // Finnish
     #[token("ЛОЖЬ")] // Russian
     #[token("間違い")] // Japanese
     #[token("错误的")] // Chinese
     #[token("거짓")] // Korean
-    #[regex("uongo|Uongo")] // Swahili
+    #[token("uongo")] // Swahili
+    #[token("Uongo")] // Swahili
     FALSE_KW,
 
     #[doc = KEYWORDS!("FN")]
@@ -529,9 +744,9 @@ pub enum TokenKind {
     #[token("función")] // Spanish
     #[token("fonction")] // French
     #[token("funktion")] // German, Swedish, Danish
-    #[token("functie")] // Dutch
-    #[token("funzione")] // Italian
     #[token("função")] // Portuguese
+    #[token("funzione")] // Italian
+    #[token("functie")] // Dutch
     #[token("funksjon")] // Norwegian
     #[token("toiminto")] // Finnish
     #[token("функция")] // Russian
@@ -543,8 +758,10 @@ pub enum TokenKind {
 
     #[doc = KEYWORDS!("FOR")]
     #[token("for")] // English, Danish, Norwegian, Finnish
-    #[token("para")] // Spanish
+    #[token("para")] // Spanish, Portuguese
     #[token("pour")] // French
+    #[token("für")] // German
+    #[token("per")] // Italian
     #[token("voor")] // Dutch
     #[token("för")] // Swedish
     #[token("для")] // Russian
@@ -557,6 +774,8 @@ pub enum TokenKind {
     #[doc = KEYWORDS!("IF")]
     #[token("if")] // English
     #[token("si")] // Spanish, French
+    #[token("wenn")] // German
+    #[token("se")] // Portuguese, Italian
     #[token("indien")] // Dutch
     #[token("om")] // Swedish
     #[token("hvis")] // Danish, Norwegian
@@ -572,11 +791,12 @@ pub enum TokenKind {
     #[token("impl")] // English
     #[token("implementos")] // Spanish
     #[token("met en oeuvre")] // French
+    #[token("implementiert")] // German
+    #[token("implementação")] // Portuguese
+    #[token("implemento")] // Italian
     #[token("implementeert")] // Dutch
     #[token("implementera")] // Swedish
-    #[token("implementerer")] // Norwegian
-    #[token("implemento")] // Italian
-    #[token("implementação")] // Portuguese
+    #[token("implementerer")] // Danish, Norwegian
     #[token("implementointi")] // Finnish
     #[token("имплементация")] // Russian
     #[token("実装")] // Japanese
@@ -588,13 +808,14 @@ pub enum TokenKind {
     /// The **keyword** `in`.
     ///
     /// **NOTE**: Since `in` directly translates to `i` in Swedish, Danish, and
-    /// Norwegian, we use the we don't actually use the `i` keyword in those
-    /// languages and instead use `in` such that `i` can be **used as a
-    /// variable name** commonly used for _indexing and iteration_.
+    /// Norwegian, we don't use the `i` keyword in those languages and instead use
+    /// `in` such that `i` can be **used as a variable name** commonly used for
+    /// _indexing and iteration_.
     #[doc = KEYWORDS!("IN")]
-    #[token("in")] // English, German, Dutch, Finnish
+    #[token("in")] // English, German, Italian, Dutch, Swedish, Danish, Norwegian, Finnish
     #[token("dentro de")] // Spanish
     #[token("dans")] // French
+    #[token("em")] // Portuguese
     #[token("в")] // Russian
     #[token("の中で")] // Japanese
     #[token("在")] // Chinese
@@ -609,8 +830,8 @@ pub enum TokenKind {
     #[token("lst")] // German
     #[token("é")] // Portuguese
     #[token("è")] // Italian
-    #[token("er")] // Danish, Norwegian
     #[token("är")] // Swedish
+    #[token("er")] // Danish, Norwegian
     #[token("on")] // Finnish
     #[token("является")] // Russian
     #[token("は")] // Japanese
@@ -623,6 +844,9 @@ pub enum TokenKind {
     #[token("isn't")] // English
     #[token("no es")] // Spanish
     #[token("n'est pas")] // French
+    #[token("ist nicht")] // German
+    #[token("não é")] // Portuguese
+    #[token("non è")] // Italian
     #[token("is niet")] // Dutch
     #[token("är inte")] // Swedish
     #[token("er ikke")] // Danish, Norwegian
@@ -638,12 +862,13 @@ pub enum TokenKind {
     #[token("loop")] // English
     #[token("bucle")] // Spanish
     #[token("boucle")] // French
+    #[token("schleife")] // German
+    #[token("laço")] // Portuguese
+    #[token("ciclo continuo")] // Italian
     #[token("lussen")] // Dutch
     #[token("slinga")] // Swedish
     #[token("sløjfe")] // Danish
     #[token("løkke")] // Norwegian
-    #[token("ciclo continuo")] // Italian
-    #[token("laço")] // Portuguese
     #[token("silmukka")] // Finnish
     #[token("петля")] // Russian
     #[token("ループ")] // Japanese
@@ -656,11 +881,12 @@ pub enum TokenKind {
     #[token("match")] // English
     #[token("partido")] // Spanish
     #[token("correspondre")] // French
+    #[token("abgleichen")] // German
+    #[token("partida")] // Portuguese
+    #[token("partita")] // Italian
     #[token("matchen")] // Dutch
     #[token("matcha")] // Swedish
     #[token("matche")] // Danish, Norwegian
-    #[token("partita")] // Italian
-    #[token("partida")] // Portuguese
     #[token("ottelu")] // Finnish
     #[token("совпадение")] // Russian
     #[token("マッチ")] // Japanese
@@ -670,31 +896,44 @@ pub enum TokenKind {
     MATCH_KW,
 
     #[doc = KEYWORDS!("MISSING")]
-    #[regex("missing|Missing")] // English
-    #[regex("faltante|Faltante")] // Spanish
-    #[regex("manquant|Manquant")] // French
-    #[regex("missend|Missend")] // Dutch
-    #[regex("fehlen|Fehlen")] // German
-    #[regex("saknas|Saknas")] // Swedish
-    #[regex("mangler|Mangler")] // Danish
-    #[regex("savnet|Savnet")] // Norwegian
-    #[regex("mancante|Mancante")] // Italian
-    #[regex("ausente|Ausente")] // Portuguese
-    #[regex("puuttuu|Puuttuu")] // Finnish
-    #[regex("отсутствует|Отсутствует")] // Russian
+    #[token("missing")] // English
+    #[token("Missing")] // English
+    #[token("faltante")] // Spanish
+    #[token("Faltante")] // Spanish
+    #[token("manquant")] // French
+    #[token("Manquant")] // French
+    #[token("fehlen")] // German
+    #[token("Fehlen")] // German
+    #[token("ausente")] // Portuguese
+    #[token("Ausente")] // Portuguese
+    #[token("mancante")] // Italian
+    #[token("Mancante")] // Italian
+    #[token("missend")] // Dutch
+    #[token("Missend")] // Dutch
+    #[token("saknas")] // Swedish
+    #[token("Saknas")] // Swedish
+    #[token("mangler")] // Danish
+    #[token("Mangler")] // Danish
+    #[token("savnet")] // Norwegian
+    #[token("Savnet")] // Norwegian
+    #[token("puuttuu")] // Finnish
+    #[token("Puuttuu")] // Finnish
+    #[token("отсутствует")] // Russian
+    #[token("Отсутствует")] // Russian
     #[token("欠けている")] // Japanese
     #[token("缺失")] // Chinese
     #[token("누락")] // Korean
-    #[regex("hakuna|Hakuna")] // Swahili
+    #[token("hakuna")] // Swahili
+    #[token("Hakuna")] // Swahili
     MISSING_KW,
 
     #[doc = KEYWORDS!("MOD")]
     #[token("mod")] // English
+    #[token("módulo")] // Spanish, Portuguese
     #[token("module")] // French
-    #[token("moduul")] // Dutch
-    #[token("modul")] // Danish, Norwegian, Swedish, German
+    #[token("modul")] // German, Swedish, Danish, Norwegian
     #[token("modulo")] // Italian
-    #[token("módulo")] // Spanish, Portuguese
+    #[token("moduul")] // Dutch
     #[token("moduuli")] // Finnish
     #[token("модуль")] // Russian
     #[token("モジュール")] // Japanese
@@ -707,10 +946,11 @@ pub enum TokenKind {
     #[token("move")] // English
     #[token("mover")] // Spanish, Portuguese
     #[token("déplacer")] // French
+    #[token("bewegen")] // German
+    #[token("muovere")] // Italian
     #[token("verplaatsen")] // Dutch
     #[token("flytta")] // Swedish
     #[token("flytte")] // Danish, Norwegian
-    #[token("muovere")] // Italian
     #[token("siirtää")] // Finnish
     #[token("перемещение")] // Russian
     #[token("移動")] // Japanese
@@ -723,11 +963,12 @@ pub enum TokenKind {
     #[token("mut")] // English
     #[token("mudable")] // Spanish
     #[token("mutable")] // French
+    #[token("veränderlich")] // German
+    #[token("mutável")] // Portuguese
+    #[token("mutabile")] // Italian
     #[token("veranderlijk")] // Dutch
     #[token("muterbar")] // Swedish, Danish
     #[token("endringsbar")] // Norwegian
-    #[token("mutabile")] // Italian
-    #[token("mutável")] // Portuguese
     #[token("muuttuva")] // Finnish
     #[token("мутабельный")] // Russian
     #[token("ミュータブル")] // Japanese
@@ -740,12 +981,12 @@ pub enum TokenKind {
     #[token("not")] // English
     #[token("no")] // Spanish
     #[token("ne")] // French
-    #[token("niet")] // Dutch
     #[token("nicht")] // German
+    #[token("não")] // Portuguese
+    #[token("non")] // Italian
+    #[token("niet")] // Dutch
     #[token("inte")] // Swedish
     #[token("ikke")] // Danish, Norwegian
-    #[token("non")] // Italian
-    #[token("não")] // Portuguese
     #[token("ei")] // Finnish
     #[token("нет")] // Russian
     #[token("ない")] // Japanese
@@ -757,11 +998,11 @@ pub enum TokenKind {
     /// The **keyword** `or`.
     #[doc = KEYWORDS!("OR")]
     #[token("or")] // English
+    #[token("o")] // Spanish, Italian
     #[token("ou")] // French, Portuguese
     #[token("oder")] // German
     #[token("of")] // Dutch
     #[token("eller")] // Swedish, Danish, Norwegian
-    #[token("o")] // Italian, Spanish
     #[token("tai")] // Finnish
     #[token("или")] // Russian
     #[token("または")] // Japanese
@@ -774,10 +1015,10 @@ pub enum TokenKind {
     #[token("pkg")] // English
     #[token("paquete")] // Spanish
     #[token("paquet")] // French
-    #[token("pakket")] // Dutch
-    #[token("paket")] // Swedish, Danish, Norwegian
-    #[token("pacchetto")] // Italian
+    #[token("paket")] // German, Swedish, Danish, Norwegian
     #[token("pacote")] // Portuguese
+    #[token("pacchetto")] // Italian
+    #[token("pakket")] // Dutch
     #[token("paketti")] // Finnish
     #[token("пакет")] // Russian
     #[token("パッケージ")] // Japanese
@@ -791,9 +1032,10 @@ pub enum TokenKind {
     #[token("pub")] // English
     #[token("público")] // Spanish, Portuguese
     #[token("publique")] // French
+    #[token("öffentlich")] // German
+    #[token("pubblico")] // Italian
     #[token("publiek")] // Dutch
     #[token("publik")] // Swedish, Danish, Norwegian
-    #[token("pubblico")] // Italian
     #[token("julkinen")] // Finnish
     #[token("публичный")] // Russian
     #[token("パブリック")] // Japanese
@@ -806,10 +1048,11 @@ pub enum TokenKind {
     #[token("return")] // English
     #[token("regreso")] // Spanish
     #[token("retour")] // French
-    #[token("terug")] // Dutch
-    #[token("retur")] // Danish, Norwegian, Swedish
-    #[token("ritorno")] // Italian
+    #[token("zurückgeben")] // German
     #[token("retorno")] // Portuguese
+    #[token("ritorno")] // Italian
+    #[token("terug")] // Dutch
+    #[token("retur")] // Swedish, Danish, Norwegian
     #[token("paluu")] // Finnish
     #[token("возврат")] // Russian
     #[token("リターン")] // Japanese
@@ -820,13 +1063,14 @@ pub enum TokenKind {
 
     #[doc = KEYWORDS!("SELF_VALUE")]
     #[token("self")] // English
-    #[token("se")] // Spanish
+    #[token("sí")] // Spanish
     #[token("soi")] // French
+    #[token("selbst")] // German
+    #[token("auto")] // Portuguese
+    #[token("sé")] // Italian
     #[token("zelf")] // Dutch
     #[token("själv")] // Swedish
     #[token("selv")] // Danish, Norwegian
-    #[token("sé")] // Italian
-    #[token("auto")] // Portuguese
     #[token("itse")] // Finnish
     #[token("сам")] // Russian
     #[token("セルフ")] // Japanese
@@ -837,13 +1081,14 @@ pub enum TokenKind {
 
     #[doc = KEYWORDS!("SELF_TYPE")]
     #[token("Self")] // English
-    #[token("Se")] // Spanish
+    #[token("Sí")] // Spanish
     #[token("Soi")] // French
+    #[token("Selbst")] // German
+    #[token("Auto")] // Portuguese
+    #[token("Sé")] // Italian
     #[token("Zelf")] // Dutch
     #[token("Själv")] // Swedish
     #[token("Selv")] // Danish, Norwegian
-    #[token("Sé")] // Italian
-    #[token("Auto")] // Portuguese
     #[token("Itse")] // Finnish
     #[token("Сам")] // Russian
     #[token("セルフタイプ")] // Japanese
@@ -856,9 +1101,9 @@ pub enum TokenKind {
     #[token("static")] // English
     #[token("estático")] // Spanish, Portuguese
     #[token("statique")] // French
-    #[token("statisch")] // Dutch
-    #[token("statisk")] // Swedish, Danish, Norwegian
+    #[token("statisch")] // German, Dutch
     #[token("statico")] // Italian
+    #[token("statisk")] // Swedish, Danish, Norwegian
     #[token("staattinen")] // Finnish
     #[token("статический")] // Russian
     #[token("スタティック")] // Japanese
@@ -871,10 +1116,10 @@ pub enum TokenKind {
     #[token("struct")] // English
     #[token("estructura")] // Spanish
     #[token("structure")] // French
-    #[token("structuur")] // Dutch
-    #[token("struktur")] // Swedish, Danish, Norwegian
-    #[token("struttura")] // Italian
+    #[token("struktur")] // German, Swedish, Danish, Norwegian
     #[token("estrutura")] // Portuguese
+    #[token("struttura")] // Italian
+    #[token("structuur")] // Dutch
     #[token("rakenne")] // Finnish
     #[token("структура")] // Russian
     #[token("ストラクチャ")] // Japanese
@@ -884,11 +1129,11 @@ pub enum TokenKind {
     STRUCT_KW,
 
     #[doc = KEYWORDS!("SUPER")]
-    #[token("super")] // English
+    #[token("super")] // English, German
+    #[token("superior")] // Spanish, Portuguese, Swedish, Danish, Norwegian
     #[token("supérieur")] // French
-    #[token("superieur")] // Dutch
-    #[token("superior")] // Danish, Norwegian, Swedish, Portuguese, Spanish
     #[token("superiore")] // Italian
+    #[token("superieur")] // Dutch
     #[token("ylhäältä")] // Finnish
     #[token("супер")] // Russian
     #[token("スーパー")] // Japanese
@@ -900,10 +1145,11 @@ pub enum TokenKind {
     #[doc = KEYWORDS!("TRAIT")]
     #[token("trait")] // English, French
     #[token("rasgo")] // Spanish
-    #[token("eigenschap")] // Dutch
-    #[token("egenskap")] // Danish, Norwegian, Swedish
-    #[token("tratto")] // Italian
+    #[token("merkmal")] // German
     #[token("característica")] // Portuguese
+    #[token("tratto")] // Italian
+    #[token("eigenschap")] // Dutch
+    #[token("egenskap")] // Swedish, Danish, Norwegian
     #[token("piirre")] // Finnish
     #[token("характеристика")] // Russian
     #[token("トレイト")] // Japanese
@@ -913,18 +1159,29 @@ pub enum TokenKind {
     TRAIT_KW,
 
     #[doc = KEYWORDS!("TRUE")]
-    #[regex("true|True")] // English
-    #[regex("verdadero|Verdadero")] // Spanish
-    #[regex("vrai|Vrai")] // French
-    #[regex("wahr|Wahr")] // German
-    #[regex("verdadeiro|Verdadeiro")] // Portuguese
-    #[regex("vero|Vero")] // Italian
-    #[regex("waar|Waar")] // Dutch
-    #[regex("sann|Sann")] // Swedish
-    #[regex("rigtigt|Rigtigt")] // Danish
-    #[regex("ekte|Ekte")] // Norwegian
-    #[regex("totta|Totta")] // Finnish
-    #[regex("истинный")] // Russian
+    #[token("true")] // English
+    #[token("True")] // English
+    #[token("verdadero")] // Spanish
+    #[token("Verdadero")] // Spanish
+    #[token("vrai")] // French
+    #[token("Vrai")] // French
+    #[token("wahr")] // German
+    #[token("Wahr")] // German
+    #[token("verdadeiro")] // Portuguese
+    #[token("Verdadeiro")] // Portuguese
+    #[token("vero")] // Italian
+    #[token("Vero")] // Italian
+    #[token("waar")] // Dutch
+    #[token("Waar")] // Dutch
+    #[token("sann")] // Swedish
+    #[token("Sann")] // Swedish
+    #[token("rigtigt")] // Danish
+    #[token("Rigtigt")] // Danish
+    #[token("ekte")] // Norwegian
+    #[token("Ekte")] // Norwegian
+    #[token("totta")] // Finnish
+    #[token("Totta")] // Finnish
+    #[token("истинный")] // Russian
     #[token("真実")] // Japanese
     #[token("真的")] // Chinese
     #[token("진실")] // Korean
@@ -933,9 +1190,9 @@ pub enum TokenKind {
 
     #[doc = KEYWORDS!("TYPE")]
     #[token("type")] // English, Dutch
-    #[token("taper")] // French
     #[token("tipo")] // Spanish, Portuguese, Italian
-    #[token("typ")] // Swedish, Danish, Norwegian, German
+    #[token("taper")] // French
+    #[token("typ")] // German, Swedish, Danish, Norwegian
     #[token("tyyppi")] // Finnish
     #[token("тип")] // Russian
     #[token("タイプ")] // Japanese
@@ -949,10 +1206,10 @@ pub enum TokenKind {
     #[token("inseguro")] // Spanish, Portuguese
     #[token("insécurisé")] // French
     #[token("unsicher")] // German
+    #[token("insicuro")] // Italian
     #[token("onveilig")] // Dutch
     #[token("osäker")] // Swedish
     #[token("usikker")] // Danish, Norwegian
-    #[token("insicuro")] // Italian
     #[token("epävarma")] // Finnish
     #[token("небезопасный")] // Russian
     #[token("アンセーフ")] // Japanese
@@ -965,12 +1222,13 @@ pub enum TokenKind {
     #[token("use")] // English
     #[token("utilizar")] // Spanish
     #[token("utiliser")] // French
+    #[token("verwenden")] // German
+    #[token("usar")] // Portuguese
+    #[token("usare")] // Italian
     #[token("gebruiken")] // Dutch
     #[token("använda")] // Swedish
-    #[token("bruke")] // Norwegian
     #[token("benytte")] // Danish
-    #[token("usare")] // Italian
-    #[token("usar")] // Portuguese
+    #[token("bruke")] // Norwegian
     #[token("käyttää")] // Finnish
     #[token("использовать")] // Russian
     #[token("使用する")] // Japanese
@@ -981,14 +1239,14 @@ pub enum TokenKind {
 
     #[doc = KEYWORDS!("WHERE")]
     #[token("where")] // English
-    #[token("où")] // French
     #[token("dónde")] // Spanish
+    #[token("où")] // French
     #[token("wo")] // German
+    #[token("onde")] // Portuguese
+    #[token("dove")] // Italian
     #[token("waarin")] // Dutch
-    #[token("hvor")] // Danish, Norwegian
     #[token("var")] // Swedish
-    #[token("dove")] // Italian
-    #[token("onde")] // Portuguese
+    #[token("hvor")] // Danish, Norwegian
     #[token("missä")] // Finnish
     #[token("где")] // Russian
     #[token("どこ")] // Japanese
@@ -1001,12 +1259,12 @@ pub enum TokenKind {
     #[token("while")] // English
     #[token("mientras")] // Spanish
     #[token("tant que")] // French
+    #[token("während")] // German
+    #[token("enquanto")] // Portuguese
+    #[token("mentre")] // Italian
     #[token("terwijl")] // Dutch
     #[token("medan")] // Swedish
     #[token("mens")] // Danish, Norwegian
-    #[token("mentre")] // Italian
-    #[token("enquanto")] // Portuguese
-    #[token("während")] // German
     #[token("samalla")] // Finnish
     #[token("пока")] // Russian
     #[token("間")] // Japanese
@@ -1017,12 +1275,12 @@ pub enum TokenKind {
 
     #[doc = KEYWORDS!("YIELD")]
     #[token("yield")] // English
-    #[token("rendement")] // French, Dutch
     #[token("rendimiento")] // Spanish
+    #[token("rendement")] // French, Dutch
+    #[token("rendite")] // German
+    #[token("rendimento")] // Portuguese, Italian
     #[token("avkastning")] // Swedish, Norwegian
     #[token("afkastning")] // Danish
-    #[token("rendimento")] // Italian, Portuguese
-    #[token("rendite")] // German
     #[token("tuotto")] // Finnish
     #[token("доход")] // Russian
     #[token("収益")] // Japanese
@@ -1031,6 +1289,8 @@ pub enum TokenKind {
     #[token("kupato")] // Swahili
     YIELD_KW,
 
+    // endregion: keywords
+
     ////////////////////////////////////////////////////////////////////////////////////////////////////////////////
     // Punctuation
     ////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1126,7 +1386,7 @@ pub enum TokenKind {
     #[token("|")]
     PIPE,
 
-    /// A **double ampersand** `&&`.
+    /// A **double ampersand** `&&`, or its mathematical spelling `∧`.
     ///
     /// **Usage**:
     ///
@@ -1135,15 +1395,17 @@ pub enum TokenKind {
     /// * **References**.
     /// * **Reference patterns**.
     #[token("&&")]
+    #[token("∧")]
     DOUBLE_AMPERSAND,
 
-    /// A **double pipe** `||`.
+    /// A **double pipe** `||`, or its mathematical spelling `∨`.
     ///
     /// **Usage**:
     ///
     /// * **Lazy OR**.
     /// * _potentially_ **closures**.
     #[token("||")]
+    #[token("∨")]
     DOUBLE_PIPE,
 
     /// A **shift left** `<<`.
@@ -1261,12 +1523,13 @@ pub enum TokenKind {
     #[token("==")]
     EQEQ,
 
-    /// A **not equal sign** `!=`.
+    /// A **not equal sign** `!=`, or its mathematical spelling `≠`.
     ///
     /// **Usage**:
     ///
     /// * **Inequality**.
     #[token("!=")]
+    #[token("≠")]
     NE,
 
     /// A **greater than sign** `>`.
@@ -1288,20 +1551,23 @@ pub enum TokenKind {
     #[token("<")]
     LT,
 
-    /// A **greater than or equal to sign** `>=`.
+    /// A **greater than or equal to sign** `>=`, or its mathematical spelling
+    /// `≥`.
     ///
     /// **Usage**:
     /// * **Greater than or equal to**.
     /// * **Generics**.
     #[token(">=")]
+    #[token("≥")]
     GE,
 
-    /// A **less than or equal to sign** `<=`.
+    /// A **less than or equal to sign** `<=`, or its mathematical spelling `≤`.
     ///
     /// **Usage**:
     ///
     /// * **Less than or equal to**.
     #[token("<=")]
+    #[token("≤")]
     LE,
 
     /// An **at sign** `@`.
@@ -1432,12 +1698,13 @@ pub enum TokenKind {
     #[token("?")]
     QMARK,
 
-    /// A **tilde** `~`.
+    /// A **tilde** `~`, or its mathematical spelling `∼`.
     ///
     /// **Usage**:
     ///
     /// * **Reserved for future use**.
     #[token("~")]
+    #[token("∼")]
     TILDE,
 
     ////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1493,6 +1760,59 @@ pub enum TokenKind {
     DOUBLE_STAR,
 }
 
+/// Bumps the lexer past the rest of a **block comment**, including any nested
+/// block comments.
+///
+/// The comment is scanned from just after its opening `/*`, as the matched
+/// prefix may already include part of it (e.g. the `*` in `/***/`, which is
+/// closed by `*/`). An unterminated block comment runs to the end of the input
+/// (see [`block_comment_len`]).
+fn block_comment(lex: &mut Lexer<TokenKind>) {
+    let start = lex.span().start + "/*".len();
+    let text = &lex.source()[start..];
+    let len = block_comment_len(text).unwrap_or(text.len());
+    lex.bump(len - (lex.span().end - start));
+}
+
+/// Returns the length of the rest of a **block comment** in `text` (i.e. after
+/// its opening `/*`), up to and including its closing `*/`.
+///
+/// Block comments **nest**, so every `/*` must be matched by its own `*/`.
+/// Returns `None` if the block comment is never closed.
+pub(crate) fn block_comment_len(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 1;
+    let mut i = 0;
+    while i + 1 < bytes.len() {
+        match &bytes[i..i + 2] {
+            b"/*" => {
+                depth += 1;
+                i += 2;
+            }
+            b"*/" => {
+                depth -= 1;
+                i += 2;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// Bumps the lexer past the rest of a **raw string**, up to the first `"`
+/// followed by as many `#`s as the raw string was opened with.
+///
+/// An unterminated raw string runs to the end of the input.
+fn raw_string(lex: &mut Lexer<TokenKind>) {
+    let hashes = lex.slice().matches('#').count();
+    let closing = format!("\"{}", "#".repeat(hashes));
+    let rest = lex.remainder();
+    lex.bump(rest.find(&closing).map_or(rest.len(), |i| i + closing.len()));
+}
+
 impl TokenKind {
     pub fn is_whitespace(&self) -> bool {
         matches!(self, TokenKind::WHITESPACE)
@@ -1501,4 +1821,17 @@ impl TokenKind {
     pub fn is_comment(&self) -> bool {
         matches!(self, TokenKind::COMMENT | TokenKind::DOC_COMMENT)
     }
+
+    /// Returns `true` if the token is a **keyword** (i.e. its spelling depends
+    /// on the spoken language it's written in, such as `fn` and `fonction`).
+    pub fn is_keyword(&self) -> bool {
+        !matches!(self, generic_tokens!())
+    }
+
+    pub fn is_number(&self) -> bool {
+        matches!(
+            self,
+            TokenKind::INTEGER | TokenKind::FLOAT | TokenKind::INTEGER_SUP | TokenKind::FLOAT_SUP
+        )
+    }
 }
@@ -85,6 +85,9 @@ macro_rules! generic_tokens {
             TokenKind::RUNE |
             TokenKind::STRING |
             TokenKind::RAW_STRING |
+            TokenKind::FORMAT_STRING_START |
+            TokenKind::STRING_FRAGMENT |
+            TokenKind::FORMAT_STRING_END |
             TokenKind::INTEGER |
             TokenKind::FLOAT |
             TokenKind::LIFETIME |
@@ -98,6 +101,27 @@ macro_rules! generic_tokens {
             TokenKind::EULERGAMMA |
             TokenKind::INF |
             TokenKind::NAN |
+            TokenKind::SQRT |
+            TokenKind::CBRT |
+            TokenKind::FOURTH_ROOT |
+            TokenKind::INTERSECTION |
+            TokenKind::UNION |
+            TokenKind::SUBSET |
+            TokenKind::SUPERSET |
+            TokenKind::NOT_SUBSET |
+            TokenKind::NOT_SUPERSET |
+            TokenKind::SUBSET_EQ |
+            TokenKind::SUPERSET_EQ |
+            TokenKind::APPROX_EQ |
+            TokenKind::IDENTICAL |
+            TokenKind::PROPORTIONAL |
+            TokenKind::ANGLE |
+            TokenKind::INTEGRAL |
+            TokenKind::CONTOUR_INTEGRAL |
+            TokenKind::SURFACE_INTEGRAL |
+            TokenKind::VOLUME_INTEGRAL |
+            TokenKind::THEREFORE |
+            TokenKind::BECAUSE |
             TokenKind::DEFINE |
             TokenKind::PLUS |
             TokenKind::MINUS |
@@ -138,19 +162,24 @@ macro_rules! generic_tokens {
             TokenKind::SEMICOLON |
             TokenKind::COLON |
             TokenKind::PATHSEP |
-            TokenKind::RARROW
-    }; /* ERROR, WHITESPACE, IDENTIFIER, COMMENT, DOC_COMMENT, RUNE,
-        *         STRING, RAW_STRING, INTEGER, FLOAT, LIFETIME, INTEGER_SUP,
-        *         FLOAT_SUP, PI, EULER, PHI, TAU, CATALAN, EULERGAMMA,
-        *         INF, NAN, DEFINE, PLUS, MINUS, STAR, SLASH, PERCENT, CARET,
-        *         BANG, AMPERSAND, PIPE, DOUBLE_AMPERSAND, DOUBLE_PIPE, SHL,
-        *         SHR, PLUS_EQ, MINUS_EQ, STAR_EQ, SLASH_EQ, PERCENT_EQ,
-        *         CARET_EQ, AMPERSAND_EQ, PIPE_EQ, SHL_EQ, SHR_EQ, EQ, EQEQ,
-        *         NE, GT, LT, GE, LE, AT, UNDERSCORE, DOT, DOTDOT, DOTDOTEQ,
-        *         COMMA, SEMICOLON, COLON, PATHSEP, RARROW, FATARROW, HASH,
-        *         DOLLAR, QMARK, TILDE, L_BRACKET, R_BRACKET, L_PAREN, R_PAREN,
-        *         L_BRACE, R_BRACE, L_PAREN_SUPERSCRIPT, R_PAREN_SUPERSCRIPT,
-        *         L_ARROW, DOUBLE_STAR */
+            TokenKind::RARROW |
+            TokenKind::FATARROW |
+            TokenKind::HASH |
+            TokenKind::DOLLAR |
+            TokenKind::QMARK |
+            TokenKind::TILDE |
+            TokenKind::L_BRACKET |
+            TokenKind::R_BRACKET |
+            TokenKind::L_PAREN |
+            TokenKind::R_PAREN |
+            TokenKind::L_BRACE |
+            TokenKind::R_BRACE |
+            TokenKind::L_PAREN_SUPERSCRIPT |
+            TokenKind::R_PAREN_SUPERSCRIPT |
+            TokenKind::L_ARROW |
+            TokenKind::DOUBLE_STAR |
+            TokenKind::EOF
+    };
 }
 
 /// Macro used for matching a **language-specific token** within the
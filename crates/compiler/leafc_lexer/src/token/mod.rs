@@ -17,6 +17,8 @@ use owo_colors::OwoColorize;
 use smol_str::SmolStr;
 use typed_builder::TypedBuilder;
 
+use crate::language_check::keywords;
+
 #[cfg(feature = "serde")]
 use serde::{
     Deserialize,
@@ -95,6 +97,45 @@ impl Token {
         Self::new(TokenKind::EOF, SmolStr::new("EOF_TOMBSTONE"), Span::new(0..0))
     }
 
+    /// Returns the **spoken languages** the token is spelled in, if it is a
+    /// keyword (e.g. `pour` is French). Other tokens (e.g. identifiers and
+    /// punctuation) aren't specific to any spoken language.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use leafc_cfg::lang::LanguageKind;
+    /// use leafc_lexer::{
+    ///     token::Token,
+    ///     TokenKind,
+    /// };
+    /// use leafc_utils::Span;
+    ///
+    /// let token = Token::new(TokenKind::FOR_KW, "pour".into(), Span::new(0..4));
+    /// assert_eq!(token.languages(), Some(&[LanguageKind::French][..]));
+    ///
+    /// let token = Token::new(TokenKind::IDENTIFIER, "pour".into(), Span::new(0..4));
+    /// assert_eq!(token.languages(), None);
+    /// ```
+    pub fn languages(&self) -> Option<&'static [LanguageKind]> {
+        if !self.kind().is_keyword() {
+            return None;
+        }
+        keywords::keyword(self.lexeme())
+            .filter(|keyword| keyword.kind == *self.kind())
+            .map(|keyword| keyword.languages)
+    }
+
+    /// Returns `true` if the token is considered **valid** in the context of
+    /// the given spoken language (i.e. it is either a keyword spelled in that
+    /// language, or it isn't specific to any spoken language).
+    pub fn is_valid_in(&self, lang: LanguageKind) -> bool {
+        if !self.kind().is_keyword() {
+            return true;
+        }
+        self.languages().map_or(false, |languages| languages.contains(&lang))
+    }
+
     /// Returns `true` if the token is considered **valid** in the context of
     /// the **English** language. This is used to **filter** out tokens that
     /// are **not** valid in the **English** language.
@@ -102,7 +143,11 @@ impl Token {
     /// # Example:
     ///
     /// ```rust
-    /// use leafc_lexer::Token;
+    /// use leafc_lexer::{
+    ///     token::Token,
+    ///     TokenKind,
+    /// };
+    /// use leafc_utils::Span;
     ///
     /// let token = Token::new(TokenKind::FN_KW, "fn".into(), Span::new(0..2));
     /// assert!(token.is_english());
@@ -111,303 +156,7 @@ impl Token {
     /// assert!(!token2.is_english());
     /// ```
     pub fn is_english(&self) -> bool {
-        match self.kind() {
-            // Generic tokens
-            generic_tokens!() => true,
-            _ => false, /* temp
-                         * // ----------------------------------------------------------------
-                         * // Generic tokens
-                         * // ----------------------------------------------------------------
-                         * TokenKind::ERROR => true,
-                         * TokenKind::WHITESPACE => true,
-                         * TokenKind::IDENTIFIER => true,
-                         * TokenKind::COMMENT => true,
-                         * TokenKind::DOC_COMMENT => true,
-                         * TokenKind::RUNE => true,
-                         * TokenKind::STRING => true,
-                         * TokenKind::RAW_STRING => true,
-                         * TokenKind::INTEGER => true,
-                         * TokenKind::FLOAT => true,
-                         * TokenKind::LIFETIME => true,
-                         * TokenKind::INTEGER_SUP => true,
-                         * TokenKind::FLOAT_SUP => true,
-                         * TokenKind::PI => true,
-                         * TokenKind::EULER => true,
-                         * TokenKind::PHI => true,
-                         * TokenKind::TAU => true,
-                         * TokenKind::CATALAN => true,
-                         * TokenKind::EULERGAMMA => true,
-                         * TokenKind::INF => true,
-                         * TokenKind::NAN => true,
-                         * TokenKind::DEFINE => true,
-                         * TokenKind::PLUS => true,
-                         * TokenKind::MINUS => true,
-                         * TokenKind::STAR => true,
-                         * TokenKind::SLASH => true,
-                         * TokenKind::PERCENT => true,
-                         * TokenKind::CARET => true,
-                         * TokenKind::BANG => true,
-                         * TokenKind::AMPERSAND => true,
-                         * TokenKind::PIPE => true,
-                         * TokenKind::DOUBLE_AMPERSAND => true,
-                         * TokenKind::DOUBLE_PIPE => true,
-                         * TokenKind::SHL => true,
-                         * TokenKind::SHR => true,
-                         * TokenKind::PLUS_EQ => true,
-                         * TokenKind::MINUS_EQ => true,
-                         * TokenKind::STAR_EQ => true,
-                         * TokenKind::SLASH_EQ => true,
-                         * TokenKind::PERCENT_EQ => true,
-                         * TokenKind::CARET_EQ => true,
-                         * TokenKind::AMPERSAND_EQ => true,
-                         * TokenKind::PIPE_EQ => true,
-                         * TokenKind::SHL_EQ => true,
-                         * TokenKind::SHR_EQ => true,
-                         * TokenKind::EQ => true,
-                         * TokenKind::EQEQ => true,
-                         * TokenKind::NE => true,
-                         * TokenKind::GT => true,
-                         * TokenKind::LT => true,
-                         * TokenKind::GE => true,
-                         * TokenKind::LE => true,
-                         * TokenKind::AT => true,
-                         * TokenKind::UNDERSCORE => true,
-                         * TokenKind::DOT => true,
-                         * TokenKind::DOTDOT => true,
-                         * TokenKind::DOTDOTEQ => true,
-                         * TokenKind::COMMA => true,
-                         * TokenKind::SEMICOLON => true,
-                         * TokenKind::COLON => true,
-                         * TokenKind::PATHSEP => true,
-                         * TokenKind::RARROW => true,
-                         * TokenKind::FATARROW => true,
-                         * TokenKind::HASH => true,
-                         * TokenKind::DOLLAR => true,
-                         * TokenKind::QMARK => true,
-                         * TokenKind::TILDE => true,
-                         * TokenKind::L_BRACKET => true,
-                         * TokenKind::R_BRACKET => true,
-                         * TokenKind::L_PAREN => true,
-                         * TokenKind::R_PAREN => true,
-                         * TokenKind::L_BRACE => true,
-                         * TokenKind::R_BRACE => true,
-                         * TokenKind::L_PAREN_SUPERSCRIPT => true,
-                         * TokenKind::R_PAREN_SUPERSCRIPT => true,
-                         * TokenKind::L_ARROW => true,
-                         * TokenKind::DOUBLE_STAR => true, */
-
-                        /* // ----------------------------------------------------------------
-                         * // Language-specific tokens
-                         * // ----------------------------------------------------------------
-                         * TokenKind::ABSTRACT_KW => match self.lexeme().as_str() {
-                         *     "abstract" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::ASYNC_KW => match self.lexeme().as_str() {
-                         *     "async" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::AWAIT_KW => match self.lexeme().as_str() {
-                         *     "await" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::CASE_KW => match self.lexeme().as_str() {
-                         *     "case" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::EXTERN_KW => match self.lexeme().as_str() {
-                         *     "extern" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::FINAL_KW => match self.lexeme().as_str() {
-                         *     "final" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::IMPORT_KW => match self.lexeme().as_str() {
-                         *     "import" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::LET_KW => match self.lexeme().as_str() {
-                         *     "let" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::AND_KW => match self.lexeme().as_str() {
-                         *     "and" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::AS_KW => match self.lexeme().as_str() {
-                         *     "as" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::BREAK_KW => match self.lexeme().as_str() {
-                         *     "break" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::CONST_KW => match self.lexeme().as_str() {
-                         *     "const" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::CONTINUE_KW => match self.lexeme().as_str() {
-                         *     "continue" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::DEFAULT_KW => match self.lexeme().as_str() {
-                         *     "default" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::DEFER_KW => match self.lexeme().as_str() {
-                         *     "defer" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::DO_KW => match self.lexeme().as_str() {
-                         *     "do" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::DYN_KW => match self.lexeme().as_str() {
-                         *     "dyn" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::ELSE_KW => match self.lexeme().as_str() {
-                         *     "else" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::ENUM_KW => match self.lexeme().as_str() {
-                         *     "enum" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::FALLTHROUGH_KW => match self.lexeme().as_str() {
-                         *     "fallthrough" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::FALSE_KW => match self.lexeme().as_str() {
-                         *     "false" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::FN_KW => match self.lexeme().as_str() {
-                         *     "fn" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::FOR_KW => match self.lexeme().as_str() {
-                         *     "for" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::IF_KW => match self.lexeme().as_str() {
-                         *     "if" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::IMPL_KW => match self.lexeme().as_str() {
-                         *     "impl" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::IN_KW => match self.lexeme().as_str() {
-                         *     "in" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::IS_KW => match self.lexeme().as_str() {
-                         *     "is" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::ISNT_KW => match self.lexeme().as_str() {
-                         *     "isnt" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::LOOP_KW => match self.lexeme().as_str() {
-                         *     "loop" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::MATCH_KW => match self.lexeme().as_str() {
-                         *     "match" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::MISSING_KW => match self.lexeme().as_str() {
-                         *     "missing" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::MOD_KW => match self.lexeme().as_str() {
-                         *     "mod" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::MOVE_KW => match self.lexeme().as_str() {
-                         *     "move" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::MUT_KW => match self.lexeme().as_str() {
-                         *     "mut" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::NOT_KW => match self.lexeme().as_str() {
-                         *     "not" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::OR_KW => match self.lexeme().as_str() {
-                         *     "or" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::PACKAGE_KW => match self.lexeme().as_str() {
-                         *     "package" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::PUB_KW => match self.lexeme().as_str() {
-                         *     "pub" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::RETURN_KW => match self.lexeme().as_str() {
-                         *     "return" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::SELF_VALUE_KW => match self.lexeme().as_str() {
-                         *     "self" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::SELF_TYPE_KW => match self.lexeme().as_str() {
-                         *     "Self" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::STATIC_KW => match self.lexeme().as_str() {
-                         *     "static" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::STRUCT_KW => match self.lexeme().as_str() {
-                         *     "struct" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::SUPER_KW => match self.lexeme().as_str() {
-                         *     "super" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::TRAIT_KW => match self.lexeme().as_str() {
-                         *     "trait" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::TRUE_KW => match self.lexeme().as_str() {
-                         *     "true" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::TYPE_KW => match self.lexeme().as_str() {
-                         *     "type" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::UNSAFE_KW => match self.lexeme().as_str() {
-                         *     "unsafe" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::USE_KW => match self.lexeme().as_str() {
-                         *     "use" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::WHERE_KW => match self.lexeme().as_str() {
-                         *     "where" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::WHILE_KW => match self.lexeme().as_str() {
-                         *     "while" => true,
-                         *     _ => false,
-                         * },
-                         * TokenKind::YIELD_KW => match self.lexeme().as_str() {
-                         *     "yield" => true,
-                         *     _ => false,
-                         * }, */
-        }
+        self.is_valid_in(LanguageKind::English)
     }
 
     fn pretty_print(&self) -> String {
@@ -476,8 +225,8 @@ mod smoke_token {
     #[case(TokenKind::MUT_KW, "mut", Span::new(0..3))]
     // #[case(TokenKind::REF_KW, "ref", Span::new(0..3))]
     #[case(TokenKind::RETURN_KW, "return", Span::new(0..6))]
-    #[case(TokenKind::SELF_TYPE_KW, "self", Span::new(0..4))]
-    #[case(TokenKind::SELF_VALUE_KW, "Self", Span::new(0..4))]
+    #[case(TokenKind::SELF_VALUE_KW, "self", Span::new(0..4))]
+    #[case(TokenKind::SELF_TYPE_KW, "Self", Span::new(0..4))]
     #[case(TokenKind::STATIC_KW, "static", Span::new(0..6))]
     #[case(TokenKind::STRUCT_KW, "struct", Span::new(0..6))]
     #[case(TokenKind::SUPER_KW, "super", Span::new(0..5))]
@@ -0,0 +1,165 @@
+//! **Translation** of source files between spoken languages.
+//!
+//! Every keyword is spelled in each of the spoken languages (see
+//! [`keywords`][crate::language_check::keywords]), so a file can be rewritten
+//! from one language into another by **respelling its keywords** (e.g. `fn`
+//! into `fonction`), while everything else (i.e. its identifiers, literals,
+//! comments and whitespace) is left byte-for-byte as it is.
+//!
+//! The language a file is translated **from** is the one it declares with a
+//! `lang` attribute (e.g. `#![lang(fr)]`), or else the one inferred from its
+//! keywords (see [`LanguageChecker`]). A `lang` attribute is translated as
+//! well (e.g. into `#![lang(en)]`).
+
+use std::ops::Range;
+
+use getset::Getters;
+use leafc_cfg::lang::LanguageKind;
+use leafc_diagnostics::errors::{
+    LexicalError,
+    TranslationError,
+};
+use leafc_utils::codemap::Spanned;
+
+use crate::{
+    language_check::{
+        keywords::{
+            self,
+            KEYWORDS,
+        },
+        tag,
+        LanguageChecker,
+    },
+    lexer::TokenStream,
+    token::Token,
+    TokenKind,
+};
+
+/// The **result** of [translating][translate] a file into another spoken
+/// language.
+#[derive(Debug, Clone, PartialEq, Eq, Getters)]
+#[getset(get = "pub")]
+pub struct Translation {
+    /// The spoken **language** the file was translated from (i.e. `None` if
+    /// it could be written in none of the languages it was translated from).
+    from: Option<LanguageKind>,
+
+    /// The **translated** text of the file.
+    text: String,
+
+    /// The **keywords** which aren't part of the file's language (see
+    /// [`LanguageChecker::check`]), and the tokens which couldn't be
+    /// translated (see [`LexicalError::Untranslatable`]), in the order they
+    /// occur.
+    errors: Vec<Spanned<LexicalError>>,
+}
+
+/// **Translates** the keywords of `input` into the spoken language `to`, where
+/// `input` may be written in any of the given languages `from` (with
+/// [`LanguageKind::Any`] allowing every spoken language), whose keywords are
+/// the only ones recognized (see [`TokenStream::with_languages`]).
+///
+/// A keyword without a spelling in `to` is left as it is, and so is an
+/// identifier which is a keyword in `to` (e.g. `pour` when translating into
+/// French), but both are reported as [`LexicalError::Untranslatable`].
+///
+/// # Example:
+///
+/// ```rust
+/// use leafc_cfg::lang::LanguageKind;
+/// use leafc_lexer::translate::translate;
+///
+/// let source = "fn main() { for x in xs { println!(x) } } // true";
+/// let translation = translate(source, &[LanguageKind::Any], LanguageKind::French);
+///
+/// assert_eq!(translation.text(), "fonction main() { pour x dans xs { println!(x) } } // true");
+/// assert_eq!(translation.from(), &Some(LanguageKind::English));
+/// assert!(translation.errors().is_empty());
+/// ```
+pub fn translate(input: &str, from: &[LanguageKind], to: LanguageKind) -> Translation {
+    let mut tokens = TokenStream::with_languages(input, true, from);
+    let check = LanguageChecker::new(from).check(tokens.tokens());
+    let mut errors = check.errors().clone();
+
+    let Some(source) = check.language() else {
+        return Translation { from: None, text: input.to_string(), errors };
+    };
+    // The keywords of every other language are identifiers of the file.
+    LanguageChecker::new(&[source]).scope_keywords(tokens.tokens_mut());
+
+    let tag: Option<Range<usize>> = check.tag().map(Into::into);
+    let mut text = String::with_capacity(input.len());
+    let mut cursor = 0;
+    for token in tokens.tokens() {
+        let Range { start, end } = (*token.span()).into();
+        if start < cursor {
+            continue;
+        }
+        text.push_str(&input[cursor..start]);
+        cursor = end;
+
+        if let Some(tag) = tag.as_ref().filter(|tag| tag.start == start) {
+            // The tag may be lexed as several tokens (e.g. `fr-CA`).
+            text.push_str(to.tag().unwrap_or(&input[tag.clone()]));
+            cursor = tag.end;
+        } else if let Some(spelling) = respell(token, to) {
+            text.push_str(spelling);
+        } else {
+            let reported = check.errors().iter().any(|error| error.span == *token.span());
+            if let Some(error) = untranslatable(token, source, to).filter(|_| !reported) {
+                errors.push(Spanned {
+                    span: *token.span(),
+                    node: LexicalError::Untranslatable(error),
+                });
+            }
+            text.push_str(token.lexeme());
+        }
+    }
+    text.push_str(&input[cursor..]);
+
+    errors.sort_by_key(|error| error.span);
+    Translation { from: Some(source), text, errors }
+}
+
+/// Returns the **spelling** of the given keyword in the spoken language `to`,
+/// if it has one.
+///
+/// A keyword which is already spelled the same way in `to` (e.g. `for` when
+/// translating from English into Danish) is kept as it is, and otherwise the
+/// spelling which is **capitalized** the same way is preferred (e.g. `Vrai`
+/// rather than `vrai` for `True`).
+fn respell(token: &Token, to: LanguageKind) -> Option<&'static str> {
+    let languages = token.languages()?;
+    if languages.contains(&to) {
+        return keywords::keyword(token.lexeme()).map(|keyword| keyword.lexeme);
+    }
+
+    let capitalized = |lexeme: &str| lexeme.chars().next().map_or(false, char::is_uppercase);
+    let mut spellings = KEYWORDS
+        .iter()
+        .filter(|keyword| keyword.kind == *token.kind() && keyword.languages.contains(&to))
+        .map(|keyword| keyword.lexeme);
+    spellings
+        .clone()
+        .find(|spelling| capitalized(spelling) == capitalized(token.lexeme()))
+        .or_else(|| spellings.next())
+}
+
+/// Returns why the given token (which couldn't be [respelled][respell]) can't
+/// be translated from `from` into `to`, if it can't.
+fn untranslatable(token: &Token, from: LanguageKind, to: LanguageKind) -> Option<TranslationError> {
+    if token.languages().is_some() {
+        return Some(TranslationError::NoSpelling {
+            keyword:  token.lexeme().clone(),
+            language: tag(to),
+        });
+    }
+
+    let keyword = keywords::keyword(token.lexeme())?;
+    (*token.kind() == TokenKind::IDENTIFIER && from != to && keyword.languages.contains(&to)).then(
+        || TranslationError::KeywordInLanguage {
+            identifier: token.lexeme().clone(),
+            language:   tag(to),
+        },
+    )
+}
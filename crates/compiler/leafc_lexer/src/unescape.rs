@@ -0,0 +1,336 @@
+//! **Unescaping** of rune and string literals.
+//!
+//! The lexer only finds where a literal **starts** and **ends**, so the escapes
+//! within it (e.g. `\n`, `\x7F` or `\u{1F980}`) are validated here, both while
+//! lexing (see [`TokenStream::errors`][crate::lexer::TokenStream::errors]) and
+//! when later phases need the **decoded value** of a literal (see
+//! [`unescape`]).
+//!
+//! The following literals are supported:
+//!
+//! * **Runes** (e.g. `'a'` or `'\n'`) and **byte runes** (e.g. `b'a'`).
+//! * **Strings** (e.g. `"a\tb"`) and **byte strings** (e.g. `b"a\tb"`).
+//! * **Raw strings** with any number of `#`s (e.g. `r"a"` or `r##"a "#" b"##`)
+//!   and **raw byte strings** (e.g. `br#"a"#`), which have no escapes at all.
+//! * The **literal fragments** of format strings (e.g. the `Bonjour, ` and `!`
+//!   of `"Bonjour, {name}!"`).
+//!
+//! In (non-byte) strings, `{{` and `}}` stand for a single `{` and `}`, as the
+//! braces would otherwise start or end a hole of a format string.
+//!
+//! **Byte** literals may only hold ASCII characters and escapes, but their `\x`
+//! escapes go all the way up to `\xFF`.
+
+use std::str::Chars;
+
+use leafc_diagnostics::errors::{
+    EscapeError,
+    LexicalError,
+};
+use leafc_utils::codemap::{
+    Span,
+    Spanned,
+};
+
+use crate::TokenKind;
+
+/// The **decoded value** of a rune or string literal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LiteralValue {
+    /// The value of a **rune** (e.g. `'a'`).
+    Rune(char),
+    /// The value of a **byte rune** (e.g. `b'a'`).
+    Byte(u8),
+    /// The value of a (raw) **string** (e.g. `"a\n"` or `r#"a"#`).
+    Str(String),
+    /// The value of a (raw) **byte string** (e.g. `b"a\n"` or `br#"a"#`).
+    ByteStr(Vec<u8>),
+}
+
+/// Decodes the **value** of the rune or string literal `lexeme`, which was
+/// lexed as a token of the given `kind`.
+///
+/// # Errors
+///
+/// Returns a [`LexicalError`] for **every** invalid escape (or unterminated
+/// raw string) in the literal, spanning the escape within `lexeme` (i.e. the
+/// spans are relative to the start of the literal).
+///
+/// # Panics
+///
+/// Panics if `kind` is not a [`TokenKind::RUNE`], [`TokenKind::STRING`],
+/// [`TokenKind::RAW_STRING`] or [`TokenKind::STRING_FRAGMENT`].
+///
+/// # Example:
+///
+/// ```rust
+/// use leafc_lexer::{
+///     unescape::{
+///         unescape,
+///         LiteralValue,
+///     },
+///     TokenKind,
+/// };
+///
+/// let value = unescape(TokenKind::STRING, r#""🦀\t\u{1F980}""#).unwrap();
+/// assert_eq!(value, LiteralValue::Str("🦀\t🦀".into()));
+///
+/// let value = unescape(TokenKind::RAW_STRING, r###"br##"a "#" b"##"###).unwrap();
+/// assert_eq!(value, LiteralValue::ByteStr(br##"a "#" b"##.to_vec()));
+///
+/// let errors = unescape(TokenKind::STRING, r#""a\qb""#).unwrap_err();
+/// assert_eq!(errors.len(), 1);
+/// ```
+pub fn unescape(kind: TokenKind, lexeme: &str) -> Result<LiteralValue, Vec<Spanned<LexicalError>>> {
+    let is_byte = lexeme.starts_with('b');
+    let prefix_len = usize::from(is_byte);
+
+    let mut errors = Vec::new();
+    let value = match kind {
+        TokenKind::RUNE | TokenKind::STRING => {
+            let body = lexeme[prefix_len + 1..].strip_suffix(['\'', '"']).unwrap_or_default();
+            let is_format = kind == TokenKind::STRING && !is_byte;
+            let chars = unescape_body(body, prefix_len + 1, is_byte, is_format, &mut errors);
+            match (kind, is_byte) {
+                (TokenKind::RUNE, false) => {
+                    LiteralValue::Rune(chars.first().copied().unwrap_or_default())
+                }
+                (TokenKind::RUNE, true) => {
+                    LiteralValue::Byte(chars.first().map_or(0, |&c| c as u8))
+                }
+                (_, false) => LiteralValue::Str(chars.into_iter().collect()),
+                (_, true) => LiteralValue::ByteStr(chars.into_iter().map(|c| c as u8).collect()),
+            }
+        }
+        TokenKind::RAW_STRING => {
+            let body = raw_string_body(lexeme, prefix_len).unwrap_or_else(|body| {
+                let error = LexicalError::UnterminatedRawString;
+                errors.push(Spanned { span: Span::new(0..lexeme.len()), node: error });
+                body
+            });
+
+            if is_byte {
+                let offset = lexeme.find('"').map_or(lexeme.len(), |i| i + 1);
+                for (i, c) in body.char_indices().filter(|(_, c)| !c.is_ascii()) {
+                    let error = LexicalError::InvalidEscape(EscapeError::NonAsciiInByteLiteral);
+                    let start = offset + i;
+                    errors.push(Spanned {
+                        span: Span::new(start..start + c.len_utf8()),
+                        node: error,
+                    });
+                }
+                LiteralValue::ByteStr(body.bytes().collect())
+            } else {
+                LiteralValue::Str(body.to_string())
+            }
+        }
+        TokenKind::STRING_FRAGMENT => LiteralValue::Str(
+            unescape_body(lexeme, 0, false, true, &mut errors).into_iter().collect(),
+        ),
+        kind => panic!("`{kind:?}` is not a rune or string literal"),
+    };
+
+    if errors.is_empty() {
+        Ok(value)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Returns the **errors** in the rune or string literal `lexeme` of the given
+/// `kind` (see [`unescape`]), which are empty if the literal is valid.
+pub fn validate(kind: TokenKind, lexeme: &str) -> Vec<Spanned<LexicalError>> {
+    unescape(kind, lexeme).err().unwrap_or_default()
+}
+
+/// Returns the text between the quotes of the raw string `lexeme` (after its
+/// `prefix_len` long `b` prefix), or the rest of its text if it is never
+/// closed.
+fn raw_string_body(lexeme: &str, prefix_len: usize) -> Result<&str, &str> {
+    let text = &lexeme[prefix_len + 1..];
+    let hashes = text.len() - text.trim_start_matches('#').len();
+    let body = text[hashes..].strip_prefix('"').unwrap_or_default();
+
+    let closing = format!("\"{}", "#".repeat(hashes));
+    body.strip_suffix(closing.as_str()).ok_or(body)
+}
+
+/// Decodes the characters of the `body` of a rune or string, which starts
+/// `offset` bytes into the literal, reporting any invalid escapes in `errors`.
+///
+/// If `is_format` is set, `{{` and `}}` are decoded as a single brace.
+fn unescape_body(
+    body: &str,
+    offset: usize,
+    is_byte: bool,
+    is_format: bool,
+    errors: &mut Vec<Spanned<LexicalError>>,
+) -> Vec<char> {
+    let mut chars = body.chars();
+    let mut res = Vec::new();
+    loop {
+        let start = offset + body.len() - chars.as_str().len();
+        let result = match chars.next() {
+            Some('\\') => scan_escape(&mut chars, is_byte),
+            Some(c) if is_byte && !c.is_ascii() => Err(EscapeError::NonAsciiInByteLiteral),
+            Some(c @ ('{' | '}')) if is_format && chars.as_str().starts_with(c) => {
+                chars.next();
+                Ok(c)
+            }
+            Some(c) => Ok(c),
+            None => break res,
+        };
+
+        match result {
+            Ok(c) => res.push(c),
+            Err(error) => {
+                let end = offset + body.len() - chars.as_str().len();
+                errors.push(Spanned {
+                    span: Span::new(start..end),
+                    node: LexicalError::InvalidEscape(error),
+                });
+            }
+        }
+    }
+}
+
+/// Decodes the escape after a `\`, leaving `chars` after the escape.
+fn scan_escape(chars: &mut Chars<'_>, is_byte: bool) -> Result<char, EscapeError> {
+    let c = match chars.next().ok_or(EscapeError::LoneSlash)? {
+        'n' => '\n',
+        'r' => '\r',
+        't' => '\t',
+        '\\' => '\\',
+        '0' => '\0',
+        '\'' => '\'',
+        '"' => '"',
+        'x' => {
+            let value = scan_hex_digit(chars)
+                .and_then(|hi| Some(hi * 16 + scan_hex_digit(chars)?))
+                .ok_or(EscapeError::InvalidHexEscape)?;
+            if !is_byte && value > 0x7F {
+                return Err(EscapeError::OutOfRangeHexEscape);
+            }
+            char::from(value as u8)
+        }
+        'u' => {
+            let c = scan_unicode_escape(chars)?;
+            if is_byte {
+                return Err(EscapeError::UnicodeEscapeInByteLiteral);
+            }
+            c
+        }
+        c => return Err(EscapeError::UnknownEscape(c)),
+    };
+    Ok(c)
+}
+
+/// Scans the `{...}` of a `\u{...}` escape.
+fn scan_unicode_escape(chars: &mut Chars<'_>) -> Result<char, EscapeError> {
+    if !chars.as_str().starts_with('{') {
+        return Err(EscapeError::InvalidUnicodeEscape);
+    }
+    chars.next();
+
+    let mut value = None::<u32>;
+    loop {
+        match chars.as_str().chars().next() {
+            Some('}') => {
+                chars.next();
+                break;
+            }
+            Some(c) if c.is_ascii_hexdigit() => {
+                let digit = scan_hex_digit(chars).unwrap_or_default();
+                // Saturate rather than overflow, as any value past `10FFFF` is
+                // out of range anyway.
+                value = Some(value.unwrap_or(0).saturating_mul(16).saturating_add(digit));
+            }
+            _ => return Err(EscapeError::InvalidUnicodeEscape),
+        }
+    }
+
+    let value = value.ok_or(EscapeError::EmptyUnicodeEscape)?;
+    char::from_u32(value).ok_or(EscapeError::OutOfRangeUnicodeEscape)
+}
+
+/// Scans a single hexadecimal digit, leaving `chars` alone if there isn't one.
+fn scan_hex_digit(chars: &mut Chars<'_>) -> Option<u32> {
+    let digit = chars.as_str().chars().next()?.to_digit(16)?;
+    chars.next();
+    Some(digit)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions_sorted::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(TokenKind::RUNE, "'a'", LiteralValue::Rune('a'))]
+    #[case(TokenKind::RUNE, "'\\n'", LiteralValue::Rune('\n'))]
+    #[case(TokenKind::RUNE, "'\\u{1F980}'", LiteralValue::Rune('🦀'))]
+    #[case(TokenKind::RUNE, "'\\u{00000000}'", LiteralValue::Rune('\0'))]
+    #[case(TokenKind::RUNE, "b'\\xFF'", LiteralValue::Byte(0xFF))]
+    #[case(TokenKind::RUNE, "b'\\''", LiteralValue::Byte(b'\''))]
+    #[case(TokenKind::STRING, "\"\"", LiteralValue::Str(String::new()))]
+    #[case(TokenKind::STRING, "\"a\\tb\\\\\\\"\"", LiteralValue::Str("a\tb\\\"".into()))]
+    #[case(TokenKind::STRING, "\"日本\\x41\\u{8a9e}\"", LiteralValue::Str("日本A語".into()))]
+    #[case(TokenKind::STRING, "b\"\\x00\\xff\\n\"", LiteralValue::ByteStr(vec![0, 0xFF, b'\n']))]
+    #[case(TokenKind::RAW_STRING, "r\"a\\n\"", LiteralValue::Str("a\\n".into()))]
+    #[case(TokenKind::RAW_STRING, "r#\"\"#", LiteralValue::Str(String::new()))]
+    #[case(TokenKind::RAW_STRING, "r##\"a \"# b\"##", LiteralValue::Str("a \"# b".into()))]
+    #[case(TokenKind::RAW_STRING, "br#\"\\\"#", LiteralValue::ByteStr(b"\\".to_vec()))]
+    #[case(TokenKind::STRING, "\"{{}}{}\"", LiteralValue::Str("{}{}".into()))]
+    #[case(TokenKind::STRING, "b\"{{\"", LiteralValue::ByteStr(b"{{".to_vec()))]
+    #[case(TokenKind::STRING_FRAGMENT, "Bonjour,\\t{{", LiteralValue::Str("Bonjour,\t{".into()))]
+    fn test_unescape(#[case] kind: TokenKind, #[case] lexeme: &str, #[case] value: LiteralValue) {
+        assert_eq!(unescape(kind, lexeme), Ok(value));
+    }
+
+    #[rstest]
+    #[case(TokenKind::RUNE, "'\\q'", &[(1..3, EscapeError::UnknownEscape('q'))])]
+    #[case(TokenKind::RUNE, "'\\x8'", &[(1..4, EscapeError::InvalidHexEscape)])]
+    #[case(TokenKind::RUNE, "'\\x80'", &[(1..5, EscapeError::OutOfRangeHexEscape)])]
+    #[case(TokenKind::RUNE, "b'é'", &[(2..4, EscapeError::NonAsciiInByteLiteral)])]
+    #[case(TokenKind::STRING, "\"\\u{D800}\"", &[(1..9, EscapeError::OutOfRangeUnicodeEscape)])]
+    #[case(TokenKind::STRING, "\"\\u{110000}\"", &[(1..11, EscapeError::OutOfRangeUnicodeEscape)])]
+    #[case(TokenKind::STRING, "\"\\u{}\"", &[(1..5, EscapeError::EmptyUnicodeEscape)])]
+    #[case(TokenKind::STRING, "\"\\u1F980\"", &[(1..3, EscapeError::InvalidUnicodeEscape)])]
+    #[case(TokenKind::STRING, "\"\\u{1F98z}\"", &[(1..8, EscapeError::InvalidUnicodeEscape)])]
+    #[case(TokenKind::STRING, "b\"\\u{41}\"", &[(2..8, EscapeError::UnicodeEscapeInByteLiteral)])]
+    #[case(
+        TokenKind::STRING,
+        "\"a\\q \\w\"",
+        &[(2..4, EscapeError::UnknownEscape('q')), (5..7, EscapeError::UnknownEscape('w'))],
+    )]
+    #[case(TokenKind::RAW_STRING, "br\"日\"", &[(3..6, EscapeError::NonAsciiInByteLiteral)])]
+    #[case(TokenKind::STRING_FRAGMENT, "a\\q", &[(1..3, EscapeError::UnknownEscape('q'))])]
+    fn test_invalid_escapes(
+        #[case] kind: TokenKind,
+        #[case] lexeme: &str,
+        #[case] expected: &[(std::ops::Range<usize>, EscapeError)],
+    ) {
+        let expected = expected
+            .iter()
+            .map(|(range, error)| Spanned {
+                span: Span::new(range.clone()),
+                node: LexicalError::InvalidEscape(*error),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(validate(kind, lexeme), expected);
+    }
+
+    #[rstest]
+    #[case("r\"")]
+    #[case("r#\"a\"")]
+    #[case("r##\"a\"#")]
+    fn test_unterminated_raw_string(#[case] lexeme: &str) {
+        let expected = vec![Spanned {
+            span: Span::new(0..lexeme.len()),
+            node: LexicalError::UnterminatedRawString,
+        }];
+        assert_eq!(validate(TokenKind::RAW_STRING, lexeme), expected);
+    }
+}
@@ -0,0 +1,182 @@
+mod fuzz_test_suite {
+    use std::panic;
+
+    use leafc_lexer::{
+        lossless_lex,
+        lossy_lex,
+        TokenKind,
+    };
+    use leafc_utils::regex_gen::RegexGen;
+    use pretty_assertions_sorted::assert_eq;
+    use rand::{
+        rngs::StdRng,
+        seq::SliceRandom,
+        Rng,
+        SeedableRng,
+    };
+
+    // Property tests of the lexer over random inputs, which are either streams
+    // of tokens generated from the `#[token]`s and `#[regex]`es `TokenKind` is
+    // defined by, or random garbage. Each input is generated from its own seed,
+    // so a failure can be reproduced from the seed alone.
+
+    /// The number of random inputs of each kind to check.
+    const ITERATIONS: u64 = 1000;
+
+    /// A pattern which the lexemes of a `TokenKind` match.
+    enum Pattern {
+        /// A `#[token("...")]`, which only matches itself.
+        Token(String),
+        /// A `#[regex("...")]`.
+        Regex(RegexGen),
+    }
+
+    impl Pattern {
+        fn generate(&self, rng: &mut StdRng) -> String {
+            match self {
+                Pattern::Token(text) => text.clone(),
+                Pattern::Regex(gen) => gen.generate(rng),
+            }
+        }
+    }
+
+    /// Extracts every `#[token]` and `#[regex]` pattern from the definition of
+    /// `TokenKind`, so the generated inputs stay in sync with the lexer.
+    fn token_patterns() -> Vec<Pattern> {
+        let source = include_str!("../../src/token/kinds.rs");
+
+        let mut patterns = Vec::new();
+        for (i, _) in source.match_indices("#[") {
+            let line_start = source[..i].rfind('\n').map_or(0, |n| n + 1);
+            if source[line_start..i].trim_start().starts_with("//") {
+                continue;
+            }
+
+            let attr = &source[i + 2..];
+            let (is_regex, args) = if let Some(args) = attr.strip_prefix("token(") {
+                (false, args)
+            } else if let Some(args) = attr.strip_prefix("regex(") {
+                (true, args)
+            } else {
+                continue;
+            };
+
+            let literal = parse_str_literal(args.trim_start())
+                .unwrap_or_else(|| panic!("expected a string literal at {:?}", &source[i..i + 8]));
+            patterns.push(if is_regex {
+                let gen = RegexGen::new(&literal)
+                    .unwrap_or_else(|err| panic!("can't generate from `{literal}`: {err}"));
+                Pattern::Regex(gen)
+            } else {
+                Pattern::Token(literal)
+            });
+        }
+        patterns
+    }
+
+    /// Parses the (raw) string literal at the start of `text`, returning its
+    /// value.
+    fn parse_str_literal(text: &str) -> Option<String> {
+        if let Some(raw) = text.strip_prefix('r') {
+            let hashes = raw.len() - raw.trim_start_matches('#').len();
+            let body = raw[hashes..].strip_prefix('"')?;
+            let end = body.find(&format!("\"{}", "#".repeat(hashes)))?;
+            return Some(body[..end].to_string());
+        }
+
+        let mut chars = text.strip_prefix('"')?.chars();
+        let mut value = String::new();
+        loop {
+            match chars.next()? {
+                '"' => return Some(value),
+                '\\' => match chars.next()? {
+                    'n' => value.push('\n'),
+                    'r' => value.push('\r'),
+                    't' => value.push('\t'),
+                    '0' => value.push('\0'),
+                    'u' => {
+                        let rest = chars.as_str().strip_prefix('{')?;
+                        let end = rest.find('}')?;
+                        value.push(char::from_u32(u32::from_str_radix(&rest[..end], 16).ok()?)?);
+                        chars = rest[end + 1..].chars();
+                    }
+                    c => value.push(c),
+                },
+                c => value.push(c),
+            }
+        }
+    }
+
+    /// Generates a random stream of tokens, separated by random (possibly
+    /// empty) whitespace, so neighbouring tokens may also run together.
+    fn random_token_stream(patterns: &[Pattern], rng: &mut StdRng) -> String {
+        let mut input = String::new();
+        for _ in 0..rng.gen_range(0..32) {
+            input.push_str(&patterns.choose(rng).unwrap().generate(rng));
+            input.push_str(["", "", " ", "\n", "\t"].choose(rng).unwrap());
+        }
+        input
+    }
+
+    /// Generates a random string of characters which are likely to confuse
+    /// the lexer (e.g. unterminated literals, stray quotes and superscripts).
+    fn random_garbage(rng: &mut StdRng) -> String {
+        const CHARS: &[char] = &[
+            'a', 'e', 'x', 'Z', '_', 'é', '本', '🦀', '0', '1', '9', '.', ',', ';', ':', '\'', '"',
+            '\\', '#', '/', '*', '+', '-', '<', '>', '=', '!', '&', '|', '(', ')', '{', '}', '[',
+            ']', ' ', '\n', '\r', '\t', '⁰', '¹', '⁻', '·', 'ᵇ', '∞', 'π',
+        ];
+        (0..rng.gen_range(0..64)).map(|_| *CHARS.choose(rng).unwrap()).collect()
+    }
+
+    /// Checks that lexing `input` doesn't panic, that the lossless tokens
+    /// concatenate back to the `input`, and that the lossy tokens are the
+    /// lossless ones without any trivia.
+    fn check_lexer(seed: u64, input: &str) {
+        let lexed = panic::catch_unwind(|| (lossless_lex(input), lossy_lex(input)));
+        let Ok((lossless, lossy)) = lexed else {
+            panic!("the lexer panicked on {input:?} (seed {seed})");
+        };
+
+        let mut offset = 0;
+        for token in lossless.tokens() {
+            let span: std::ops::Range<usize> = (*token.span()).into();
+            assert_eq!(span.start, offset, "gap before {token:?} in {input:?} (seed {seed})");
+            assert_eq!(&input[span.clone()], token.lexeme().as_str(), "seed {seed}");
+            offset = span.end;
+        }
+        assert_eq!(offset, input.len(), "tokens of {input:?} stop early (seed {seed})");
+
+        let trivia = |kind: &TokenKind| kind.is_whitespace() || kind.is_comment();
+        let non_trivia =
+            lossless.tokens().iter().filter(|token| !trivia(token.kind())).collect::<Vec<_>>();
+        assert_eq!(lossy.tokens().iter().collect::<Vec<_>>(), non_trivia, "seed {seed}");
+    }
+
+    #[test]
+    fn test_all_token_patterns_generate() {
+        let patterns = token_patterns();
+        let mut rng = StdRng::seed_from_u64(0);
+        assert!(patterns.len() > 100);
+
+        for pattern in &patterns {
+            check_lexer(0, &pattern.generate(&mut rng));
+        }
+    }
+
+    #[test]
+    fn test_random_token_streams() {
+        let patterns = token_patterns();
+        for seed in 0..ITERATIONS {
+            let input = random_token_stream(&patterns, &mut StdRng::seed_from_u64(seed));
+            check_lexer(seed, &input);
+        }
+    }
+
+    #[test]
+    fn test_random_garbage() {
+        for seed in 0..ITERATIONS {
+            check_lexer(seed, &random_garbage(&mut StdRng::seed_from_u64(seed)));
+        }
+    }
+}
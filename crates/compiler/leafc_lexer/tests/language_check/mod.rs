@@ -0,0 +1,236 @@
+mod language_check_test_suite {
+    use leafc_cfg::lang::LanguageKind;
+    use leafc_diagnostics::errors::{
+        LanguageSource,
+        LexicalError,
+        MixedLanguagesError,
+    };
+    use leafc_lexer::{
+        language_check::keywords::KEYWORDS,
+        lexer::TokenStream,
+        lossless_lex,
+        LanguageChecker,
+        TokenKind,
+    };
+    use logos::Logos;
+    use pretty_assertions_sorted::assert_eq;
+    use rstest::rstest;
+
+    #[test]
+    fn test_keywords_lex_as_their_kind() {
+        for keyword in KEYWORDS {
+            let mut lexer = TokenKind::lexer(keyword.lexeme);
+            assert_eq!(lexer.next(), Some(keyword.kind), "{}", keyword.lexeme);
+            assert_eq!(lexer.slice(), keyword.lexeme);
+            assert!(!keyword.languages.is_empty(), "{}", keyword.lexeme);
+        }
+    }
+
+    #[test]
+    fn test_keywords_have_an_english_spelling() {
+        for keyword in KEYWORDS {
+            assert!(
+                KEYWORDS.iter().any(|english| english.kind == keyword.kind &&
+                    english.languages.contains(&LanguageKind::English)),
+                "{:?}",
+                keyword.kind
+            );
+        }
+    }
+
+    #[rstest]
+    #[case("", None)]
+    #[case("x := 1 + 2", None)]
+    #[case("fn main() { for x in xs {} }", Some(LanguageKind::English))]
+    #[case("fonction principale() { pour x dans xs {} }", Some(LanguageKind::French))]
+    #[case("funktion f() { während wahr { brechen } }", Some(LanguageKind::German))]
+    #[case("abstrakt", Some(LanguageKind::German))]
+    #[case("abstrakt /* Svenska */ asynkron", Some(LanguageKind::Swedish))]
+    #[case("#![lang(ja)]\nx := 1", Some(LanguageKind::Japanese))]
+    #[case("#![lang(fr-CA)] fonction f() {}", Some(LanguageKind::French))]
+    #[case("#![allow(dead_code)]\n#![lang(Swahili)]", Some(LanguageKind::Swahili))]
+    fn test_infer_language(#[case] input: &str, #[case] language: Option<LanguageKind>) {
+        let tokens = lossless_lex(input);
+        let check = LanguageChecker::default().check(tokens.tokens());
+
+        assert_eq!(check.errors(), &[]);
+        match language {
+            Some(language) => assert_eq!(check.language(), Some(language)),
+            None => assert_eq!(check.languages(), &LanguageKind::SPOKEN),
+        }
+    }
+
+    #[rstest]
+    #[case(
+        "fonction f() { while vrai {} }",
+        &[(15..20, "`while` is English, but the rest of the file is French")]
+    )]
+    #[case(
+        "for x in xs { pour z dans zs {} }",
+        &[
+            (14..18, "`pour` is French, but the rest of the file is English, Danish, Norwegian or Finnish"),
+            (21..25, "`dans` is French, but the rest of the file is English, Danish, Norwegian or Finnish"),
+        ]
+    )]
+    #[case(
+        "#![lang(fr)]\nfn f() {}",
+        &[(13..15, "`fn` is English, but the file is declared to be French")]
+    )]
+    fn test_mixed_languages(
+        #[case] input: &str,
+        #[case] expected: &[(std::ops::Range<usize>, &str)],
+    ) {
+        let tokens = lossless_lex(input);
+        let check = LanguageChecker::default().check(tokens.tokens());
+
+        let errors = check
+            .errors()
+            .iter()
+            .map(|error| {
+                let span: std::ops::Range<usize> = error.span.into();
+                match &error.node {
+                    LexicalError::MixedLanguages(error) => (span, error.to_string()),
+                    error => panic!("unexpected error: {error:?}"),
+                }
+            })
+            .collect::<Vec<_>>();
+        let expected = expected
+            .iter()
+            .map(|(span, message)| (span.clone(), message.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(errors, expected);
+    }
+
+    #[rstest]
+    #[case("#![lang(xx)]", "xx", 8..10)]
+    #[case("#![lang()]", "", 8..8)]
+    #[case("#![lang(Any)]", "Any", 8..11)]
+    fn test_unknown_language(
+        #[case] input: &str,
+        #[case] tag: &str,
+        #[case] span: std::ops::Range<usize>,
+    ) {
+        let tokens = lossless_lex(input);
+        let check = LanguageChecker::default().check(tokens.tokens());
+
+        assert_eq!(check.errors().len(), 1);
+        assert_eq!(check.errors()[0].node, LexicalError::UnknownLanguage(tag.into()));
+        let error_span: std::ops::Range<usize> = check.errors()[0].span.into();
+        assert_eq!(error_span, span);
+        assert!(!check.declared());
+    }
+
+    #[rstest]
+    #[case("fn f() {}", &[LanguageKind::English], 0)]
+    #[case("fonction f() {}", &[LanguageKind::English], 1)]
+    #[case("fonction f() { retour }", &[LanguageKind::English], 2)]
+    #[case("fonction f() {}", &[LanguageKind::English, LanguageKind::French], 0)]
+    #[case("#![lang(fr)] fonction f() {}", &[LanguageKind::English], 0)]
+    #[case("fonction f() {}", &[LanguageKind::Any], 0)]
+    fn test_supported_languages(
+        #[case] input: &str,
+        #[case] languages: &[LanguageKind],
+        #[case] error_count: usize,
+    ) {
+        let tokens = lossless_lex(input);
+        let check = LanguageChecker::new(languages).check(tokens.tokens());
+
+        assert_eq!(check.errors().len(), error_count);
+    }
+
+    #[test]
+    fn test_unsupported_language_message() {
+        let tokens = lossless_lex("fonction f() {}");
+        let check = LanguageChecker::new(&[LanguageKind::English]).check(tokens.tokens());
+
+        assert_eq!(
+            check.errors()[0].node,
+            LexicalError::MixedLanguages(Box::new(MixedLanguagesError {
+                keyword:           "fonction".into(),
+                keyword_languages: vec!["fr".into()],
+                languages:         vec!["en".into()],
+                reason:            LanguageSource::Supported,
+            }))
+        );
+        let LexicalError::MixedLanguages(error) = &check.errors()[0].node else { unreachable!() };
+        assert_eq!(
+            error.to_string(),
+            "`fonction` is French, but the supported languages are English"
+        );
+        assert_eq!(check.language(), Some(LanguageKind::English));
+    }
+
+    #[test]
+    fn test_keywords_are_scoped_to_each_language() {
+        for language in LanguageKind::SPOKEN {
+            for keyword in KEYWORDS {
+                let tokens = TokenStream::with_languages(keyword.lexeme, false, &[language]);
+                let expected = if keyword.languages.contains(&language) {
+                    keyword.kind
+                } else {
+                    TokenKind::IDENTIFIER
+                };
+                assert_eq!(tokens.tokens()[0].kind(), &expected, "{} ({language})", keyword.lexeme);
+            }
+        }
+    }
+
+    #[rstest]
+    #[case("vente := final", &[LanguageKind::English], &[TokenKind::IDENTIFIER, TokenKind::FINAL_KW])]
+    #[case("vente := final", &[LanguageKind::Danish], &[TokenKind::AWAIT_KW, TokenKind::FINAL_KW])]
+    #[case("vente := final", &[LanguageKind::Any], &[TokenKind::AWAIT_KW, TokenKind::FINAL_KW])]
+    #[case("nje := pour", &[LanguageKind::English, LanguageKind::French], &[TokenKind::IDENTIFIER, TokenKind::FOR_KW])]
+    #[case("#![lang(da)] vente := final", &[LanguageKind::English], &[TokenKind::AWAIT_KW, TokenKind::FINAL_KW])]
+    #[case("#![lang(ja)] ja := 1", &[LanguageKind::English], &[TokenKind::IDENTIFIER, TokenKind::INTEGER])]
+    fn test_scoped_keywords(
+        #[case] input: &str,
+        #[case] languages: &[LanguageKind],
+        #[case] expected: &[TokenKind],
+    ) {
+        let tokens = TokenStream::with_languages(input, false, languages);
+
+        // The tokens after the `#![lang(..)]` attribute (if any), without the `:=`.
+        let attr_len = if input.starts_with("#!") { 8 } else { 0 };
+        let kinds = tokens
+            .tokens()
+            .iter()
+            .map(|token| *token.kind())
+            .filter(|kind| !kind.is_whitespace())
+            .skip(attr_len)
+            .filter(|kind| kind != &TokenKind::DEFINE)
+            .collect::<Vec<_>>();
+        assert_eq!(kinds, expected);
+    }
+
+    // Spellings which are also common words (or names) in other languages.
+    #[rstest]
+    #[case("fall")] // German, Swedish `case`
+    #[case("cas")] // French `case`
+    #[case("caso")] // Spanish, Portuguese, Italian `case`
+    #[case("per")] // Italian `for`
+    #[case("für")] // German `for`
+    #[case("se")] // Portuguese, Italian `if`
+    #[case("wenn")] // German `if`
+    #[case("em")] // Portuguese `in`
+    #[case("lass")] // German `let`
+    #[case("standard")] // German `default`
+    #[case("selbst")] // German `self`
+    #[case("sí")] // Spanish `self`
+    fn test_other_languages_keywords_are_identifiers_in_english(#[case] input: &str) {
+        let tokens = TokenStream::with_languages(input, false, &[LanguageKind::English]);
+        assert_eq!(tokens.tokens()[0].kind(), &TokenKind::IDENTIFIER);
+
+        let tokens = TokenStream::with_languages(input, false, &[LanguageKind::Any]);
+        assert!(tokens.tokens()[0].kind().is_keyword(), "{input}");
+    }
+
+    #[test]
+    fn test_lang_attribute_tags_are_kept() {
+        let tokens = TokenStream::with_languages("#![lang(ja)]", false, &[LanguageKind::Japanese]);
+        assert_eq!(tokens.tokens()[5].kind(), &TokenKind::AND_KW);
+
+        let check = LanguageChecker::default().check(tokens.tokens());
+        assert_eq!(check.language(), Some(LanguageKind::Japanese));
+        assert!(check.declared());
+    }
+}
@@ -1,5 +1,17 @@
+/// Property tests of the lexer over **random inputs**.
+mod fuzz;
+
+/// Tests the **spoken language** checks of files.
+mod language_check;
+
+/// Keeps the files generated from the **keyword table** up to date.
+mod sourcegen;
+
 mod tokens;
 
+/// Tests the **translation** of files between spoken languages.
+mod translate;
+
 #[test]
 fn lex_test_suite() {
     // Iterate over all files in the `test_data/` directory
@@ -0,0 +1,84 @@
+use std::{
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// The keyword variants of `TokenKind`, generated from `keywords.toml` by the
+/// build script.
+const KINDS: &str = include_str!(concat!(env!("OUT_DIR"), "/kinds.rs"));
+
+/// The keyword table of the book, generated by the build script.
+const KEYWORD_TABLE: &str = include_str!(concat!(env!("OUT_DIR"), "/keyword_table.md"));
+
+/// The keyword patterns of the TextMate grammar, generated by the build script.
+const TM_KEYWORDS: &str = include_str!(concat!(env!("OUT_DIR"), "/tm_keywords.json"));
+
+#[test]
+fn sourcegen_keyword_kinds() {
+    let file = project_root().join("crates/compiler/leafc_lexer/src/token/kinds.rs");
+    let contents = fs::read_to_string(&file).unwrap();
+
+    let start = contents.find("    // region: keywords").unwrap();
+    let start = start + contents[start..].find('\n').unwrap() + 1;
+    let end = contents.find("    // endregion: keywords").unwrap();
+
+    ensure_file_contents(&file, &format!("{}{KINDS}{}", &contents[..start], &contents[end..]));
+}
+
+#[test]
+fn sourcegen_keyword_table() {
+    ensure_file_contents(&project_root().join("docs/src/keyword_table.md"), KEYWORD_TABLE);
+}
+
+#[test]
+fn sourcegen_textmate_keywords() {
+    let file = project_root().join("editors/vscode/syntaxes/leaf.tmLanguage.json");
+    let mut grammar: Value = serde_json::from_str(&fs::read_to_string(&file).unwrap()).unwrap();
+    let keywords: Vec<Value> = serde_json::from_str(TM_KEYWORDS).unwrap();
+
+    // Replace the patterns of the keyword scopes, keeping the other ones.
+    let patterns = grammar["repository"]["tokens"]["patterns"].as_array_mut().unwrap();
+    let generated =
+        |pattern: &Value| keywords.iter().any(|keyword| keyword["name"] == pattern["name"]);
+    let position = patterns.iter().position(generated).unwrap_or(0);
+    patterns.retain(|pattern| !generated(pattern));
+    patterns.splice(position..position, keywords.iter().cloned());
+
+    let mut contents = vec![];
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(b"    ");
+    let mut serializer = serde_json::Serializer::with_formatter(&mut contents, formatter);
+    grammar.serialize(&mut serializer).unwrap();
+    ensure_file_contents(&file, &format!("{}\n", String::from_utf8(contents).unwrap()));
+}
+
+/// Checks that the given file has the given contents, updating it (and
+/// failing) otherwise.
+fn ensure_file_contents(file: &Path, contents: &str) {
+    if let Ok(old_contents) = fs::read_to_string(file) {
+        if old_contents.replace("\r\n", "\n") == contents.replace("\r\n", "\n") {
+            // File is already up to date.
+            return;
+        }
+    }
+
+    let display_path = file.strip_prefix(project_root()).unwrap_or(file);
+    eprintln!(
+        "\n\x1b[31;1merror\x1b[0m: {} was not up-to-date, updating\n",
+        display_path.display()
+    );
+    if std::env::var("CI").is_ok() {
+        eprintln!("    NOTE: run `cargo test` locally and commit the updated files\n");
+    }
+    fs::write(file, contents).unwrap();
+    panic!("some file was not up to date and has been updated, simply re-run the tests");
+}
+
+fn project_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).ancestors().nth(3).unwrap().to_owned()
+}
@@ -0,0 +1,69 @@
+mod comment_test_suite {
+    use leafc_diagnostics::errors::LexicalError;
+    use leafc_lexer::{
+        lossless_lex,
+        TokenKind,
+    };
+    use logos::Logos;
+    use pretty_assertions_sorted::assert_eq;
+    use rstest::rstest;
+
+    // Test all comments (line and block comments, and their doc forms)
+
+    #[rstest]
+    #[case("// a comment", TokenKind::COMMENT)]
+    #[case("/// a doc comment", TokenKind::DOC_COMMENT)]
+    #[case("/* */", TokenKind::COMMENT)]
+    #[case("/**/", TokenKind::COMMENT)]
+    #[case("/***/", TokenKind::COMMENT)]
+    #[case("/*** not a doc comment */", TokenKind::COMMENT)]
+    #[case("/* a /* nested */ comment */", TokenKind::COMMENT)]
+    #[case("/* /* /* */ */ */", TokenKind::COMMENT)]
+    #[case("/*/ still open */", TokenKind::COMMENT)]
+    #[case("/* 🦀\n * across lines\n */", TokenKind::COMMENT)]
+    #[case("/** an outer doc comment */", TokenKind::DOC_COMMENT)]
+    #[case("/**\n * across lines\n */", TokenKind::DOC_COMMENT)]
+    #[case("/*! an inner doc comment */", TokenKind::DOC_COMMENT)]
+    #[case("/*!*/", TokenKind::DOC_COMMENT)]
+    #[case("/** a /* nested */ doc comment */", TokenKind::DOC_COMMENT)]
+    fn test_comment(#[case] input: &str, #[case] kind: TokenKind) {
+        let mut lexer = TokenKind::lexer(input);
+        let token = lexer.next();
+        assert_eq!(token, Some(kind));
+        assert_eq!(lexer.slice(), input);
+        assert_eq!(lexer.next(), None);
+    }
+
+    #[rstest]
+    #[case("/* a */ b */", &["/* a */", " ", "b", " ", "*", "/"])]
+    #[case("a /* b */ c", &["a", " ", "/* b */", " ", "c"])]
+    #[case("a/**/b", &["a", "/**/", "b"])]
+    #[case("a / *b", &["a", " ", "/", " ", "*", "b"])]
+    fn test_comment_boundaries(#[case] input: &str, #[case] expected: &[&str]) {
+        let tokens = lossless_lex(input);
+        let lexemes =
+            tokens.tokens().iter().map(|token| token.lexeme().as_str()).collect::<Vec<_>>();
+        assert_eq!(lexemes, expected);
+        assert!(tokens.errors().is_empty());
+    }
+
+    #[rstest]
+    #[case("/*", 0..2)]
+    #[case("a /* b", 2..6)]
+    #[case("/* a /* b */", 0..12)]
+    #[case("/** doc\nfn f() {}", 0..17)]
+    #[case("/*! doc /* */ */ /*", 17..19)]
+    fn test_unterminated_block_comment(#[case] input: &str, #[case] span: std::ops::Range<usize>) {
+        let tokens = lossless_lex(input);
+
+        // The comment runs to the end of the input.
+        let last = tokens.tokens().back().unwrap();
+        assert!(last.kind().is_comment());
+        assert_eq!(last.lexeme().as_str(), &input[span.start..]);
+
+        assert_eq!(tokens.errors().len(), 1);
+        assert_eq!(tokens.errors()[0].node, LexicalError::UnterminatedBlockComment);
+        let error_span: std::ops::Range<usize> = tokens.errors()[0].span.into();
+        assert_eq!(error_span, span);
+    }
+}
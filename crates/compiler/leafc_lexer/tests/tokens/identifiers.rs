@@ -18,6 +18,8 @@ mod identifier_test_suite {
     #[case("你好世界")]
     #[case("foo_bar")]
     #[case("المملكة")]
+    #[case("ligne_d'impression")]
+    #[case("l'été")]
     fn test_identifier(#[case] input: &str) {
         let mut lexer = TokenKind::lexer(input);
         let token = lexer.next();
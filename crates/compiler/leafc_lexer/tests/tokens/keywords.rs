@@ -422,6 +422,7 @@ mod keyword_test_suite {
     #[rstest]
     #[case("if")] // English
     #[case("si")] // Spanish, French
+    #[case("se")] // Portuguese, Italian
     #[case("indien")] // Dutch
     #[case("om")] // Swedish
     #[case("hvis")] // Danish, Norwegian
@@ -851,7 +852,7 @@ mod keyword_test_suite {
 
     #[rstest]
     #[case("self")] // English
-    #[case("se")] // Spanish
+    #[case("sí")] // Spanish
     #[case("soi")] // French
     #[case("zelf")] // Dutch
     #[case("själv")] // Swedish
@@ -873,7 +874,7 @@ mod keyword_test_suite {
 
     #[rstest]
     #[case("Self")] // English
-    #[case("Se")] // Spanish
+    #[case("Sí")] // Spanish
     #[case("Soi")] // French
     #[case("Zelf")] // Dutch
     #[case("Själv")] // Swedish
@@ -1,5 +1,15 @@
 mod literal_test_suite {
-    use leafc_lexer::TokenKind;
+    use std::ops::Range;
+
+    use leafc_diagnostics::errors::{
+        LexicalError,
+        NumberError,
+    };
+    use leafc_lexer::{
+        lossless_lex,
+        lossy_lex,
+        TokenKind,
+    };
     use logos::Logos;
     use pretty_assertions_sorted::assert_eq;
     use rstest::rstest;
@@ -69,6 +79,11 @@ mod literal_test_suite {
     #[case("r#\"\u{65e5}本\"#")]
     #[case("r#\"\\u{00000000}\"#")]
     #[case("r#\"Hello, world!\n\"#")]
+    #[case("r\"\"")]
+    #[case("r\"a\"")]
+    #[case("br#\"a\"#")]
+    #[case("r##\"a \"# b\"##")]
+    #[case("r###\"\"##\"###")]
     fn test_raw_string_literal(#[case] input: &str) {
         let mut lexer = TokenKind::lexer(input);
         let token = lexer.next();
@@ -76,6 +91,37 @@ mod literal_test_suite {
         assert_eq!(lexer.slice(), input);
     }
 
+    #[rstest]
+    #[case("r#\"a\"# \"b\"#", &[TokenKind::RAW_STRING, TokenKind::WHITESPACE, TokenKind::STRING, TokenKind::HASH])]
+    #[case("r##\"a\"#", &[TokenKind::RAW_STRING])]
+    fn test_raw_string_boundaries(#[case] input: &str, #[case] expected: &[TokenKind]) {
+        let tokens = lossless_lex(input);
+        let kinds = tokens.tokens().iter().map(|token| *token.kind()).collect::<Vec<_>>();
+        assert_eq!(kinds, expected);
+    }
+
+    #[rstest]
+    #[case("\"a\\qb\"", &[(2, 4)])]
+    #[case("\"\\x8F\"", &[(1, 5)])]
+    #[case("b\"\\xFF\"", &[])]
+    #[case("'\\u{110000}'", &[(1, 11)])]
+    #[case("b'\\u{7F}'", &[(2, 8)])]
+    #[case("b\"ä\\z\"", &[(2, 4), (4, 6)])]
+    #[case("r##\"a\"#", &[(0, 7)])]
+    #[case("x = \"\\{\" + '\\u{}'", &[(5, 7), (12, 16)])]
+    fn test_string_escape_errors(#[case] input: &str, #[case] expected: &[(usize, usize)]) {
+        let tokens = lossless_lex(input);
+        let spans = tokens
+            .errors()
+            .iter()
+            .map(|error| {
+                let span: Range<usize> = error.span.into();
+                (span.start, span.end)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(spans, expected);
+    }
+
     #[rstest]
     #[case("0")]
     #[case("123")]
@@ -135,4 +181,96 @@ mod literal_test_suite {
         assert_eq!(lexer.slice(), input);
         assert_eq!(token, Some(TokenKind::LIFETIME));
     }
+
+    #[rstest]
+    #[case("<'a, 'b>", &[TokenKind::LT, TokenKind::LIFETIME, TokenKind::COMMA, TokenKind::LIFETIME, TokenKind::GT])]
+    #[case("T: 'static {}", &[TokenKind::IDENTIFIER, TokenKind::COLON, TokenKind::LIFETIME, TokenKind::L_BRACE, TokenKind::R_BRACE])]
+    #[case("f('a', 'b')", &[TokenKind::IDENTIFIER, TokenKind::L_PAREN, TokenKind::RUNE, TokenKind::COMMA, TokenKind::RUNE, TokenKind::R_PAREN])]
+    fn test_lifetime_boundaries(#[case] input: &str, #[case] expected: &[TokenKind]) {
+        let tokens = lossy_lex(input);
+        let kinds = tokens.tokens().iter().map(|token| *token.kind()).collect::<Vec<_>>();
+        assert_eq!(kinds, expected);
+    }
+
+    // Numeric literals adjacent to operators (signs and dots belong to the
+    // operators, not the literals)
+
+    #[rstest]
+    #[case("1-2", &[TokenKind::INTEGER, TokenKind::MINUS, TokenKind::INTEGER])]
+    #[case("1+2.5", &[TokenKind::INTEGER, TokenKind::PLUS, TokenKind::FLOAT])]
+    #[case("1..2", &[TokenKind::INTEGER, TokenKind::DOTDOT, TokenKind::INTEGER])]
+    #[case("0..=9", &[TokenKind::INTEGER, TokenKind::DOTDOTEQ, TokenKind::INTEGER])]
+    #[case("1.5..2.", &[TokenKind::FLOAT, TokenKind::DOTDOT, TokenKind::FLOAT])]
+    #[case("a.b", &[TokenKind::IDENTIFIER, TokenKind::DOT, TokenKind::IDENTIFIER])]
+    fn test_numeric_literal_boundaries(#[case] input: &str, #[case] expected: &[TokenKind]) {
+        let tokens = lossy_lex(input);
+        let kinds = tokens.tokens().iter().map(|token| *token.kind()).collect::<Vec<_>>();
+        assert_eq!(kinds, expected);
+        assert_eq!(tokens.tokens().iter().map(|token| token.lexeme().as_str()).collect::<String>(), input);
+    }
+
+    // Format strings (strings with holes are split into fragments, and the
+    // holes are lexed as ordinary tokens)
+
+    #[rstest]
+    #[case("\"a{b}c\"", &[TokenKind::FORMAT_STRING_START, TokenKind::STRING_FRAGMENT, TokenKind::L_BRACE, TokenKind::IDENTIFIER, TokenKind::R_BRACE, TokenKind::STRING_FRAGMENT, TokenKind::FORMAT_STRING_END])]
+    #[case("\"{z}\"", &[TokenKind::FORMAT_STRING_START, TokenKind::L_BRACE, TokenKind::IDENTIFIER, TokenKind::R_BRACE, TokenKind::FORMAT_STRING_END])]
+    #[case("\"{{a}} {b}\"", &[TokenKind::FORMAT_STRING_START, TokenKind::STRING_FRAGMENT, TokenKind::L_BRACE, TokenKind::IDENTIFIER, TokenKind::R_BRACE, TokenKind::FORMAT_STRING_END])]
+    #[case("\"{f(\"{z}\")}\"", &[TokenKind::FORMAT_STRING_START, TokenKind::L_BRACE, TokenKind::IDENTIFIER, TokenKind::L_PAREN, TokenKind::FORMAT_STRING_START, TokenKind::L_BRACE, TokenKind::IDENTIFIER, TokenKind::R_BRACE, TokenKind::FORMAT_STRING_END, TokenKind::R_PAREN, TokenKind::R_BRACE, TokenKind::FORMAT_STRING_END])]
+    #[case("\"{ {z} }\"", &[TokenKind::FORMAT_STRING_START, TokenKind::L_BRACE, TokenKind::WHITESPACE, TokenKind::L_BRACE, TokenKind::IDENTIFIER, TokenKind::R_BRACE, TokenKind::WHITESPACE, TokenKind::R_BRACE, TokenKind::FORMAT_STRING_END])]
+    #[case("\"Bonjour, {🦀ferris_le_crabe}!\"", &[TokenKind::FORMAT_STRING_START, TokenKind::STRING_FRAGMENT, TokenKind::L_BRACE, TokenKind::IDENTIFIER, TokenKind::R_BRACE, TokenKind::STRING_FRAGMENT, TokenKind::FORMAT_STRING_END])]
+    fn test_format_string(#[case] input: &str, #[case] expected: &[TokenKind]) {
+        let tokens = lossless_lex(input);
+        let kinds = tokens.tokens().iter().map(|token| *token.kind()).collect::<Vec<_>>();
+        assert_eq!(kinds, expected);
+        assert!(tokens.errors().is_empty());
+        assert_eq!(tokens.tokens().iter().map(|token| token.lexeme().as_str()).collect::<String>(), input);
+    }
+
+    // Strings without holes (empty braces, format specs, escaped braces, byte
+    // and raw strings) stay a single token
+
+    #[rstest]
+    #[case("\"{}\"", TokenKind::STRING)]
+    #[case("\"{:?}\"", TokenKind::STRING)]
+    #[case("\"{{a}}\"", TokenKind::STRING)]
+    #[case("\"\\{a}\"", TokenKind::STRING)]
+    #[case("b\"{a}\"", TokenKind::STRING)]
+    #[case("r\"{a}\"", TokenKind::RAW_STRING)]
+    fn test_string_without_holes(#[case] input: &str, #[case] expected: TokenKind) {
+        let tokens = lossless_lex(input);
+        assert_eq!(tokens.tokens().len(), 1);
+        assert_eq!(*tokens.tokens()[0].kind(), expected);
+    }
+
+    #[rstest]
+    #[case("\"a {b\"", 0..6)]
+    #[case("\"{z} {w\"", 0..8)]
+    fn test_unterminated_format_string(#[case] input: &str, #[case] span: Range<usize>) {
+        let tokens = lossless_lex(input);
+        assert_eq!(*tokens.tokens()[0].kind(), TokenKind::FORMAT_STRING_START);
+        assert_eq!(tokens.errors().len(), 1);
+        assert_eq!(tokens.errors()[0].node, LexicalError::UnterminatedFormatString);
+        let error_span: Range<usize> = tokens.errors()[0].span.into();
+        assert_eq!(error_span, span);
+    }
+
+    // Numeric literals whose values don't fit in their types
+
+    #[rstest]
+    #[case("z := 256u8", 5..10, NumberError::IntegerOutOfRange("u8"))]
+    #[case("f(0x81_i8)", 2..9, NumberError::IntegerOutOfRange("i8"))]
+    #[case("1 + 340282366920938463463374607431768211456", 4..43, NumberError::IntegerOutOfRange("u128"))]
+    #[case("w := 3.5e38f32", 5..14, NumberError::FloatOutOfRange("f32"))]
+    fn test_number_out_of_range(
+        #[case] input: &str,
+        #[case] span: Range<usize>,
+        #[case] error: NumberError,
+    ) {
+        let tokens = lossless_lex(input);
+        assert_eq!(tokens.errors().len(), 1);
+        assert_eq!(tokens.errors()[0].node, LexicalError::InvalidNumber(error));
+        let error_span: Range<usize> = tokens.errors()[0].span.into();
+        assert_eq!(error_span, span);
+    }
 }
@@ -61,7 +61,6 @@ mod mathematical_constants_test_suite {
 
     #[rstest]
     #[case("catalan")]
-    #[case("K")]
     #[case("𝑘")]
     fn test_catalan(#[case] input: &str) {
         let mut lexer = TokenKind::lexer(input);
@@ -70,6 +69,17 @@ mod mathematical_constants_test_suite {
         assert_eq!(lexer.slice(), input);
     }
 
+    #[rstest]
+    #[case("K")]
+    #[case("k")]
+    #[case("Catalan")]
+    fn test_catalan_identifiers(#[case] input: &str) {
+        let mut lexer = TokenKind::lexer(input);
+        let token = lexer.next();
+        assert_eq!(token, Some(TokenKind::IDENTIFIER));
+        assert_eq!(lexer.slice(), input);
+    }
+
     #[rstest]
     #[case("γ")]
     #[case("𝛾")]
@@ -119,3 +129,58 @@ mod mathematical_constants_test_suite {
         assert_eq!(lexer.slice(), input);
     }
 }
+
+mod mathematical_operators_test_suite {
+    use leafc_lexer::TokenKind;
+    use logos::Logos;
+    use pretty_assertions_sorted::assert_eq;
+    use rstest::rstest;
+
+    // Test all mathematical operators (and the aliases of ASCII operators)
+
+    #[rstest]
+    #[case("∧", TokenKind::DOUBLE_AMPERSAND)]
+    #[case("∨", TokenKind::DOUBLE_PIPE)]
+    #[case("≠", TokenKind::NE)]
+    #[case("≤", TokenKind::LE)]
+    #[case("≥", TokenKind::GE)]
+    #[case("∼", TokenKind::TILDE)]
+    #[case("√", TokenKind::SQRT)]
+    #[case("∛", TokenKind::CBRT)]
+    #[case("∜", TokenKind::FOURTH_ROOT)]
+    #[case("∩", TokenKind::INTERSECTION)]
+    #[case("∪", TokenKind::UNION)]
+    #[case("⊂", TokenKind::SUBSET)]
+    #[case("⊃", TokenKind::SUPERSET)]
+    #[case("⊄", TokenKind::NOT_SUBSET)]
+    #[case("⊅", TokenKind::NOT_SUPERSET)]
+    #[case("⊆", TokenKind::SUBSET_EQ)]
+    #[case("⊇", TokenKind::SUPERSET_EQ)]
+    #[case("≈", TokenKind::APPROX_EQ)]
+    #[case("≡", TokenKind::IDENTICAL)]
+    #[case("∝", TokenKind::PROPORTIONAL)]
+    #[case("∠", TokenKind::ANGLE)]
+    #[case("∫", TokenKind::INTEGRAL)]
+    #[case("∮", TokenKind::CONTOUR_INTEGRAL)]
+    #[case("∯", TokenKind::SURFACE_INTEGRAL)]
+    #[case("∰", TokenKind::VOLUME_INTEGRAL)]
+    #[case("∴", TokenKind::THEREFORE)]
+    #[case("∵", TokenKind::BECAUSE)]
+    fn test_mathematical_operator(#[case] input: &str, #[case] kind: TokenKind) {
+        let mut lexer = TokenKind::lexer(input);
+        let token = lexer.next();
+        assert_eq!(token, Some(kind));
+        assert_eq!(lexer.slice(), input);
+    }
+
+    #[rstest]
+    #[case("√z", &[TokenKind::SQRT, TokenKind::IDENTIFIER])]
+    #[case("a≤π", &[TokenKind::IDENTIFIER, TokenKind::LE, TokenKind::PI])]
+    #[case("z∩w", &[TokenKind::IDENTIFIER, TokenKind::INTERSECTION, TokenKind::IDENTIFIER])]
+    #[case("∫f", &[TokenKind::INTEGRAL, TokenKind::IDENTIFIER])]
+    #[case("p∴q", &[TokenKind::IDENTIFIER, TokenKind::THEREFORE, TokenKind::IDENTIFIER])]
+    fn test_mathematical_operator_boundaries(#[case] input: &str, #[case] expected: &[TokenKind]) {
+        let tokens = TokenKind::lexer(input).collect::<Vec<_>>();
+        assert_eq!(tokens, expected);
+    }
+}
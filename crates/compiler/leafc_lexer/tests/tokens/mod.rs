@@ -1,3 +1,6 @@
+/// Tests **comments**.
+mod comments;
+
 /// Tests **identifiers**.
 mod identifiers;
 
@@ -1,5 +1,8 @@
 mod superscript_literals_test_suite {
-    use leafc_lexer::TokenKind;
+    use leafc_lexer::{
+        lossless_lex,
+        TokenKind,
+    };
     use logos::Logos;
     use pretty_assertions_sorted::assert_eq;
     use rstest::rstest;
@@ -42,4 +45,22 @@ mod superscript_literals_test_suite {
         assert_eq!(token, Some(TokenKind::FLOAT_SUP));
         assert_eq!(lexer.slice(), input);
     }
+
+    // `·` and `¹` share their first byte, which used to make the lexer split a
+    // `·` in two.
+    #[rstest]
+    #[case("⁰··", &[(TokenKind::FLOAT_SUP, "⁰·"), (TokenKind::ERROR, "·")])]
+    #[case("²·¹·", &[(TokenKind::FLOAT_SUP, "²·¹"), (TokenKind::ERROR, "·")])]
+    fn test_superscript_followed_by_dot(
+        #[case] input: &str,
+        #[case] expected: &[(TokenKind, &str)],
+    ) {
+        let tokens = lossless_lex(input);
+        let tokens = tokens
+            .tokens()
+            .iter()
+            .map(|token| (*token.kind(), token.lexeme().as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(tokens, expected);
+    }
 }
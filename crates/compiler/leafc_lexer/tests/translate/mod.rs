@@ -0,0 +1,95 @@
+mod translate_test_suite {
+    use leafc_cfg::lang::LanguageKind;
+    use leafc_diagnostics::errors::LexicalError;
+    use leafc_lexer::translate::translate;
+    use pretty_assertions_sorted::assert_eq;
+    use rstest::rstest;
+
+    const HELLO_WORLD: &str =
+        include_str!("../../../../../examples/leaf/hello_world/hello_world.leaf");
+    const BONJOUR_LE_MONDE: &str =
+        include_str!("../../../../../examples/leaf/hello_world/bonjour_le_monde.leaf");
+
+    #[rstest]
+    #[case("", LanguageKind::French, "")]
+    #[case("fn f() {}", LanguageKind::French, "fonction f() {}")]
+    #[case("fn f() {}", LanguageKind::German, "funktion f() {}")]
+    #[case("fonction f() {}", LanguageKind::English, "fn f() {}")]
+    #[case("x := True || false", LanguageKind::French, "x := Vrai || faux")]
+    #[case("for x {}", LanguageKind::Danish, "for x {}")]
+    #[case(
+        "fn f() { /* if */ \"while\" // for\n}",
+        LanguageKind::French,
+        "fonction f() { /* if */ \"while\" // for\n}"
+    )]
+    #[case("#![lang(fr)]\nfonction f() {}", LanguageKind::English, "#![lang(en)]\nfn f() {}")]
+    #[case(
+        "#![lang(fr-CA)]\nfonction f() {}",
+        LanguageKind::German,
+        "#![lang(de)]\nfunktion f() {}"
+    )]
+    #[case("fn f() { vente := 1 }", LanguageKind::French, "fonction f() { vente := 1 }")]
+    fn test_translate(#[case] input: &str, #[case] to: LanguageKind, #[case] expected: &str) {
+        let translation = translate(input, &[LanguageKind::Any], to);
+
+        assert_eq!(translation.text(), expected);
+    }
+
+    #[rstest]
+    #[case(HELLO_WORLD, LanguageKind::English, LanguageKind::French)]
+    #[case(BONJOUR_LE_MONDE, LanguageKind::French, LanguageKind::English)]
+    fn test_round_trip(#[case] input: &str, #[case] from: LanguageKind, #[case] to: LanguageKind) {
+        let translation = translate(input, &[LanguageKind::Any], to);
+        assert_eq!(translation.from(), &Some(from));
+        assert_ne!(translation.text(), input);
+
+        let round_trip = translate(translation.text(), &[LanguageKind::Any], from);
+        assert_eq!(round_trip.from(), &Some(to));
+        assert_eq!(round_trip.text(), input);
+    }
+
+    #[rstest]
+    #[case("fn pour() {}", &[LanguageKind::English], LanguageKind::French, &["`pour` is a keyword in French"])]
+    #[case(
+        "fn pour() {}",
+        &[LanguageKind::Any],
+        LanguageKind::French,
+        &["`pour` is French, but the rest of the file is English"]
+    )]
+    #[case("fn cas() {}", &[LanguageKind::English], LanguageKind::French, &["`cas` is a keyword in French"])]
+    #[case(
+        "fonction f() { while vrai {} }",
+        &[LanguageKind::Any],
+        LanguageKind::English,
+        &["`while` is English, but the rest of the file is French"]
+    )]
+    fn test_translate_errors(
+        #[case] input: &str,
+        #[case] from: &[LanguageKind],
+        #[case] to: LanguageKind,
+        #[case] expected: &[&str],
+    ) {
+        let translation = translate(input, from, to);
+
+        let messages = translation
+            .errors()
+            .iter()
+            .map(|error| match &error.node {
+                LexicalError::Untranslatable(error) => error.to_string(),
+                LexicalError::MixedLanguages(error) => error.to_string(),
+                error => panic!("unexpected error: {error:?}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(messages, expected);
+    }
+
+    #[test]
+    fn test_translate_from_unsupported_language() {
+        let translation = translate("fn f() {}", &[LanguageKind::French], LanguageKind::German);
+
+        // `fn` isn't French, so it's an identifier (and is left as it is).
+        assert_eq!(translation.from(), &Some(LanguageKind::French));
+        assert_eq!(translation.text(), "fn f() {}");
+        assert_eq!(translation.errors(), &[]);
+    }
+}
@@ -1,7 +1,7 @@
 use cargo_emit::rerun_if_changed;
 
 fn main() {
-    // lalrpop();
+    lalrpop();
 }
 
 /// The **LALRPOP** grammar file.
@@ -10,6 +10,11 @@ const LALRPOP_GRAMMAR: &str = "src/grammar/lalrpop/leaf.lalrpop";
 fn lalrpop() {
     // Only regenerate the correctness parser if the grammar has changed
     rerun_if_changed!(LALRPOP_GRAMMAR);
+
+    // The (default) lane table construction of LALRPOP panics on the grammar,
+    // so build the canonical LR(1) tables instead (which also gives precise
+    // reports for any conflicts).
+    std::env::set_var("LALRPOP_LANE_TABLE", "disabled");
     lalrpop::Configuration::new()
         .use_cargo_dir_conventions()
         .emit_report(true)
@@ -1,6 +1,243 @@
-// #[derive(Debug, Clone, Copy)]
-// pub(crate) enum Event {
-//     StartNode { kind: NodeKind },
-//     FinishNode,
-//     AddToken,
-// }
+//! The **events** emitted by the [`Parser`][crate::parser::Parser].
+//!
+//! Rather than building the syntax tree directly, the parser records a flat
+//! list of [`Event`]s (i.e. _start a node_, _add a token_, _finish a node_,
+//! _report an error_). Once parsing is complete, the events are **replayed**
+//! into a [`SyntaxTreeBuilder`] by [`process`], which is also responsible for
+//! attaching **trivia** (i.e. whitespace and comments) to the tree. Decoupling
+//! the grammar from the tree construction allows the parser to wrap an already
+//! parsed node in a new parent (see [`CompletedMarker::precede`]) without
+//! having to rebuild any part of the tree.
+//!
+//! [`CompletedMarker::precede`]: crate::parser::CompletedMarker::precede
+
+use std::mem;
+
+use leafc_diagnostics::errors::SyntaxError;
+use leafc_lexer::{
+    token::Token,
+    TokenKind,
+};
+use leafc_syntax::{
+    SyntaxKind::{
+        self,
+        *,
+    },
+    SyntaxTreeBuilder,
+};
+use leafc_utils::{
+    Span,
+    Spanned,
+};
+
+/// An **event** emitted by the parser during parsing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// Starts a new node of the given `kind`.
+    ///
+    /// A `StartNode` event with a kind of `TOMBSTONE` is ignored when the
+    /// events are processed (i.e. an **abandoned** marker).
+    ///
+    /// The `forward_parent` is the **relative offset** to the `StartNode`
+    /// event of the node which should become the **parent** of this node.
+    /// This is how a node which has already been parsed (e.g. the `lhs` of a
+    /// binary expression) is wrapped in a new node.
+    StartNode { kind: SyntaxKind, forward_parent: Option<u32> },
+
+    /// Finishes the most recently started node.
+    FinishNode,
+
+    /// Adds the **next non-trivia token** in the token stream to the current
+    /// node as a token of the given `kind`.
+    AddToken { kind: SyntaxKind },
+
+    /// Adds the **first character** of the next non-trivia token in the token
+    /// stream to the current node as a token of the given `kind` (e.g. the
+    /// first `>` of a `>>`). The rest of the token is added by the following
+    /// `AddToken` event.
+    SplitToken { kind: SyntaxKind },
+
+    /// Reports a **syntax error** at the current position.
+    Error { error: SyntaxError },
+}
+
+impl Event {
+    /// Creates a new placeholder `StartNode` event, which is **ignored**
+    /// unless the node is later completed.
+    pub(crate) fn tombstone() -> Self {
+        Event::StartNode { kind: TOMBSTONE, forward_parent: None }
+    }
+}
+
+/// **Replays** the given `events` into the `builder`, interleaving the
+/// **trivia** tokens found in `tokens` which the parser skipped over.
+///
+/// Returns the **syntax errors** reported during parsing, each spanning the
+/// token it was reported at (or the end of the file).
+pub(crate) fn process(
+    tokens: &[Token],
+    mut events: Vec<Event>,
+    builder: &mut SyntaxTreeBuilder,
+) -> Vec<Spanned<SyntaxError>> {
+    let mut sink =
+        Sink { tokens, cursor: 0, split_offset: 0, depth: 0, builder, errors: Vec::new() };
+    let mut forward_parents = Vec::new();
+
+    for i in 0..events.len() {
+        match mem::replace(&mut events[i], Event::tombstone()) {
+            Event::StartNode { kind, forward_parent } => {
+                // Walk the chain of forward parents, collecting the kinds of
+                // every node which must be started before this one.
+                forward_parents.push(kind);
+                let mut idx = i;
+                let mut fp = forward_parent;
+                while let Some(offset) = fp {
+                    idx += offset as usize;
+                    fp = match mem::replace(&mut events[idx], Event::tombstone()) {
+                        Event::StartNode { kind, forward_parent } => {
+                            forward_parents.push(kind);
+                            forward_parent
+                        }
+                        _ => unreachable!("forward parent must point to a `StartNode` event"),
+                    };
+                }
+
+                for kind in forward_parents.drain(..).rev() {
+                    if kind != TOMBSTONE {
+                        sink.start_node(kind);
+                    }
+                }
+            }
+            Event::FinishNode => sink.finish_node(),
+            Event::AddToken { kind } => sink.add_token(kind),
+            Event::SplitToken { kind } => sink.split_token(kind),
+            Event::Error { error } => sink.error(error),
+        }
+    }
+
+    sink.errors
+}
+
+/// Builds the syntax tree from the processed events, keeping track of the
+/// position within the **lossless** token stream.
+struct Sink<'t, 'b> {
+    tokens:       &'t [Token],
+    cursor:       usize,
+    /// The length of the prefix of the current token which has already been
+    /// added by a `SplitToken` event.
+    split_offset: usize,
+    depth:        usize,
+    builder:      &'b mut SyntaxTreeBuilder,
+    errors:       Vec<Spanned<SyntaxError>>,
+}
+
+impl Sink<'_, '_> {
+    fn start_node(&mut self, kind: SyntaxKind) {
+        // Leading trivia of the file belongs to the root node.
+        if self.depth == 0 {
+            self.builder.start_node(kind);
+            self.depth += 1;
+            return;
+        }
+
+        let n_trivia = self.n_trivia_ahead();
+        let leading = &self.tokens[self.cursor..self.cursor + n_trivia];
+        let n_attached = n_attached_trivia(kind, leading);
+
+        self.add_trivia(n_trivia - n_attached);
+        self.builder.start_node(kind);
+        self.add_trivia(n_attached);
+        self.depth += 1;
+    }
+
+    fn finish_node(&mut self) {
+        self.depth -= 1;
+
+        // Trailing trivia of the file belongs to the root node.
+        if self.depth == 0 {
+            self.add_trivia(self.tokens.len() - self.cursor);
+        }
+
+        self.builder.finish_node();
+    }
+
+    fn add_token(&mut self, kind: SyntaxKind) {
+        self.add_trivia(self.n_trivia_ahead());
+
+        let token = &self.tokens[self.cursor];
+        if *token.kind() == TokenKind::ERROR {
+            let error = SyntaxError::UnknownToken(token.lexeme().clone());
+            self.errors.push(Spanned { span: *token.span(), node: error });
+        }
+
+        self.builder.add_raw_token(kind, &token.lexeme()[self.split_offset..]);
+        self.split_offset = 0;
+        self.cursor += 1;
+    }
+
+    fn split_token(&mut self, kind: SyntaxKind) {
+        self.add_trivia(self.n_trivia_ahead());
+
+        let lexeme = self.tokens[self.cursor].lexeme();
+        let len = lexeme.chars().next().map_or(0, char::len_utf8);
+        self.builder.add_raw_token(kind, &lexeme[..len]);
+        self.split_offset = len;
+    }
+
+    /// Records a syntax `error` at the next non-trivia token.
+    fn error(&mut self, error: SyntaxError) {
+        let span = match self.tokens[self.cursor..].iter().find(|token| !is_trivia(token)) {
+            Some(token) => {
+                let span = *token.span();
+                let start = usize::from(span.start()) + self.split_offset;
+                Span::new(start..span.end().into())
+            }
+            // Errors at the end of the file point just past its last token.
+            None => {
+                let end = self.tokens.last().map_or(0, |token| token.span().end().into());
+                Span::new(end..end)
+            }
+        };
+        self.errors.push(Spanned { span, node: error });
+    }
+
+    fn add_trivia(&mut self, n: usize) {
+        for token in &self.tokens[self.cursor..self.cursor + n] {
+            self.builder.add_token(token);
+        }
+        self.cursor += n;
+    }
+
+    fn n_trivia_ahead(&self) -> usize {
+        self.tokens[self.cursor..].iter().take_while(|token| is_trivia(token)).count()
+    }
+}
+
+/// Returns `true` if the given token is **trivia** (i.e. whitespace or a
+/// comment), which the parser skips over.
+pub(crate) fn is_trivia(token: &Token) -> bool {
+    token.kind().is_whitespace() || token.kind().is_comment()
+}
+
+/// The number of trailing `trivia` tokens which should be **attached** to (i.e.
+/// placed inside of) a node of the given `kind`, rather than its parent.
+///
+/// This is how **comments** directly preceding an item end up as part of the
+/// item itself (e.g. for doc comments). A blank line breaks the attachment.
+fn n_attached_trivia(kind: SyntaxKind, trivia: &[Token]) -> usize {
+    match kind {
+        ENUM | FN | IMPL | MODULE | STRUCT | TRAIT | TYPE_ALIAS | USE | VARIANT |
+        RECORD_FIELD | TUPLE_FIELD => {
+            let mut res = 0;
+            for (i, token) in trivia.iter().rev().enumerate() {
+                match token.kind() {
+                    TokenKind::WHITESPACE if token.lexeme().matches('\n').count() > 1 => break,
+                    TokenKind::COMMENT | TokenKind::DOC_COMMENT => res = i + 1,
+                    _ => (),
+                }
+            }
+            res
+        }
+        _ => 0,
+    }
+}
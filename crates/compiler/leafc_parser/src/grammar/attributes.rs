@@ -0,0 +1,57 @@
+use super::*;
+
+/// Parses any number of **inner attributes** (e.g. `#![lang(fr)]`).
+pub(super) fn inner_attrs(p: &mut Parser) {
+    while p.at(T![#]) && p.nth_at(1, T![!]) {
+        attr(p, true);
+    }
+}
+
+/// Parses any number of **outer attributes** (e.g. `#[test]`).
+pub(super) fn outer_attrs(p: &mut Parser) {
+    while p.at(T![#]) {
+        attr(p, false);
+    }
+}
+
+/// ```text
+/// Attr =
+///   '#' '!'? '[' Meta ']'
+/// ```
+fn attr(p: &mut Parser, inner: bool) {
+    let m = p.start();
+    p.bump(T![#]);
+
+    if inner {
+        p.bump(T![!]);
+    }
+
+    if p.eat(T!['[']) {
+        meta(p);
+        p.expect(T![']']);
+    } else {
+        p.error(SyntaxError::Expected(Expected::Token(format!("{:?}", T!['[']).into())));
+    }
+
+    m.complete(p, ATTR);
+}
+
+/// ```text
+/// Meta =
+///   Path ('=' Expr | TokenTree)?
+/// ```
+fn meta(p: &mut Parser) {
+    let m = p.start();
+    paths::path(p);
+
+    match p.current() {
+        T![=] => {
+            p.bump(T![=]);
+            expressions::expr(p);
+        }
+        T!['('] | T!['['] | T!['{'] => token_tree(p),
+        _ => (),
+    }
+
+    m.complete(p, META);
+}
@@ -0,0 +1,1225 @@
+//! The **expression** grammar of Leaf, implemented as a **Pratt parser** (i.e.
+//! a recursive descent parser driven by the _binding power_ of each operator).
+//!
+//! ## Precedence
+//!
+//! From **tightest** to **loosest** binding:
+//!
+//! | Operator                                     | Binding power | Associativity |
+//! |----------------------------------------------|---------------|---------------|
+//! | Calls `f(x)`, indexing `a[i]`, fields `a.b`  | -             | left          |
+//! | Superscript exponents `x²` `2⁽n + 1⁾`        | -             | -             |
+//! | `**`                                         | 13            | **right**     |
+//! | Unary `-` `!` `not` `&` `√` `∛` `∜`          | 12            | -             |
+//! | Unary `∠` `∫` `∮` `∯` `∰`                    | 12            | -             |
+//! | `*` `/` `%`                                  | 11            | left          |
+//! | `+` `-`                                      | 10            | left          |
+//! | `<<` `>>`                                    | 9             | left          |
+//! | `&` `∩`                                      | 8             | left          |
+//! | `^`                                          | 7             | left          |
+//! | <code>&#124;</code> `∪`                      | 6             | left          |
+//! | `==` `!=` `<` `<=` `>` `>=` `is` `isnt`      | 5             | left          |
+//! | `⊂` `⊃` `⊄` `⊅` `⊆` `⊇` `≈` `≡` `∝`          | 5             | left          |
+//! | `and` `&&` `∧`                               | 4             | left          |
+//! | `or` <code>&#124;&#124;</code> `∨` `∴` `∵`   | 3             | left          |
+//! | `..` `..=`                                   | 2             | none          |
+//! | `=` `+=` `-=` `*=` `/=` `%=` ... `<-`        | 1             | **right**     |
+//!
+//! **NOTE**: `**` binds tighter than the unary operators, so `-2 ** 2` is
+//! `-(2 ** 2)` (i.e. `-4`), and is right-associative, so `2 ** 3 ** 2` is
+//! `2 ** (3 ** 2)`.
+//!
+//! A **superscript exponent** (e.g. `x²`, `x⁻¹` or `2⁽n + 1⁾`) is a power
+//! without an operator token, which binds tighter than any other operator (so
+//! `-x²` is `-(x²)` and `2 ** x²` is `2 ** (x²)`). It must directly follow its
+//! base, and a superscript can't follow another one (e.g. `x²⁽n⁾`). Inside a
+//! superscript group, a signed superscript literal after an operand is a
+//! **sum** (e.g. `2⁽ⁿ⁺¹⁾` is `2 ** (n + 1)`).
+//!
+//! The mathematical spellings `≠`, `≤` and `≥` are lexed as `!=`, `<=` and
+//! `>=` (and `∧` and `∨` as `&&` and `||`), so they need no rules of their own.
+
+use super::*;
+
+/// ```text
+/// Stmt =
+///   ';'
+/// | ExprStmt
+/// | Item
+/// | LetStmt
+/// ```
+pub(super) fn stmt(p: &mut Parser) {
+    if p.eat(T![;]) {
+        return;
+    }
+
+    let m = p.start();
+    attributes::outer_attrs(p);
+
+    if p.at(T![let]) || (p.at(IDENTIFIER) && p.nth_at(1, T![:=])) {
+        let_stmt(p, m);
+        return;
+    }
+
+    let has_visibility = opt_visibility(p);
+    let m = match items::opt_item(p, m) {
+        Ok(()) => return,
+        Err(m) => m,
+    };
+
+    if has_visibility {
+        p.error(SyntaxError::Expected(Expected::ItemAfterVisibility));
+        m.complete(p, ERROR);
+        return;
+    }
+
+    if !at_expr_start(p) {
+        // Skip to the next statement, but leave the closing `}` of the
+        // enclosing `StmtList` alone.
+        p.error(SyntaxError::Expected(Expected::Statement));
+        if !p.at(EOF) && !p.at(T!['}']) {
+            p.skip_to(STMT_RECOVERY_SET);
+        }
+        m.complete(p, ERROR);
+        return;
+    }
+
+    expr_stmt(p, m);
+}
+
+/// The tokens which can start a statement (other than an expression), where
+/// parsing **recovers** after skipping over an invalid statement. A `;` or a
+/// new line also ends the skipped statement.
+const STMT_RECOVERY_SET: TokenSet = items::ITEM_RECOVERY_SET.union(TokenSet::new(&[
+    T![;],
+    T![let],
+    T![if],
+    T![match],
+    T![loop],
+    T![while],
+    T![for],
+    T![break],
+    T![continue],
+    T![return],
+    T![defer],
+]));
+
+/// ```text
+/// ExprStmt =
+///   Expr ';'?
+/// ```
+///
+/// An expression directly before the closing `}` of a block (without a `;`)
+/// is the block's **tail expression** rather than a statement.
+///
+/// A **block-like** expression (e.g. an `if` or a `loop`) always ends the
+/// statement, so `if c { a } -1` is two statements rather than a subtraction.
+fn expr_stmt(p: &mut Parser, m: Marker) {
+    if at_block_like(p) {
+        atom_expr(p);
+    } else {
+        expr(p);
+    }
+
+    if p.at(T!['}']) {
+        m.abandon(p);
+        return;
+    }
+
+    p.eat(T![;]);
+    m.complete(p, EXPR_STMT);
+}
+
+/// Parses both forms of a `LetStmt` (i.e. `let mut x: T = e` and `x := e`),
+/// which produce the **same** tree shape.
+///
+/// ```text
+/// LetStmt =
+///   Attr* 'let' Pat (':' Type)? '=' initializer:Expr ';'?
+/// | Attr* Pat ':=' initializer:Expr ';'?
+/// ```
+fn let_stmt(p: &mut Parser, m: Marker) {
+    if p.eat(T![let]) {
+        patterns::pattern(p);
+        if p.eat(T![:]) {
+            types::type_(p);
+        }
+        p.expect(T![=]);
+    } else {
+        patterns::pattern(p);
+        p.bump(T![:=]);
+    }
+
+    expr(p);
+    p.eat(T![;]);
+    m.complete(p, LET_STMT);
+}
+
+/// ```text
+/// BlockExpr =
+///   Attr* Label? ('unsafe' | 'async' | 'const')? StmtList
+/// ```
+pub(super) fn block_expr(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    finish_block_expr(p, m)
+}
+
+/// Parses the rest of a [`BlockExpr`][block_expr], where the marker `m` has
+/// already consumed any [label].
+fn finish_block_expr(p: &mut Parser, m: Marker) -> CompletedMarker {
+    if p.at_any(BLOCK_QUALIFIERS) {
+        p.bump_any();
+    }
+    stmt_list(p);
+    m.complete(p, BLOCK_EXPR)
+}
+
+/// Parses a [`BlockExpr`][block_expr], reporting a syntax error if there is
+/// none (e.g. for the body of an `if` or a loop).
+fn expect_block_expr(p: &mut Parser) {
+    if at_block_expr(p) {
+        block_expr(p);
+    } else {
+        p.error(SyntaxError::Expected(Expected::Block));
+    }
+}
+
+/// ```text
+/// StmtList =
+///   '{'
+///     Attr*
+///     statements:Stmt*
+///     tail_expr:Expr?
+///   '}'
+/// ```
+pub(super) fn stmt_list(p: &mut Parser) {
+    let m = p.start();
+    p.expect(T!['{']);
+    attributes::inner_attrs(p);
+
+    while !p.at(EOF) && !p.at(T!['}']) {
+        stmt(p);
+    }
+
+    p.expect(T!['}']);
+    m.complete(p, STMT_LIST);
+}
+
+/// The keywords which may qualify a [`BlockExpr`][block_expr] (e.g. `unsafe {
+/// .. }`).
+const BLOCK_QUALIFIERS: &[SyntaxKind] = &[T![unsafe], T![async], T![const]];
+
+/// Returns `true` if the current tokens start a [`BlockExpr`][block_expr].
+fn at_block_expr(p: &Parser) -> bool {
+    p.at(T!['{']) || (p.at_any(BLOCK_QUALIFIERS) && p.nth_at(1, T!['{']))
+}
+
+/// Returns `true` if the current tokens start a [label].
+fn at_label(p: &Parser) -> bool {
+    p.at(LIFETIME) && p.nth_at(1, T![:])
+}
+
+/// The expressions which end with a block, and so don't need a `;` or `,`
+/// after them (i.e. in statements and match arms).
+const BLOCK_LIKE: &[SyntaxKind] =
+    &[BLOCK_EXPR, IF_EXPR, LOOP_EXPR, WHILE_EXPR, FOR_EXPR, MATCH_EXPR];
+
+/// Returns `true` if the current tokens start a [block-like][BLOCK_LIKE]
+/// expression.
+fn at_block_like(p: &Parser) -> bool {
+    at_block_expr(p) ||
+        at_label(p) ||
+        p.at_any(&[T![if], T![match], T![loop], T![while], T![for]])
+}
+
+/// Parses the **restricted** expression allowed as the value of a const
+/// generic (i.e. a literal, a negated literal, a block or a path), as a `>`
+/// would otherwise be parsed as a comparison.
+pub(super) fn const_arg(p: &mut Parser) {
+    match p.current() {
+        kind if LITERAL_FIRST.contains(&kind) => {
+            literal(p);
+        }
+        T![-] if LITERAL_FIRST.contains(&p.nth(1)) => {
+            let m = p.start();
+            p.bump(T![-]);
+            literal(p);
+            m.complete(p, PREFIX_EXPR);
+        }
+        _ if at_block_expr(p) => {
+            block_expr(p);
+        }
+        _ if paths::is_path_start(p) => {
+            path_expr(p);
+        }
+        _ => p.error(SyntaxError::Expected(Expected::ConstArg)),
+    }
+}
+
+/// The binding power of the **unary** (i.e. prefix) operators.
+const PREFIX_BP: u8 = 12;
+
+/// The binding power of the **range** operators (`..` and `..=`).
+const RANGE_BP: u8 = 2;
+
+/// The tokens which start a [`Literal`][literal].
+pub(super) const LITERAL_FIRST: &[SyntaxKind] = &[
+    INTEGER,
+    FLOAT,
+    RUNE,
+    STRING,
+    RAW_STRING,
+    T![true],
+    T![false],
+    PI,
+    EULER,
+    PHI,
+    TAU,
+    CATALAN,
+    EULERGAMMA,
+    INF,
+    NAN,
+];
+
+/// The **unary** (i.e. prefix) operators.
+const PREFIX_OPS: &[SyntaxKind] = &[
+    T![-],
+    T![!],
+    T![not],
+    T!['√'],
+    T!['∛'],
+    T!['∜'],
+    T!['∠'],
+    T!['∫'],
+    T!['∮'],
+    T!['∯'],
+    T!['∰'],
+];
+
+/// The **associativity** of a binary operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Associativity {
+    Left,
+    Right,
+}
+
+/// ```text
+/// Expr =
+///   BinExpr
+/// | BlockExpr
+/// | BreakExpr
+/// | CallExpr
+/// | ContinueExpr
+/// | DeferExpr
+/// | FallthroughExpr
+/// | FieldExpr
+/// | ForExpr
+/// | FormatStringExpr
+/// | IfExpr
+/// | IndexExpr
+/// | Literal
+/// | LoopExpr
+/// | MacroExpr
+/// | MatchExpr
+/// | ParenExpr
+/// | PathExpr
+/// | PrefixExpr
+/// | RangeExpr
+/// | RefExpr
+/// | ReturnExpr
+/// | SuperscriptExpr
+/// | WhileExpr
+/// ```
+pub(super) fn expr(p: &mut Parser) -> Option<CompletedMarker> {
+    expr_bp(p, 1)
+}
+
+/// Returns `true` if the current token can start an [expression][expr].
+pub(super) fn at_expr_start(p: &Parser) -> bool {
+    LITERAL_FIRST.contains(&p.current()) ||
+        p.at_any(PREFIX_OPS) ||
+        p.at_any(&[FORMAT_STRING_START, T!['('], T![&], T![..], T![..=]]) ||
+        paths::is_path_start(p) ||
+        at_block_like(p) ||
+        p.at_any(&[T![break], T![continue], T![return], T![defer], T![fallthrough]])
+}
+
+/// Returns the **binding power** and **associativity** of the current token
+/// if it is a binary operator (see the [precedence table][self]).
+fn current_op(p: &Parser) -> Option<(u8, Associativity)> {
+    use Associativity::*;
+
+    // The sign of a superscript literal in a superscript group (e.g. the `⁺`
+    // of `ⁿ⁺¹`) is split off as a `+` or `-`.
+    if *p.in_superscript() && p.at_superscript_sign() {
+        return Some((10, Left));
+    }
+
+    let op = match p.current() {
+        T![**] => (13, Right),
+        T![*] | T![/] | T![%] => (11, Left),
+        T![+] | T![-] => (10, Left),
+        T![<<] | T![>>] => (9, Left),
+        T![&] | T!['∩'] => (8, Left),
+        T![^] => (7, Left),
+        T![|] | T!['∪'] => (6, Left),
+        T![==] | T![!=] | T![<] | T![<=] | T![>] | T![>=] | T![is] | T![isnt] => (5, Left),
+        T!['⊂'] | T!['⊃'] | T!['⊄'] | T!['⊅'] | T!['⊆'] | T!['⊇'] => (5, Left),
+        T!['≈'] | T!['≡'] | T!['∝'] => (5, Left),
+        T![and] | T![&&] => (4, Left),
+        T![or] | T![||] | T!['∴'] | T!['∵'] => (3, Left),
+        T![..] | T![..=] => (RANGE_BP, Left),
+        T![=] |
+        T![+=] |
+        T![-=] |
+        T![*=] |
+        T![/=] |
+        T![%=] |
+        T![<<=] |
+        T![>>=] |
+        T![&=] |
+        T![|=] |
+        T![^=] |
+        T![<-] => (1, Right),
+        _ => return None,
+    };
+
+    Some(op)
+}
+
+/// Parses an expression whose binary operators all bind **at least** as
+/// tightly as `min_bp`.
+fn expr_bp(p: &mut Parser, min_bp: u8) -> Option<CompletedMarker> {
+    let mut lhs = lhs(p)?;
+
+    while let Some((op_bp, assoc)) = current_op(p) {
+        if op_bp < min_bp {
+            break;
+        }
+
+        // Ranges are non-associative (e.g. `a..b..c` is an error).
+        if p.at_any(&[T![..], T![..=]]) {
+            if lhs.kind() == RANGE_EXPR {
+                break;
+            }
+            lhs = range_expr(p, lhs);
+            continue;
+        }
+
+        let m = lhs.precede(p);
+        if p.at_superscript_sign() {
+            p.bump_superscript_sign();
+        } else {
+            p.bump_any();
+        }
+
+        let rhs_bp = match assoc {
+            Associativity::Left => op_bp + 1,
+            Associativity::Right => op_bp,
+        };
+        expr_bp(p, rhs_bp);
+
+        lhs = m.complete(p, BIN_EXPR);
+    }
+
+    Some(lhs)
+}
+
+/// Parses the **left-hand side** of a binary expression (i.e. a prefix
+/// expression, or an atom followed by any number of postfix operators).
+fn lhs(p: &mut Parser) -> Option<CompletedMarker> {
+    let m = p.start();
+    let kind = match p.current() {
+        kind if PREFIX_OPS.contains(&kind) => {
+            p.bump_any();
+            PREFIX_EXPR
+        }
+        T![&] => {
+            p.bump(T![&]);
+            if !p.eat(T![mut]) {
+                p.eat(T![const]);
+            }
+            REF_EXPR
+        }
+        T![..] | T![..=] => {
+            p.bump_any();
+            if at_range_end(p) {
+                expr_bp(p, RANGE_BP + 1);
+            }
+            return Some(m.complete(p, RANGE_EXPR));
+        }
+        _ => {
+            m.abandon(p);
+            let atom = atom_expr(p)?;
+            return Some(postfix_expr(p, atom));
+        }
+    };
+
+    expr_bp(p, PREFIX_BP);
+    Some(m.complete(p, kind))
+}
+
+/// Parses an **atom** (i.e. an expression which isn't made up of operators).
+fn atom_expr(p: &mut Parser) -> Option<CompletedMarker> {
+    let cm = match p.current() {
+        kind if LITERAL_FIRST.contains(&kind) => literal(p),
+        FORMAT_STRING_START => format_string_expr(p),
+        T!['('] => paren_expr(p),
+        _ if paths::is_path_start(p) => path_expr(p),
+        _ if at_block_expr(p) => block_expr(p),
+        T![if] => if_expr(p),
+        T![match] => match_expr(p),
+        T![loop] | T![while] | T![for] => {
+            let m = p.start();
+            loop_expr(p, m)
+        }
+        LIFETIME if at_label(p) => labeled_expr(p),
+        T![break] => break_expr(p),
+        T![continue] => continue_expr(p),
+        T![return] => return_expr(p),
+        T![defer] => defer_expr(p),
+        T![fallthrough] => fallthrough_expr(p),
+        INTEGER_SUP | FLOAT_SUP if *p.in_superscript() => literal(p),
+        INTEGER_SUP | FLOAT_SUP | T!['⁽'] => {
+            p.err_and_bump(SyntaxError::MisplacedSuperscript(SuperscriptError::NoBase));
+            return None;
+        }
+        _ => {
+            p.error(SyntaxError::Expected(Expected::Expression));
+            return None;
+        }
+    };
+
+    Some(cm)
+}
+
+/// Parses any number of **postfix operators** (i.e. calls, indexing and
+/// field accesses) following the expression `lhs`, and then its superscript
+/// exponent (if any).
+fn postfix_expr(p: &mut Parser, mut lhs: CompletedMarker) -> CompletedMarker {
+    loop {
+        lhs = match p.current() {
+            T!['('] => call_expr(p, lhs),
+            T!['['] => index_expr(p, lhs),
+            T![.] => field_expr(p, lhs),
+            _ => break,
+        };
+    }
+
+    if at_superscript_exponent(p) {
+        lhs = superscript_exponent(p, lhs);
+    }
+    lhs
+}
+
+/// Returns `true` if the current token starts a **superscript exponent** (i.e.
+/// a superscript literal or group), unless it's the sign of a sum in a
+/// superscript group.
+fn at_superscript_exponent(p: &Parser) -> bool {
+    p.at_any(&[INTEGER_SUP, FLOAT_SUP, T!['⁽']]) &&
+        !(*p.in_superscript() && p.at_superscript_sign())
+}
+
+/// ```text
+/// BinExpr =
+///   Attr* lhs:Expr rhs:(Literal | SuperscriptExpr)
+/// ```
+///
+/// A **superscript exponent** (e.g. `x²`) has no operator token, but is the
+/// same power expression as `x ** 2`.
+fn superscript_exponent(p: &mut Parser, mut lhs: CompletedMarker) -> CompletedMarker {
+    if p.after_trivia() {
+        p.error(SyntaxError::MisplacedSuperscript(SuperscriptError::NotAdjacent));
+    }
+
+    loop {
+        let m = lhs.precede(p);
+        if p.at(T!['⁽']) {
+            superscript_expr(p);
+        } else {
+            literal(p);
+        }
+        lhs = m.complete(p, BIN_EXPR);
+
+        if !at_superscript_exponent(p) {
+            break lhs;
+        }
+        // Parse the next superscript as an exponent anyway (e.g. `x²⁽n⁾`), as
+        // it's most likely meant to be a single exponent.
+        p.error(SyntaxError::MisplacedSuperscript(SuperscriptError::Consecutive));
+    }
+}
+
+/// ```text
+/// SuperscriptExpr =
+///   Attr* '⁽' Expr '⁾'
+/// ```
+fn superscript_expr(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T!['⁽']);
+
+    let outer = *p.in_superscript();
+    p.set_in_superscript(true);
+    expr(p);
+    p.set_in_superscript(outer);
+
+    p.expect(T!['⁾']);
+    m.complete(p, SUPERSCRIPT_EXPR)
+}
+
+/// ```text
+/// Literal =
+///   Attr* value:(
+///     'integer' | 'float' | 'rune' | 'string' | 'raw_string'
+///   | 'integer_sup' | 'float_sup'
+///   | 'true' | 'false'
+///   | 'pi' | 'euler' | 'phi' | 'tau' | 'catalan' | 'eulergamma' | 'inf' | 'nan'
+///   )
+/// ```
+pub(super) fn literal(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    p.bump_any();
+    m.complete(p, LITERAL)
+}
+
+/// ```text
+/// FormatStringExpr =
+///   Attr* 'format_string_start' FormatArg* 'format_string_end'
+///
+/// FormatArg =
+///   '{' Expr '}'
+/// ```
+///
+/// The literal fragments between the holes are `STRING_FRAGMENT` tokens. The
+/// lexer only splits up a string with a hole in it, so there is always at
+/// least one `FormatArg`.
+pub(super) fn format_string_expr(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(FORMAT_STRING_START);
+    loop {
+        match p.current() {
+            STRING_FRAGMENT => p.bump(STRING_FRAGMENT),
+            T!['{'] => format_arg(p),
+            _ => break,
+        }
+    }
+    p.expect(FORMAT_STRING_END);
+    m.complete(p, FORMAT_STRING_EXPR)
+}
+
+fn format_arg(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T!['{']);
+    expr(p);
+
+    if !p.at(T!['}']) && !p.at(EOF) {
+        // Skip whatever follows the expression (e.g. the `b` of `{a b}`), up to
+        // the `}` which the lexer ended the hole with.
+        let err = p.start();
+        p.error(SyntaxError::Expected(Expected::Token(format!("{:?}", T!['}']).into())));
+        let mut depth = 0_usize;
+        while !p.at(EOF) && !(depth == 0 && p.at(T!['}'])) {
+            match p.current() {
+                T!['{'] => depth += 1,
+                T!['}'] => depth -= 1,
+                _ => (),
+            }
+            p.bump_any();
+        }
+        err.complete(p, ERROR);
+    }
+
+    p.expect(T!['}']);
+    m.complete(p, FORMAT_ARG);
+}
+
+/// ```text
+/// PathExpr =
+///   Attr* Path
+///
+/// MacroExpr =
+///   MacroCall
+/// ```
+///
+/// A path followed by a `!` is a **macro call** (e.g. `println!("{}", x)`)
+/// rather than a path expression. There is no binary `!` operator, and `!=`
+/// is a single token, so a `!` can't mean anything else here.
+fn path_expr(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    paths::path(p);
+
+    if p.eat(T![!]) {
+        token_tree(p);
+        let call = m.complete(p, MACRO_CALL);
+        let m = call.precede(p);
+        return m.complete(p, MACRO_EXPR);
+    }
+
+    m.complete(p, PATH_EXPR)
+}
+
+/// ```text
+/// ParenExpr =
+///   Attr* '(' Attr* Expr ')'
+/// ```
+fn paren_expr(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T!['(']);
+    attributes::outer_attrs(p);
+    expr(p);
+    p.expect(T![')']);
+    m.complete(p, PAREN_EXPR)
+}
+
+/// ```text
+/// CallExpr =
+///   Attr* Expr ArgList
+/// ```
+fn call_expr(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
+    let m = lhs.precede(p);
+    arg_list(p);
+    m.complete(p, CALL_EXPR)
+}
+
+/// ```text
+/// ArgList =
+///   '(' args:(Expr (',' Expr)* ','?)? ')'
+/// ```
+fn arg_list(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T!['(']);
+
+    while !p.at(EOF) && !p.at(T![')']) {
+        if expr(p).is_none() {
+            break;
+        }
+        if !p.at(T![')']) && !p.expect(T![,]) {
+            break;
+        }
+    }
+
+    p.expect(T![')']);
+    m.complete(p, ARG_LIST);
+}
+
+/// ```text
+/// IndexExpr =
+///   Attr* base:Expr '[' index:Expr ']'
+/// ```
+fn index_expr(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
+    let m = lhs.precede(p);
+    p.bump(T!['[']);
+    expr(p);
+    p.expect(T![']']);
+    m.complete(p, INDEX_EXPR)
+}
+
+/// ```text
+/// FieldExpr =
+///   Attr* Expr '.' NameRef
+/// ```
+fn field_expr(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
+    let m = lhs.precede(p);
+    p.bump(T![.]);
+    name_ref(p);
+    m.complete(p, FIELD_EXPR)
+}
+
+/// ```text
+/// RangeExpr =
+///   Attr* start:Expr? op:('..' | '..=') end:Expr?
+/// ```
+fn range_expr(p: &mut Parser, lhs: CompletedMarker) -> CompletedMarker {
+    let m = lhs.precede(p);
+    p.bump_any();
+    if at_range_end(p) {
+        expr_bp(p, RANGE_BP + 1);
+    }
+    m.complete(p, RANGE_EXPR)
+}
+
+/// Returns `true` if the current token can start the `end` of a range.
+///
+/// A `{` never does, so that the block of `for i in 0.. { .. }` isn't parsed
+/// as the end of the range.
+fn at_range_end(p: &Parser) -> bool {
+    at_expr_start(p) && !p.at(T!['{'])
+}
+
+/// ```text
+/// IfExpr =
+///   Attr* 'if' condition:Expr then_branch:BlockExpr
+///   ('else' else_branch:(IfExpr | BlockExpr))?
+/// ```
+fn if_expr(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T![if]);
+    expr(p);
+    expect_block_expr(p);
+
+    if p.eat(T![else]) {
+        if p.at(T![if]) {
+            if_expr(p);
+        } else {
+            expect_block_expr(p);
+        }
+    }
+
+    m.complete(p, IF_EXPR)
+}
+
+/// ```text
+/// Label =
+///   'lifetime' ':'
+/// ```
+fn label(p: &mut Parser) {
+    let m = p.start();
+    p.bump(LIFETIME);
+    p.bump(T![:]);
+    m.complete(p, LABEL);
+}
+
+/// Parses a **labeled** loop or block (e.g. `'outer: loop { .. }`).
+fn labeled_expr(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    label(p);
+
+    match p.current() {
+        T![loop] | T![while] | T![for] => loop_expr(p, m),
+        _ if at_block_expr(p) => finish_block_expr(p, m),
+        _ => {
+            p.error(SyntaxError::Expected(Expected::LoopOrBlock));
+            m.complete(p, ERROR)
+        }
+    }
+}
+
+/// Parses any of the **loops**, where the marker `m` has already consumed any
+/// [label].
+///
+/// ```text
+/// LoopExpr =
+///   Attr* Label? 'loop'
+///   loop_body:BlockExpr
+///
+/// ForExpr =
+///   Attr* Label? 'for' Pat 'in' iterable:Expr
+///   loop_body:BlockExpr
+///
+/// WhileExpr =
+///   Attr* Label? 'while' condition:Expr
+///   loop_body:BlockExpr
+/// ```
+fn loop_expr(p: &mut Parser, m: Marker) -> CompletedMarker {
+    let kind = match p.current() {
+        T![loop] => {
+            p.bump(T![loop]);
+            LOOP_EXPR
+        }
+        T![for] => {
+            p.bump(T![for]);
+            patterns::pattern(p);
+            p.expect(T![in]);
+            expr(p);
+            FOR_EXPR
+        }
+        _ => {
+            p.bump(T![while]);
+            expr(p);
+            WHILE_EXPR
+        }
+    };
+
+    expect_block_expr(p);
+    m.complete(p, kind)
+}
+
+/// ```text
+/// BreakExpr =
+///   Attr* 'break' 'lifetime'? Expr?
+/// ```
+fn break_expr(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T![break]);
+    p.eat(LIFETIME);
+    if at_expr_start(p) {
+        expr(p);
+    }
+    m.complete(p, BREAK_EXPR)
+}
+
+/// ```text
+/// ContinueExpr =
+///   Attr* 'continue' 'lifetime'?
+/// ```
+fn continue_expr(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T![continue]);
+    p.eat(LIFETIME);
+    m.complete(p, CONTINUE_EXPR)
+}
+
+/// ```text
+/// ReturnExpr =
+///   Attr* 'return' Expr?
+/// ```
+fn return_expr(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T![return]);
+    if at_expr_start(p) {
+        expr(p);
+    }
+    m.complete(p, RETURN_EXPR)
+}
+
+/// ```text
+/// DeferExpr =
+///   Attr* 'defer' Expr
+/// ```
+fn defer_expr(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T![defer]);
+    expr(p);
+    m.complete(p, DEFER_EXPR)
+}
+
+/// ```text
+/// FallthroughExpr =
+///   Attr* 'fallthrough'
+/// ```
+fn fallthrough_expr(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T![fallthrough]);
+    m.complete(p, FALLTHROUGH_EXPR)
+}
+
+/// ```text
+/// MatchExpr =
+///   Attr* 'match' Expr MatchArmList
+/// ```
+fn match_expr(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T![match]);
+    expr(p);
+
+    if p.at(T!['{']) {
+        match_arm_list(p);
+    } else {
+        p.error(SyntaxError::Expected(Expected::MatchArmList));
+    }
+
+    m.complete(p, MATCH_EXPR)
+}
+
+/// ```text
+/// MatchArmList =
+///   '{'
+///     Attr*
+///     arms:MatchArm*
+///   '}'
+/// ```
+pub(super) fn match_arm_list(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T!['{']);
+    attributes::inner_attrs(p);
+
+    while !p.at(EOF) && !p.at(T!['}']) {
+        if !p.at(T![#]) && !patterns::at_pat_start(p) {
+            if p.at_ts(items::ITEM_RECOVERY_SET) {
+                break;
+            }
+            p.err_and_bump(SyntaxError::Expected(Expected::MatchArm));
+            continue;
+        }
+
+        match_arm(p);
+    }
+
+    p.expect(T!['}']);
+    m.complete(p, MATCH_ARM_LIST);
+}
+
+/// ```text
+/// MatchArm =
+///   Attr* Pat guard:MatchGuard? '=>' Expr ','?
+/// ```
+///
+/// The `,` is only optional after a [block-like][BLOCK_LIKE] expression or
+/// for the last arm.
+fn match_arm(p: &mut Parser) {
+    let m = p.start();
+    attributes::outer_attrs(p);
+    patterns::pattern(p);
+
+    if p.at(T![if]) {
+        match_guard(p);
+    }
+    p.expect(T![=>]);
+
+    let is_block_like = expr(p).map_or(false, |cm| BLOCK_LIKE.contains(&cm.kind()));
+    if is_block_like || p.at(T!['}']) {
+        p.eat(T![,]);
+    } else {
+        p.expect(T![,]);
+    }
+
+    m.complete(p, MATCH_ARM);
+}
+
+/// ```text
+/// MatchGuard =
+///   'if' condition:Expr
+/// ```
+fn match_guard(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T![if]);
+    expr(p);
+    m.complete(p, MATCH_GUARD);
+}
+
+#[cfg(test)]
+mod expression_test_suite {
+    use leafc_cfg::lang::LanguageKind;
+    use leafc_syntax::{
+        SyntaxElement,
+        SyntaxNode,
+    };
+    use pretty_assertions_sorted::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    /// The spoken **languages** of the cases (which aren't all written in
+    /// English).
+    const LANGUAGES: &[LanguageKind] = &[LanguageKind::Any];
+
+    /// Parses `text` as a single expression and renders the resulting tree as
+    /// an s-expression (e.g. `(BIN_EXPR (LITERAL 1) + (LITERAL 2))`), ignoring
+    /// any trivia. Also returns the number of errors (counting any tokens left
+    /// over after the expression as an error).
+    fn parse_expr(text: &str) -> (String, usize) {
+        parse_with(text, |p| {
+            expr(p);
+        })
+    }
+
+    /// Parses `text` as a single statement (see [`parse_expr`]).
+    fn parse_stmt(text: &str) -> (String, usize) {
+        parse_with(text, stmt)
+    }
+
+    fn parse_with(text: &str, f: impl FnOnce(&mut Parser)) -> (String, usize) {
+        let mut p = Parser::new(text, true, LANGUAGES);
+        let m = p.start();
+        f(&mut p);
+        let trailing_tokens = !p.at(EOF);
+        m.complete(&mut p, SOURCE_FILE);
+
+        let (green, errors) = p.finish();
+        let root = SyntaxNode::new_root(green);
+        assert_eq!(root.to_string(), text);
+
+        let rendered =
+            root.children_with_tokens().filter_map(render).collect::<Vec<_>>().join(" ");
+        (rendered, errors.len() + usize::from(trailing_tokens))
+    }
+
+    fn render(element: SyntaxElement) -> Option<String> {
+        match element {
+            SyntaxElement::Node(node) => {
+                let children = node.children_with_tokens().filter_map(render).collect::<Vec<_>>();
+                Some(format!("({:?} {})", node.kind(), children.join(" ")))
+            }
+            SyntaxElement::Token(token) if token.kind().is_trivia() => None,
+            SyntaxElement::Token(token) => Some(token.text().to_string()),
+        }
+    }
+
+    #[rstest]
+    #[case("1 + 2 * 3", "(BIN_EXPR (LITERAL 1) + (BIN_EXPR (LITERAL 2) * (LITERAL 3)))")]
+    #[case("1 * 2 + 3", "(BIN_EXPR (BIN_EXPR (LITERAL 1) * (LITERAL 2)) + (LITERAL 3))")]
+    #[case("1 - 2 - 3", "(BIN_EXPR (BIN_EXPR (LITERAL 1) - (LITERAL 2)) - (LITERAL 3))")]
+    #[case("2 ** 3 ** 2", "(BIN_EXPR (LITERAL 2) ** (BIN_EXPR (LITERAL 3) ** (LITERAL 2)))")]
+    #[case("-2 ** 2", "(PREFIX_EXPR - (BIN_EXPR (LITERAL 2) ** (LITERAL 2)))")]
+    #[case("-a * b", "(BIN_EXPR (PREFIX_EXPR - (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF a))))) * (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF b)))))")]
+    #[case("(1 + 2) * 3", "(BIN_EXPR (PAREN_EXPR ( (BIN_EXPR (LITERAL 1) + (LITERAL 2)) )) * (LITERAL 3))")]
+    #[case("1 << 2 & 3", "(BIN_EXPR (BIN_EXPR (LITERAL 1) << (LITERAL 2)) & (LITERAL 3))")]
+    #[case("1 < 2 and not true", "(BIN_EXPR (BIN_EXPR (LITERAL 1) < (LITERAL 2)) and (PREFIX_EXPR not (LITERAL true)))")]
+    #[case("1 || 2 && 3", "(BIN_EXPR (LITERAL 1) || (BIN_EXPR (LITERAL 2) && (LITERAL 3)))")]
+    #[case("x = z += 1", "(BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF x)))) = (BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF z)))) += (LITERAL 1)))")]
+    #[case("ch <- 1 + 2", "(BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF ch)))) <- (BIN_EXPR (LITERAL 1) + (LITERAL 2)))")]
+    #[case("0..10", "(RANGE_EXPR (LITERAL 0) .. (LITERAL 10))")]
+    #[case("1 + 1..=2 * 4", "(RANGE_EXPR (BIN_EXPR (LITERAL 1) + (LITERAL 1)) ..= (BIN_EXPR (LITERAL 2) * (LITERAL 4)))")]
+    #[case("..", "(RANGE_EXPR ..)")]
+    #[case("..5", "(RANGE_EXPR .. (LITERAL 5))")]
+    #[case("5..", "(RANGE_EXPR (LITERAL 5) ..)")]
+    #[case("f(1, \"a\")", "(CALL_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF f)))) (ARG_LIST ( (LITERAL 1) , (LITERAL \"a\") )))")]
+    #[case("a[0].b", "(FIELD_EXPR (INDEX_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF a)))) [ (LITERAL 0) ]) . (NAME_REF b))")]
+    #[case("-f(x)[1]", "(PREFIX_EXPR - (INDEX_EXPR (CALL_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF f)))) (ARG_LIST ( (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF x)))) ))) [ (LITERAL 1) ]))")]
+    #[case("&mut x", "(REF_EXPR & mut (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF x)))))")]
+    #[case("a::b", "(PATH_EXPR (PATH (PATH (PATH_SEGMENT (NAME_REF a))) :: (PATH_SEGMENT (NAME_REF b))))")]
+    #[case("{ x := 1; x }", "(BLOCK_EXPR (STMT_LIST { (LET_STMT (IDENT_PAT (NAME x)) := (LITERAL 1) ;) (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF x)))) }))")]
+    #[case("unsafe { f() }", "(BLOCK_EXPR unsafe (STMT_LIST { (CALL_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF f)))) (ARG_LIST ( ))) }))")]
+    #[case("{}", "(BLOCK_EXPR (STMT_LIST { }))")]
+    #[case("if a { 1 } else if b { 2 } else { 3 }", "(IF_EXPR if (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF a)))) (BLOCK_EXPR (STMT_LIST { (LITERAL 1) })) else (IF_EXPR if (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF b)))) (BLOCK_EXPR (STMT_LIST { (LITERAL 2) })) else (BLOCK_EXPR (STMT_LIST { (LITERAL 3) }))))")]
+    #[case("loop { break }", "(LOOP_EXPR loop (BLOCK_EXPR (STMT_LIST { (BREAK_EXPR break) })))")]
+    #[case("'outer: for i in 0.. { continue 'outer }", "(FOR_EXPR (LABEL 'outer :) for (IDENT_PAT (NAME i)) in (RANGE_EXPR (LITERAL 0) ..) (BLOCK_EXPR (STMT_LIST { (CONTINUE_EXPR continue 'outer) })))")]
+    #[case("while x < 10 { x += 1 }", "(WHILE_EXPR while (BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF x)))) < (LITERAL 10)) (BLOCK_EXPR (STMT_LIST { (BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF x)))) += (LITERAL 1)) })))")]
+    #[case("'a: { break 'a 1 }", "(BLOCK_EXPR (LABEL 'a :) (STMT_LIST { (BREAK_EXPR break 'a (LITERAL 1)) }))")]
+    #[case("match x { _ => 1, z if z > 0 => { z } n => n }", "(MATCH_EXPR match (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF x)))) (MATCH_ARM_LIST { (MATCH_ARM (WILDCARD_PAT _) => (LITERAL 1) ,) (MATCH_ARM (IDENT_PAT (NAME z)) (MATCH_GUARD if (BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF z)))) > (LITERAL 0))) => (BLOCK_EXPR (STMT_LIST { (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF z)))) }))) (MATCH_ARM (IDENT_PAT (NAME n)) => (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF n))))) }))")]
+    #[case("return x + 1", "(RETURN_EXPR return (BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF x)))) + (LITERAL 1)))")]
+    #[case("defer close(f)", "(DEFER_EXPR defer (CALL_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF close)))) (ARG_LIST ( (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF f)))) ))))")]
+    #[case("fallthrough", "(FALLTHROUGH_EXPR fallthrough)")]
+    #[case("match c { 'a'..='z' | 'A'..='Z' => 1, _ => 0 }", "(MATCH_EXPR match (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF c)))) (MATCH_ARM_LIST { (MATCH_ARM (OR_PAT (RANGE_PAT (LITERAL_PAT (LITERAL 'a')) ..= (LITERAL_PAT (LITERAL 'z'))) | (RANGE_PAT (LITERAL_PAT (LITERAL 'A')) ..= (LITERAL_PAT (LITERAL 'Z')))) => (LITERAL 1) ,) (MATCH_ARM (WILDCARD_PAT _) => (LITERAL 0)) }))")]
+    #[case("match n { | -1 | 0 => 0, 1.. => 1, ..=-2 => 2 }", "(MATCH_EXPR match (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF n)))) (MATCH_ARM_LIST { (MATCH_ARM (OR_PAT | (LITERAL_PAT - (LITERAL 1)) | (LITERAL_PAT (LITERAL 0))) => (LITERAL 0) ,) (MATCH_ARM (RANGE_PAT (LITERAL_PAT (LITERAL 1)) ..) => (LITERAL 1) ,) (MATCH_ARM (RANGE_PAT ..= (LITERAL_PAT - (LITERAL 2))) => (LITERAL 2)) }))")]
+    #[case("match opt { Some(n) => n, None => 0 }", "(MATCH_EXPR match (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF opt)))) (MATCH_ARM_LIST { (MATCH_ARM (TUPLE_STRUCT_PAT (PATH (PATH_SEGMENT (NAME_REF Some))) ( (IDENT_PAT (NAME n)) )) => (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF n)))) ,) (MATCH_ARM (IDENT_PAT (NAME None)) => (LITERAL 0)) }))")]
+    #[case("match p { Point { x: 0, z, .. } => z, geo::Origin => 0 }", "(MATCH_EXPR match (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF p)))) (MATCH_ARM_LIST { (MATCH_ARM (RECORD_PAT (PATH (PATH_SEGMENT (NAME_REF Point))) (RECORD_PAT_FIELD_LIST { (RECORD_PAT_FIELD (NAME_REF x) : (LITERAL_PAT (LITERAL 0))) , (RECORD_PAT_FIELD (IDENT_PAT (NAME z))) , (REST_PAT ..) })) => (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF z)))) ,) (MATCH_ARM (PATH_PAT (PATH (PATH (PATH_SEGMENT (NAME_REF geo))) :: (PATH_SEGMENT (NAME_REF Origin)))) => (LITERAL 0)) }))")]
+    #[case("match t { (1, ..) | (.., 1) => 1, (x) => 0 }", "(MATCH_EXPR match (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF t)))) (MATCH_ARM_LIST { (MATCH_ARM (OR_PAT (TUPLE_PAT ( (LITERAL_PAT (LITERAL 1)) , (REST_PAT ..) )) | (TUPLE_PAT ( (REST_PAT ..) , (LITERAL_PAT (LITERAL 1)) ))) => (LITERAL 1) ,) (MATCH_ARM (PAREN_PAT ( (IDENT_PAT (NAME x)) )) => (LITERAL 0)) }))")]
+    #[case("match n { small @ 0..LIMIT => small, _ => 0 }", "(MATCH_EXPR match (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF n)))) (MATCH_ARM_LIST { (MATCH_ARM (IDENT_PAT (NAME small) @ (RANGE_PAT (LITERAL_PAT (LITERAL 0)) .. (PATH_PAT (PATH (PATH_SEGMENT (NAME_REF LIMIT)))))) => (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF small)))) ,) (MATCH_ARM (WILDCARD_PAT _) => (LITERAL 0)) }))")]
+    #[case("for (i, _) in pairs {}", "(FOR_EXPR for (TUPLE_PAT ( (IDENT_PAT (NAME i)) , (WILDCARD_PAT _) )) in (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF pairs)))) (BLOCK_EXPR (STMT_LIST { })))")]
+    #[case("Vec::<i32>::new()", "(CALL_EXPR (PATH_EXPR (PATH (PATH (PATH_SEGMENT (NAME_REF Vec) (GENERIC_ARG_LIST :: < (TYPE_ARG (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF i32))))) >))) :: (PATH_SEGMENT (NAME_REF new)))) (ARG_LIST ( )))")]
+    #[case("println!(\"Hello, {}!\", x)", "(MACRO_EXPR (MACRO_CALL (PATH (PATH_SEGMENT (NAME_REF println))) ! (TOKEN_TREE ( \"Hello, {}!\" , x ))))")]
+    #[case("vec![(1, 2), [3]] != v", "(BIN_EXPR (MACRO_EXPR (MACRO_CALL (PATH (PATH_SEGMENT (NAME_REF vec))) ! (TOKEN_TREE [ (TOKEN_TREE ( 1 , 2 )) , (TOKEN_TREE [ 3 ]) ]))) != (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF v)))))")]
+    #[case("ligne_d'impression!(\"Bonjour\")", "(MACRO_EXPR (MACRO_CALL (PATH (PATH_SEGMENT (NAME_REF ligne_d'impression))) ! (TOKEN_TREE ( \"Bonjour\" ))))")]
+    #[case("\"a{b}c\"", "(FORMAT_STRING_EXPR \" a (FORMAT_ARG { (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF b)))) }) c \")")]
+    #[case("\"{x + 1} = {f(\"s\", \"{z}\")}\"", "(FORMAT_STRING_EXPR \" (FORMAT_ARG { (BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF x)))) + (LITERAL 1)) })  =  (FORMAT_ARG { (CALL_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF f)))) (ARG_LIST ( (LITERAL \"s\") , (FORMAT_STRING_EXPR \" (FORMAT_ARG { (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF z)))) }) \") ))) }) \")")]
+    #[case("\"{{a}} {} {b}\"", "(FORMAT_STRING_EXPR \" {{a}} {}  (FORMAT_ARG { (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF b)))) }) \")")]
+    #[case("ligne_d'impression!(\"Bonjour, {🦀ferris_le_crabe}!\")", "(MACRO_EXPR (MACRO_CALL (PATH (PATH_SEGMENT (NAME_REF ligne_d'impression))) ! (TOKEN_TREE ( (FORMAT_STRING_EXPR \" Bonjour,  (FORMAT_ARG { (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF 🦀ferris_le_crabe)))) }) ! \") ))))")]
+    #[case("2 * π * z", "(BIN_EXPR (BIN_EXPR (LITERAL 2) * (LITERAL π)) * (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF z)))))")]
+    #[case("√z + ∛8", "(BIN_EXPR (PREFIX_EXPR √ (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF z))))) + (PREFIX_EXPR ∛ (LITERAL 8)))")]
+    #[case("a ∪ b ∩ c", "(BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF a)))) ∪ (BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF b)))) ∩ (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF c))))))")]
+    #[case("a ⊆ b ∧ z ≠ ∞", "(BIN_EXPR (BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF a)))) ⊆ (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF b))))) ∧ (BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF z)))) ≠ (LITERAL ∞)))")]
+    #[case("∫f + ∠z * 2", "(BIN_EXPR (PREFIX_EXPR ∫ (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF f))))) + (BIN_EXPR (PREFIX_EXPR ∠ (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF z))))) * (LITERAL 2)))")]
+    #[case("∮f ∝ ∯g", "(BIN_EXPR (PREFIX_EXPR ∮ (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF f))))) ∝ (PREFIX_EXPR ∯ (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF g))))))")]
+    #[case("a ∧ b ∴ c ∵ ∰d ≡ k", "(BIN_EXPR (BIN_EXPR (BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF a)))) ∧ (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF b))))) ∴ (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF c))))) ∵ (BIN_EXPR (PREFIX_EXPR ∰ (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF d))))) ≡ (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF k))))))")]
+    #[case("x²", "(BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF x)))) (LITERAL ²))")]
+    #[case("-x⁻¹", "(PREFIX_EXPR - (BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF x)))) (LITERAL ⁻¹)))")]
+    #[case("2 ** f(x)²·⁵", "(BIN_EXPR (LITERAL 2) ** (BIN_EXPR (CALL_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF f)))) (ARG_LIST ( (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF x)))) ))) (LITERAL ²·⁵)))")]
+    #[case("2⁽n + 1⁾", "(BIN_EXPR (LITERAL 2) (SUPERSCRIPT_EXPR ⁽ (BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF n)))) + (LITERAL 1)) ⁾))")]
+    #[case("2⁽ⁿ⁺¹⁾", "(BIN_EXPR (LITERAL 2) (SUPERSCRIPT_EXPR ⁽ (BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF ⁿ)))) ⁺ (LITERAL ¹)) ⁾))")]
+    #[case("(a + b)⁽⁻¹⁾", "(BIN_EXPR (PAREN_EXPR ( (BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF a)))) + (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF b))))) )) (SUPERSCRIPT_EXPR ⁽ (LITERAL ⁻¹) ⁾))")]
+    fn test_expr(#[case] text: &str, #[case] expected: &str) {
+        let (tree, errors) = parse_expr(text);
+        assert_eq!(tree, expected);
+        assert_eq!(errors, 0);
+    }
+
+    #[rstest]
+    #[case("1 +")]
+    #[case("1..2..3")]
+    #[case("f(1 2)")]
+    #[case("(1")]
+    #[case("a.")]
+    #[case("m!")]
+    #[case("m!(a, [b)")]
+    #[case("m!{ (a }")]
+    #[case("\"{ }\"")]
+    #[case("\"{a b}\"")]
+    #[case("\"{f(\"}")]
+    #[case("x ²")]
+    #[case("x²⁽n⁾")]
+    #[case("²")]
+    #[case("2⁽⁾")]
+    #[case("2⁽n")]
+    fn test_expr_errors(#[case] text: &str) {
+        let (_, errors) = parse_expr(text);
+        assert!(errors > 0);
+    }
+
+    #[rstest]
+    #[case("x := 1", "(LET_STMT (IDENT_PAT (NAME x)) := (LITERAL 1))")]
+    #[case("let x = 1", "(LET_STMT let (IDENT_PAT (NAME x)) = (LITERAL 1))")]
+    #[case("let mut x: i32 = 1 + 2;", "(LET_STMT let (IDENT_PAT mut (NAME x)) : (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF i32)))) = (BIN_EXPR (LITERAL 1) + (LITERAL 2)) ;)")]
+    #[case("let _ = f()", "(LET_STMT let (WILDCARD_PAT _) = (CALL_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF f)))) (ARG_LIST ( ))))")]
+    #[case("🦀ferris := \"🦀\"", "(LET_STMT (IDENT_PAT (NAME 🦀ferris)) := (LITERAL \"🦀\"))")]
+    #[case("x = 1;", "(EXPR_STMT (BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF x)))) = (LITERAL 1)) ;)")]
+    #[case("use foo;", "(USE use (USE_TREE (PATH (PATH_SEGMENT (NAME_REF foo)))) ;)")]
+    #[case("{ if c { a } -1 }", "(EXPR_STMT (BLOCK_EXPR (STMT_LIST { (EXPR_STMT (IF_EXPR if (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF c)))) (BLOCK_EXPR (STMT_LIST { (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF a)))) })))) (PREFIX_EXPR - (LITERAL 1)) })))")]
+    #[case("fn main() {}", "(FN fn (NAME main) (PARAM_LIST ( )) (BLOCK_EXPR (STMT_LIST { })))")]
+    #[case("fonction main() {}", "(FN fonction (NAME main) (PARAM_LIST ( )) (BLOCK_EXPR (STMT_LIST { })))")]
+    #[case("fn add(a: i32, b: i32) -> i32 { a + b }", "(FN fn (NAME add) (PARAM_LIST ( (PARAM (IDENT_PAT (NAME a)) : (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF i32))))) , (PARAM (IDENT_PAT (NAME b)) : (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF i32))))) )) (RET_TYPE -> (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF i32))))) (BLOCK_EXPR (STMT_LIST { (BIN_EXPR (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF a)))) + (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF b))))) })))")]
+    #[case("pub const unsafe fn f(&mut self);", "(FN (VISIBILITY pub) const unsafe fn (NAME f) (PARAM_LIST ( (SELF_PARAM & mut (NAME self)) )) ;)")]
+    #[case("default const async unsafe fn f();", "(FN default const async unsafe fn (NAME f) (PARAM_LIST ( )) ;)")]
+    #[case("struct Point { x: f64, z: f64 }", "(STRUCT struct (NAME Point) (RECORD_FIELD_LIST { (RECORD_FIELD (NAME x) : (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF f64))))) , (RECORD_FIELD (NAME z) : (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF f64))))) }))")]
+    #[case("struct Meters(f64);", "(STRUCT struct (NAME Meters) (TUPLE_FIELD_LIST ( (TUPLE_FIELD (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF f64))))) )) ;)")]
+    #[case("struct Unit;", "(STRUCT struct (NAME Unit) ;)")]
+    #[case("enum Option<T> { Some(T), None = 1 }", "(ENUM enum (NAME Option) (GENERIC_PARAM_LIST < (TYPE_PARAM (NAME T)) >) (VARIANT_LIST { (VARIANT (NAME Some) (TUPLE_FIELD_LIST ( (TUPLE_FIELD (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF T))))) ))) , (VARIANT (NAME None) = (LITERAL 1)) }))")]
+    #[case("trait Shape: Clone { fn area(&self) -> f64; }", "(TRAIT trait (NAME Shape) : (TYPE_BOUND_LIST (TYPE_BOUND (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF Clone)))))) (ASSOC_ITEM_LIST { (FN fn (NAME area) (PARAM_LIST ( (SELF_PARAM & (NAME self)) )) (RET_TYPE -> (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF f64))))) ;) }))")]
+    #[case("unsafe impl<T> Shape for Square where T: Copy + 'static {}", "(IMPL unsafe impl (GENERIC_PARAM_LIST < (TYPE_PARAM (NAME T)) >) (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF Shape)))) for (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF Square)))) (WHERE_CLAUSE where (WHERE_PRED (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF T)))) : (TYPE_BOUND_LIST (TYPE_BOUND (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF Copy))))) + (TYPE_BOUND 'static)))) (ASSOC_ITEM_LIST { }))")]
+    #[case("impl Square { type Side = f64; }", "(IMPL impl (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF Square)))) (ASSOC_ITEM_LIST { (TYPE_ALIAS type (NAME Side) = (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF f64)))) ;) }))")]
+    #[case("fn id<'a, T: ?Sized, const N: usize = 3>(x: T) -> T { x }", "(FN fn (NAME id) (GENERIC_PARAM_LIST < (LIFETIME_PARAM 'a) , (TYPE_PARAM (NAME T) : (TYPE_BOUND_LIST (TYPE_BOUND ? (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF Sized))))))) , (CONST_PARAM const (NAME N) : (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF usize)))) = (LITERAL 3)) >) (PARAM_LIST ( (PARAM (IDENT_PAT (NAME x)) : (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF T))))) )) (RET_TYPE -> (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF T))))) (BLOCK_EXPR (STMT_LIST { (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF x)))) })))")]
+    #[case("type Meters = f64;", "(TYPE_ALIAS type (NAME Meters) = (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF f64)))) ;)")]
+    #[case("let (a, .., b) = t", "(LET_STMT let (TUPLE_PAT ( (IDENT_PAT (NAME a)) , (REST_PAT ..) , (IDENT_PAT (NAME b)) )) = (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF t)))))")]
+    #[case("let Point { x, w: (z, _) } = p", "(LET_STMT let (RECORD_PAT (PATH (PATH_SEGMENT (NAME_REF Point))) (RECORD_PAT_FIELD_LIST { (RECORD_PAT_FIELD (IDENT_PAT (NAME x))) , (RECORD_PAT_FIELD (NAME_REF w) : (TUPLE_PAT ( (IDENT_PAT (NAME z)) , (WILDCARD_PAT _) ))) })) = (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF p)))))")]
+    #[case("let Pair(first, ..) = pair", "(LET_STMT let (TUPLE_STRUCT_PAT (PATH (PATH_SEGMENT (NAME_REF Pair))) ( (IDENT_PAT (NAME first)) , (REST_PAT ..) )) = (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF pair)))))")]
+    #[case("let () = unit", "(LET_STMT let (TUPLE_PAT ( )) = (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF unit)))))")]
+    #[case("let v: Vec<Vec<i32>> = xs", "(LET_STMT let (IDENT_PAT (NAME v)) : (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF Vec) (GENERIC_ARG_LIST < (TYPE_ARG (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF Vec) (GENERIC_ARG_LIST < (TYPE_ARG (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF i32))))) >))))) >)))) = (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF xs)))))")]
+    #[case("let r: &'a mut [u8; 4] = buf", "(LET_STMT let (IDENT_PAT (NAME r)) : (REF_TYPE & 'a mut (ARRAY_TYPE [ (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF u8)))) ; (LITERAL 4) ])) = (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF buf)))))")]
+    #[case("let t: (i32, (), (&[u8],)) = z", "(LET_STMT let (IDENT_PAT (NAME t)) : (TUPLE_TYPE ( (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF i32)))) , (TUPLE_TYPE ( )) , (TUPLE_TYPE ( (REF_TYPE & (SLICE_TYPE [ (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF u8)))) ])) , )) )) = (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF z)))))")]
+    #[case("let f: unsafe fn(i32, x: bool) -> i32 = g", "(LET_STMT let (IDENT_PAT (NAME f)) : (FN_PTR_TYPE unsafe fn (PARAM_LIST ( (PARAM (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF i32))))) , (PARAM (IDENT_PAT (NAME x)) : (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF bool))))) )) (RET_TYPE -> (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF i32)))))) = (PATH_EXPR (PATH (PATH_SEGMENT (NAME_REF g)))))")]
+    #[case("fn shapes() -> Vec<Box<dyn Shape + 'static>> {}", "(FN fn (NAME shapes) (PARAM_LIST ( )) (RET_TYPE -> (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF Vec) (GENERIC_ARG_LIST < (TYPE_ARG (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF Box) (GENERIC_ARG_LIST < (TYPE_ARG (DYN_TRAIT_TYPE dyn (TYPE_BOUND_LIST (TYPE_BOUND (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF Shape))))) + (TYPE_BOUND 'static)))) >))))) >))))) (BLOCK_EXPR (STMT_LIST { })))")]
+    #[case("fn bytes(it: impl Iterator<Item = u8>) {}", "(FN fn (NAME bytes) (PARAM_LIST ( (PARAM (IDENT_PAT (NAME it)) : (IMPL_TRAIT_TYPE impl (TYPE_BOUND_LIST (TYPE_BOUND (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF Iterator) (GENERIC_ARG_LIST < (ASSOC_TYPE_ARG (NAME_REF Item) = (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF u8))))) >)))))))) )) (BLOCK_EXPR (STMT_LIST { })))")]
+    #[case("fn into<T: Into<Vec<T>>>() {}", "(FN fn (NAME into) (GENERIC_PARAM_LIST < (TYPE_PARAM (NAME T) : (TYPE_BOUND_LIST (TYPE_BOUND (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF Into) (GENERIC_ARG_LIST < (TYPE_ARG (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF Vec) (GENERIC_ARG_LIST < (TYPE_ARG (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF T))))) >))))) >))))))) >) (PARAM_LIST ( )) (BLOCK_EXPR (STMT_LIST { })))")]
+    #[case("type Grid<T> = Array<T, 16>;", "(TYPE_ALIAS type (NAME Grid) (GENERIC_PARAM_LIST < (TYPE_PARAM (NAME T)) >) = (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF Array) (GENERIC_ARG_LIST < (TYPE_ARG (PATH_TYPE (PATH (PATH_SEGMENT (NAME_REF T))))) , (CONST_ARG (LITERAL 16)) >)))) ;)")]
+    #[case("fmt::format!{ x => { y } };", "(EXPR_STMT (MACRO_EXPR (MACRO_CALL (PATH (PATH (PATH_SEGMENT (NAME_REF fmt))) :: (PATH_SEGMENT (NAME_REF format))) ! (TOKEN_TREE { x => (TOKEN_TREE { y }) }))) ;)")]
+    fn test_stmt(#[case] text: &str, #[case] expected: &str) {
+        let (tree, errors) = parse_stmt(text);
+        assert_eq!(tree, expected);
+        assert_eq!(errors, 0);
+    }
+
+    #[test]
+    fn test_let_stmt_forms_have_the_same_shape() {
+        use leafc_syntax::ast::{
+            AstNode,
+            LetStmt,
+        };
+
+        let let_stmt = |text: &str| {
+            let mut p = Parser::new(text, true, LANGUAGES);
+            stmt(&mut p);
+            let (green, errors) = p.finish();
+            assert!(errors.is_empty());
+            LetStmt::cast(SyntaxNode::new_root(green)).unwrap()
+        };
+
+        for stmt in [let_stmt("x := 42"), let_stmt("let x = 42")] {
+            assert_eq!(stmt.pat().unwrap().syntax().to_string(), "x");
+            assert_eq!(stmt.initializer().unwrap().syntax().to_string(), "42");
+            assert!(stmt.ty().is_none());
+        }
+    }
+
+    #[rstest]
+    #[case("let = 1")]
+    #[case("let x 1")]
+    #[case("let x: = 1")]
+    #[case("x :=")]
+    #[case("pub x")]
+    #[case("fn f(1) {}")]
+    #[case("fn {}")]
+    #[case("fn f() { ) }")]
+    #[case("fn f() { x := 1")]
+    #[case("struct S")]
+    #[case("enum E { 1 }")]
+    #[case("trait T { struct S; }")]
+    #[case("fn f<T:>() where {}")]
+    #[case("impl<'a> {}")]
+    #[case("if x")]
+    #[case("match x { _ => 1 z => 2 }")]
+    #[case("'a: x")]
+    #[case("for in y {}")]
+    #[case("let (a b) = t")]
+    #[case("let (a, = t")]
+    #[case("match p { Point { 1 } => 0 }")]
+    #[case("match n { 1 | => 0 }")]
+    #[case("let v: Vec<i32 = w")]
+    #[case("let a: [i32; ] = b")]
+    #[case("let f: fn i32 = g")]
+    #[case("let t: (a:: ) = u")]
+    #[case("use {and ::")]
+    fn test_stmt_errors(#[case] text: &str) {
+        let (_, errors) = parse_stmt(text);
+        assert!(errors > 0);
+    }
+}
@@ -0,0 +1,195 @@
+use super::*;
+
+/// ```text
+/// GenericParamList =
+///   '<' (GenericParam (',' GenericParam)* ','?)? '>'
+/// ```
+pub(super) fn opt_generic_param_list(p: &mut Parser) {
+    if !p.at(T![<]) {
+        return;
+    }
+
+    let m = p.start();
+    p.bump(T![<]);
+
+    while !p.at(EOF) && !p.at(T![>]) {
+        if !p.at_any(GENERIC_PARAM_FIRST) {
+            if p.at_ts(items::ITEM_RECOVERY_SET) {
+                break;
+            }
+            p.err_and_bump(SyntaxError::Expected(Expected::GenericParam));
+            continue;
+        }
+
+        generic_param(p);
+        if !p.at(T![>]) && !p.expect(T![,]) {
+            break;
+        }
+    }
+
+    p.expect_r_angle();
+    m.complete(p, GENERIC_PARAM_LIST);
+}
+
+/// The tokens which can start a [`GenericParam`][generic_param].
+const GENERIC_PARAM_FIRST: &[SyntaxKind] = &[LIFETIME, T![const], IDENTIFIER, T![#]];
+
+/// ```text
+/// GenericParam =
+///   ConstParam
+/// | LifetimeParam
+/// | TypeParam
+/// ```
+fn generic_param(p: &mut Parser) {
+    let m = p.start();
+    attributes::outer_attrs(p);
+
+    match p.current() {
+        LIFETIME => lifetime_param(p, m),
+        T![const] => const_param(p, m),
+        IDENTIFIER => type_param(p, m),
+        _ => {
+            p.error(SyntaxError::Expected(Expected::GenericParam));
+            m.complete(p, ERROR);
+        }
+    }
+}
+
+/// ```text
+/// LifetimeParam =
+///   Attr* 'lifetime' (':' TypeBoundList?)?
+/// ```
+fn lifetime_param(p: &mut Parser, m: Marker) {
+    p.bump(LIFETIME);
+    if p.eat(T![:]) {
+        type_bound_list(p);
+    }
+    m.complete(p, LIFETIME_PARAM);
+}
+
+/// ```text
+/// TypeParam =
+///   Attr* Name (':' TypeBoundList?)?
+///   ('=' default_type:Type)?
+/// ```
+fn type_param(p: &mut Parser, m: Marker) {
+    name(p);
+    if p.eat(T![:]) {
+        type_bound_list(p);
+    }
+    if p.eat(T![=]) {
+        types::type_(p);
+    }
+    m.complete(p, TYPE_PARAM);
+}
+
+/// ```text
+/// ConstParam =
+///   Attr* 'const' Name ':' Type
+///   ('=' default_val:Expr)?
+/// ```
+fn const_param(p: &mut Parser, m: Marker) {
+    p.bump(T![const]);
+    name(p);
+    p.expect(T![:]);
+    types::type_(p);
+    if p.eat(T![=]) {
+        expressions::const_arg(p);
+    }
+    m.complete(p, CONST_PARAM);
+}
+
+/// Parses the (possibly **empty**) bounds following a `:`.
+///
+/// ```text
+/// TypeBoundList =
+///   bounds:(TypeBound ('+' TypeBound)* '+'?)
+/// ```
+pub(super) fn type_bound_list(p: &mut Parser) {
+    let m = p.start();
+
+    while type_bound(p) {
+        if !p.eat(T![+]) {
+            break;
+        }
+    }
+
+    m.complete(p, TYPE_BOUND_LIST);
+}
+
+/// Parses a **single** bound, returning `false` (without consuming anything)
+/// if there is none.
+///
+/// ```text
+/// TypeBound =
+///   'lifetime'
+/// | '?'? Type
+/// ```
+fn type_bound(p: &mut Parser) -> bool {
+    let m = p.start();
+
+    match p.current() {
+        LIFETIME => p.bump(LIFETIME),
+        T![?] => {
+            p.bump(T![?]);
+            types::type_(p);
+        }
+        _ if types::at_type_start(p) => types::type_(p),
+        _ => {
+            m.abandon(p);
+            return false;
+        }
+    }
+
+    m.complete(p, TYPE_BOUND);
+    true
+}
+
+/// ```text
+/// WhereClause =
+///   'where' predicates:(WherePred (',' WherePred)* ','?)
+/// ```
+pub(super) fn opt_where_clause(p: &mut Parser) {
+    if !p.at(T![where]) {
+        return;
+    }
+
+    let m = p.start();
+    p.bump(T![where]);
+
+    if !at_where_pred(p) {
+        p.error(SyntaxError::Expected(Expected::WherePred));
+    }
+
+    while at_where_pred(p) {
+        where_pred(p);
+        if !p.eat(T![,]) {
+            break;
+        }
+    }
+
+    m.complete(p, WHERE_CLAUSE);
+}
+
+/// Returns `true` if the current token can start a [`WherePred`][where_pred].
+fn at_where_pred(p: &Parser) -> bool {
+    p.at(LIFETIME) || types::at_type_start(p)
+}
+
+/// ```text
+/// WherePred =
+///   ('lifetime' | Type) ':' TypeBoundList?
+/// ```
+fn where_pred(p: &mut Parser) {
+    let m = p.start();
+
+    if !p.eat(LIFETIME) {
+        types::type_(p);
+    }
+
+    if p.expect(T![:]) {
+        type_bound_list(p);
+    }
+
+    m.complete(p, WHERE_PRED);
+}
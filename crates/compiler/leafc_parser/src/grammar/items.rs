@@ -0,0 +1,674 @@
+use super::*;
+
+/// Parses the **contents** of a module (i.e. a list of items), either at the
+/// top-level of a source file or within an `ItemList`.
+///
+/// If `stop_on_r_curly` is `true`, parsing stops at a `}` (i.e. the end of an
+/// `ItemList`).
+pub(super) fn mod_contents(p: &mut Parser, stop_on_r_curly: bool) {
+    while !p.at(EOF) && !(stop_on_r_curly && p.at(T!['}'])) {
+        item_or_error(p);
+    }
+}
+
+/// Parses an **item**, reporting a syntax error (and skipping the offending
+/// token) if there is none.
+fn item_or_error(p: &mut Parser) {
+    let start = p.events().len();
+    let m = p.start();
+    attributes::outer_attrs(p);
+    let has_visibility = opt_visibility(p);
+
+    let m = match opt_item(p, m) {
+        Ok(()) => return,
+        Err(m) => m,
+    };
+
+    // Macro calls are only items at the top-level of a module. Within a block,
+    // they are parsed as expressions instead (see `expressions::path_expr`).
+    if !has_visibility && at_macro_call(p) {
+        macro_call(p, m);
+        return;
+    }
+
+    // Don't lose any attributes or visibility which have already been parsed.
+    if p.events().len() > start + 1 {
+        p.error(SyntaxError::Expected(Expected::ItemAfterAttributes));
+        m.complete(p, ERROR);
+    } else {
+        m.abandon(p);
+
+        if p.at(T!['{']) {
+            // Skip over the whole block rather than reporting an error for
+            // each token inside of it.
+            let m = p.start();
+            p.error(SyntaxError::Expected(Expected::Item));
+            p.bump(T!['{']);
+            mod_contents(p, true);
+            p.expect(T!['}']);
+            m.complete(p, ERROR);
+        } else {
+            p.err_recover(SyntaxError::Expected(Expected::Item), ITEM_RECOVERY_SET);
+        }
+    }
+}
+
+/// The tokens which can start an item, where parsing **recovers** after
+/// skipping over something which isn't an item.
+pub(super) const ITEM_RECOVERY_SET: TokenSet = TokenSet::new(&[
+    T![#],
+    T![async],
+    T![const],
+    T![default],
+    T![enum],
+    T![fn],
+    T![impl],
+    T![mod],
+    T![pub],
+    T![struct],
+    T![trait],
+    T![type],
+    T![unsafe],
+    T![use],
+]);
+
+/// The **qualifiers** which may precede the `fn` keyword of a function (in
+/// this order).
+pub(super) const FN_QUALIFIERS: &[SyntaxKind] = &[T![default], T![const], T![async], T![unsafe]];
+
+/// The **qualifiers** which may precede the `impl` keyword (in this order).
+pub(super) const IMPL_QUALIFIERS: &[SyntaxKind] = &[T![default], T![unsafe]];
+
+/// ```text
+/// Item =
+///   Enum
+/// | Fn
+/// | Impl
+/// | MacroCall
+/// | Module
+/// | Struct
+/// | Trait
+/// | TypeAlias
+/// | Use
+/// ```
+///
+/// The marker `m` has already consumed any **outer attributes** and the
+/// **visibility** of the item. If there is no item, the marker is handed back
+/// to the caller.
+pub(super) fn opt_item(p: &mut Parser, m: Marker) -> Result<(), Marker> {
+    match p.current() {
+        T![enum] => enum_(p, m),
+        T![mod] => module(p, m),
+        T![struct] => struct_(p, m),
+        T![use] => use_(p, m),
+        // Only commit to an item if any qualifiers are actually followed by
+        // its keyword (e.g. `unsafe { .. }` is a block expression).
+        _ if at_qualified(p, FN_QUALIFIERS, T![fn]) => fn_(p, m),
+        _ if at_qualified(p, IMPL_QUALIFIERS, T![impl]) => impl_(p, m),
+        _ if at_qualified(p, &[T![unsafe]], T![trait]) => trait_(p, m),
+        _ if at_qualified(p, &[T![default]], T![type]) => type_alias(p, m),
+        _ => return Err(m),
+    }
+
+    Ok(())
+}
+
+/// Returns `true` if the current tokens are any of the `qualifiers` (in order)
+/// followed by the keyword `kw`.
+fn at_qualified(p: &Parser, qualifiers: &[SyntaxKind], kw: SyntaxKind) -> bool {
+    let mut n = 0;
+    for &qualifier in qualifiers {
+        if p.nth_at(n, qualifier) {
+            n += 1;
+        }
+    }
+    p.nth_at(n, kw)
+}
+
+/// Returns `true` if the current tokens start a [`macro_call`] (i.e. a path
+/// followed by a `!`).
+///
+/// **NOTE**: Only paths which fit within the parser's lookahead are
+/// recognized (e.g. `println!` or `fmt::format!`).
+fn at_macro_call(p: &Parser) -> bool {
+    let mut n = usize::from(p.at(T![::]));
+    while n < 3 && NAME_REF_FIRST.contains(&p.nth(n)) {
+        match p.nth(n + 1) {
+            T![!] => return true,
+            T![::] => n += 2,
+            _ => return false,
+        }
+    }
+    false
+}
+
+/// ```text
+/// MacroCall =
+///   Attr* Path '!' TokenTree ';'?
+/// ```
+fn macro_call(p: &mut Parser, m: Marker) {
+    paths::path(p);
+    p.expect(T![!]);
+    token_tree(p);
+    p.eat(T![;]);
+    m.complete(p, MACRO_CALL);
+}
+
+/// ```text
+/// Fn =
+///  Attr* Visibility?
+///  'default'? 'const'? 'async'? 'unsafe'?
+///  'fn' Name GenericParamList? ParamList RetType? WhereClause?
+///  (body:BlockExpr | ';')
+/// ```
+///
+/// The `fn` keyword is **localized** by the lexer (e.g. `fonction` is also a
+/// `FN_KW`), so there is nothing language-specific to do here.
+fn fn_(p: &mut Parser, m: Marker) {
+    for &qualifier in FN_QUALIFIERS {
+        p.eat(qualifier);
+    }
+    p.bump(T![fn]);
+    name(p);
+    generic_params::opt_generic_param_list(p);
+
+    if p.at(T!['(']) {
+        params::param_list(p);
+    } else {
+        p.error(SyntaxError::Expected(Expected::ParamList));
+    }
+
+    opt_ret_type(p);
+    generic_params::opt_where_clause(p);
+
+    if p.at(T!['{']) {
+        expressions::block_expr(p);
+    } else {
+        p.expect(T![;]);
+    }
+
+    m.complete(p, FN);
+}
+
+/// ```text
+/// RetType =
+///   '->' Type
+/// ```
+pub(super) fn opt_ret_type(p: &mut Parser) {
+    if p.at(T![->]) {
+        let m = p.start();
+        p.bump(T![->]);
+        types::type_(p);
+        m.complete(p, RET_TYPE);
+    }
+}
+
+/// ```text
+/// Struct =
+///   Attr* Visibility?
+///   'struct' Name GenericParamList? (
+///     WhereClause? (RecordFieldList | ';')
+///   | TupleFieldList WhereClause? ';'
+///   )
+/// ```
+fn struct_(p: &mut Parser, m: Marker) {
+    p.bump(T![struct]);
+    name(p);
+    generic_params::opt_generic_param_list(p);
+
+    match p.current() {
+        T!['('] => {
+            tuple_field_list(p);
+            generic_params::opt_where_clause(p);
+            p.expect(T![;]);
+        }
+        _ => {
+            generic_params::opt_where_clause(p);
+            match p.current() {
+                T![;] => p.bump(T![;]),
+                T!['{'] => record_field_list(p),
+                _ => p.error(SyntaxError::Expected(Expected::FieldList)),
+            }
+        }
+    }
+
+    m.complete(p, STRUCT);
+}
+
+/// ```text
+/// Enum =
+///   Attr* Visibility?
+///   'enum' Name GenericParamList? WhereClause?
+///   VariantList
+/// ```
+fn enum_(p: &mut Parser, m: Marker) {
+    p.bump(T![enum]);
+    name(p);
+    generic_params::opt_generic_param_list(p);
+    generic_params::opt_where_clause(p);
+
+    if p.at(T!['{']) {
+        variant_list(p);
+    } else {
+        p.error(SyntaxError::Expected(Expected::VariantList));
+    }
+
+    m.complete(p, ENUM);
+}
+
+/// ```text
+/// VariantList =
+///  '{' (Variant (',' Variant)* ','?)? '}'
+/// ```
+pub(super) fn variant_list(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T!['{']);
+
+    while !p.at(EOF) && !p.at(T!['}']) {
+        if !p.at_any(&[IDENTIFIER, T![#], T![pub]]) {
+            if p.at_ts(ITEM_RECOVERY_SET) {
+                break;
+            }
+            p.err_and_bump(SyntaxError::Expected(Expected::Variant));
+            continue;
+        }
+
+        variant(p);
+        if !p.at(T!['}']) {
+            p.expect(T![,]);
+        }
+    }
+
+    p.expect(T!['}']);
+    m.complete(p, VARIANT_LIST);
+}
+
+/// ```text
+/// Variant =
+///   Attr* Visibility?
+///   Name FieldList? ('=' Expr)?
+/// ```
+fn variant(p: &mut Parser) {
+    let m = p.start();
+    attributes::outer_attrs(p);
+    opt_visibility(p);
+    name(p);
+    opt_field_list(p);
+
+    if p.eat(T![=]) {
+        expressions::expr(p);
+    }
+
+    m.complete(p, VARIANT);
+}
+
+/// ```text
+/// Trait =
+///   Attr* Visibility?
+///   'unsafe'?
+///   'trait' Name GenericParamList?
+///   (':' TypeBoundList?)? WhereClause? AssocItemList
+/// ```
+fn trait_(p: &mut Parser, m: Marker) {
+    p.eat(T![unsafe]);
+    p.bump(T![trait]);
+    name(p);
+    generic_params::opt_generic_param_list(p);
+
+    if p.eat(T![:]) {
+        generic_params::type_bound_list(p);
+    }
+    generic_params::opt_where_clause(p);
+
+    if p.at(T!['{']) {
+        assoc_item_list(p);
+    } else {
+        p.error(SyntaxError::Expected(Expected::AssocItemList));
+    }
+
+    m.complete(p, TRAIT);
+}
+
+/// ```text
+/// Impl =
+///   Attr* Visibility?
+///   'default'? 'unsafe'?
+///   'impl' GenericParamList? ('const'? '!'? trait:Type 'for')? self_ty:Type WhereClause?
+///   AssocItemList
+/// ```
+fn impl_(p: &mut Parser, m: Marker) {
+    for &qualifier in IMPL_QUALIFIERS {
+        p.eat(qualifier);
+    }
+    p.bump(T![impl]);
+    generic_params::opt_generic_param_list(p);
+
+    // Either the implemented trait or the self type (there is no way to tell
+    // until we see whether there is a `for`).
+    p.eat(T![const]);
+    if p.at(T![!]) && p.nth_at(1, IDENTIFIER) {
+        p.bump(T![!]);
+    }
+    types::type_(p);
+
+    if p.eat(T![for]) {
+        types::type_(p);
+    }
+    generic_params::opt_where_clause(p);
+
+    if p.at(T!['{']) {
+        assoc_item_list(p);
+    } else {
+        p.error(SyntaxError::Expected(Expected::AssocItemList));
+    }
+
+    m.complete(p, IMPL);
+}
+
+/// ```text
+/// AssocItemList =
+///   '{' Attr* AssocItem* '}'
+/// ```
+pub(super) fn assoc_item_list(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T!['{']);
+    attributes::inner_attrs(p);
+
+    while !p.at(EOF) && !p.at(T!['}']) {
+        assoc_item(p);
+    }
+
+    p.expect(T!['}']);
+    m.complete(p, ASSOC_ITEM_LIST);
+}
+
+/// ```text
+/// AssocItem =
+///   Fn
+/// | TypeAlias
+/// ```
+fn assoc_item(p: &mut Parser) {
+    let m = p.start();
+    attributes::outer_attrs(p);
+    opt_visibility(p);
+
+    if at_qualified(p, FN_QUALIFIERS, T![fn]) {
+        fn_(p, m);
+    } else if at_qualified(p, &[T![default]], T![type]) {
+        type_alias(p, m);
+    } else {
+        p.error(SyntaxError::Expected(Expected::AssocItem));
+        if !p.at(EOF) && !p.at(T!['}']) {
+            p.bump_any();
+        }
+        m.complete(p, ERROR);
+    }
+}
+
+/// ```text
+/// TypeAlias =
+///   Attr* Visibility?
+///   'default'?
+///   'type' Name GenericParamList? (':' TypeBoundList?)? WhereClause?
+///   ('=' Type)? ';'
+/// ```
+fn type_alias(p: &mut Parser, m: Marker) {
+    p.eat(T![default]);
+    p.bump(T![type]);
+    name(p);
+    generic_params::opt_generic_param_list(p);
+
+    if p.eat(T![:]) {
+        generic_params::type_bound_list(p);
+    }
+    generic_params::opt_where_clause(p);
+
+    if p.eat(T![=]) {
+        types::type_(p);
+    }
+    p.expect(T![;]);
+
+    m.complete(p, TYPE_ALIAS);
+}
+
+/// ```text
+/// Module =
+///   Attr* Visibility?
+///   'mod' Name
+///   (ItemList | ';')
+/// ```
+fn module(p: &mut Parser, m: Marker) {
+    p.bump(T![mod]);
+    name(p);
+
+    if p.at(T!['{']) {
+        item_list(p);
+    } else {
+        p.expect(T![;]);
+    }
+
+    m.complete(p, MODULE);
+}
+
+/// ```text
+/// ItemList =
+///   '{' Attr* Item* '}'
+/// ```
+pub(super) fn item_list(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T!['{']);
+    attributes::inner_attrs(p);
+    mod_contents(p, true);
+    p.expect(T!['}']);
+    m.complete(p, ITEM_LIST);
+}
+
+/// ```text
+/// Use =
+///   Attr* Visibility?
+///   'use' UseTree ';'
+/// ```
+fn use_(p: &mut Parser, m: Marker) {
+    p.bump(T![use]);
+    use_tree(p);
+    p.expect(T![;]);
+    m.complete(p, USE);
+}
+
+/// Returns `true` if the current tokens start a [`use_tree`] (which, unlike a
+/// path, may also start with a `*` or a `{`).
+fn at_use_tree_start(p: &Parser) -> bool {
+    match p.current() {
+        T![*] | T!['{'] => true,
+        T![::] if p.nth_at(1, T![*]) || p.nth_at(1, T!['{']) => true,
+        _ => paths::is_path_start(p),
+    }
+}
+
+/// ```text
+/// UseTree =
+///   (Path? '::')? ('*' | UseTreeList)
+/// | Path Rename?
+/// ```
+fn use_tree(p: &mut Parser) {
+    let m = p.start();
+
+    match p.current() {
+        T![*] => p.bump(T![*]),
+        T![::] if p.nth_at(1, T![*]) => {
+            p.bump(T![::]);
+            p.bump(T![*]);
+        }
+        T!['{'] => use_tree_list(p),
+        T![::] if p.nth_at(1, T!['{']) => {
+            p.bump(T![::]);
+            use_tree_list(p);
+        }
+        _ if paths::is_path_start(p) => {
+            paths::path(p);
+
+            match p.current() {
+                T![as] => rename(p),
+                T![::] => {
+                    p.bump(T![::]);
+                    match p.current() {
+                        T![*] => p.bump(T![*]),
+                        T!['{'] => use_tree_list(p),
+                        _ => p.error(SyntaxError::Expected(Expected::UseTreeList)),
+                    }
+                }
+                _ => (),
+            }
+        }
+        _ => {
+            m.abandon(p);
+            p.error(SyntaxError::Expected(Expected::UseTreeStart));
+            return;
+        }
+    }
+
+    m.complete(p, USE_TREE);
+}
+
+/// ```text
+/// UseTreeList =
+///   '{' (UseTree (',' UseTree)* ','?)? '}'
+/// ```
+fn use_tree_list(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T!['{']);
+
+    while !p.at(EOF) && !p.at(T!['}']) {
+        if !at_use_tree_start(p) {
+            if p.at_ts(ITEM_RECOVERY_SET) {
+                break;
+            }
+            p.err_and_bump(SyntaxError::Expected(Expected::UseTree));
+            continue;
+        }
+
+        use_tree(p);
+        if !p.at(T!['}']) {
+            p.expect(T![,]);
+        }
+    }
+
+    p.expect(T!['}']);
+    m.complete(p, USE_TREE_LIST);
+}
+
+/// ```text
+/// Rename =
+///   'as' (Name | '_')
+/// ```
+fn rename(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T![as]);
+
+    if !p.eat(T![_]) {
+        name(p);
+    }
+
+    m.complete(p, RENAME);
+}
+
+/// ```text
+/// FieldList =
+///   RecordFieldList
+/// | TupleFieldList
+/// ```
+fn opt_field_list(p: &mut Parser) {
+    match p.current() {
+        T!['{'] => record_field_list(p),
+        T!['('] => tuple_field_list(p),
+        _ => (),
+    }
+}
+
+/// ```text
+/// RecordFieldList =
+///  '{' fields:(RecordField (',' RecordField)* ','?)? '}'
+/// ```
+pub(super) fn record_field_list(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T!['{']);
+
+    while !p.at(EOF) && !p.at(T!['}']) {
+        if !p.at_any(&[IDENTIFIER, T![#], T![pub]]) && p.at_ts(ITEM_RECOVERY_SET) {
+            break;
+        }
+
+        record_field(p);
+        if !p.at(T!['}']) {
+            p.expect(T![,]);
+        }
+    }
+
+    p.expect(T!['}']);
+    m.complete(p, RECORD_FIELD_LIST);
+}
+
+/// ```text
+/// RecordField =
+///   Attr* Visibility?
+///   Name ':' Type
+/// ```
+fn record_field(p: &mut Parser) {
+    let m = p.start();
+    attributes::outer_attrs(p);
+    opt_visibility(p);
+
+    if p.at(IDENTIFIER) {
+        name(p);
+        p.expect(T![:]);
+        types::type_(p);
+        m.complete(p, RECORD_FIELD);
+    } else {
+        p.error(SyntaxError::Expected(Expected::Field));
+        if !p.at(T!['}']) {
+            p.bump_any();
+        }
+        m.complete(p, ERROR);
+    }
+}
+
+/// ```text
+/// TupleFieldList =
+///   '(' fields:(TupleField (',' TupleField)* ','?)? ')'
+/// ```
+pub(super) fn tuple_field_list(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T!['(']);
+
+    while !p.at(EOF) && !p.at(T![')']) {
+        tuple_field(p);
+        if !p.at(T![')']) {
+            p.expect(T![,]);
+        }
+    }
+
+    p.expect(T![')']);
+    m.complete(p, TUPLE_FIELD_LIST);
+}
+
+/// ```text
+/// TupleField =
+///   Attr* Visibility?
+///   Type
+/// ```
+fn tuple_field(p: &mut Parser) {
+    let m = p.start();
+    attributes::outer_attrs(p);
+    opt_visibility(p);
+
+    if types::at_type_start(p) {
+        types::type_(p);
+        m.complete(p, TUPLE_FIELD);
+    } else {
+        p.error(SyntaxError::Expected(Expected::Type));
+        if !p.at(T![')']) {
+            p.bump_any();
+        }
+        m.complete(p, ERROR);
+    }
+}
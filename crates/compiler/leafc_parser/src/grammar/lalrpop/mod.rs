@@ -1,5 +1,480 @@
+//! A **second parser** for Leaf, generated by [LALRPOP] from `leaf.lalrpop`,
+//! which is used to **check** the hand-written recursive descent parser.
+//!
+//! The recursive descent parser decides between alternatives with a few tokens
+//! of lookahead, so nothing stops its grammar from quietly becoming ambiguous
+//! (e.g. a `{` after a `for` iterable which could either start the loop body or
+//! a block expression). LALRPOP, on the other hand, **rejects** any grammar
+//! which isn't LR(1) at build time, reporting every conflict it finds. Parsing
+//! the same inputs with both parsers and comparing the results then checks that
+//! the two grammars agree.
+//!
+//! The grammar's actions build a [`Tree`], which is flattened into the very
+//! same [`Event`]s the recursive descent parser emits and replayed by
+//! [`event::process`]. Both parsers therefore produce **identical** rowan trees
+//! (down to where trivia is attached) for any input they both accept.
+//!
+//! ## Subset
+//!
+//! Some corners of the language only parse with the recursive descent parser's
+//! arbitrary lookahead, or only make sense with its error recovery. The LALRPOP
+//! grammar leaves these out (and rejects them) rather than approximating them:
+//!
+//! - **Syntax errors**. The LALRPOP parser has no error recovery, so only valid
+//!   programs can be compared.
+//! - `break`, `continue`, `return` and `defer` are only parsed as a whole
+//!   expression (or the right-hand side of an assignment), never as the operand
+//!   of another operator.
+//! - A range without a start (e.g. `..5`) is only parsed as a whole expression,
+//!   never as the operand of another operator.
+//! - `pub(self)`, `pub(super)` and `pub(pkg)` are not allowed on tuple fields
+//!   (where they can't be told apart from a parenthesized type).
+//! - A type bound can't itself be an `impl` or `dyn` type (e.g. `impl dyn Tr`).
+//! - A superscript literal is only parsed as an exponent, never as an operand
+//!   in a superscript group (e.g. `x⁽⁻¹⁾`, or the `ⁿ⁺¹` of `2⁽ⁿ⁺¹⁾` where `⁺¹`
+//!   is split into a sign and a literal).
+//!
+//! [LALRPOP]: https://github.com/lalrpop/lalrpop
+
+use lalrpop_util::{
+    lalrpop_mod,
+    ParseError,
+};
+use leafc_cfg::lang::LanguageKind;
+use leafc_lexer::{
+    lexer::TokenStream,
+    token::Token,
+};
+use leafc_syntax::{
+    SyntaxKind::{
+        self,
+        *,
+    },
+    SyntaxTreeBuilder,
+};
+use rowan::GreenNode;
+
+use super::expressions::LITERAL_FIRST;
+use crate::event::{
+    self,
+    Event,
+};
+
+/// Builds a [`Tree::Node`] of the given `kind` out of any number of children
+/// (i.e. anything implementing [`Children`]).
+macro_rules! node {
+    ($kind:expr $(, $child:expr)* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut children = Vec::new();
+        $( $crate::grammar::lalrpop::Children::push_to($child, &mut children); )*
+        $crate::grammar::lalrpop::Tree::Node($kind, children)
+    }};
+}
+
 lalrpop_mod!(
     #[cfg_attr(rustfmt, rustfmt_skip)]
-    #[allow(unused_parens)]
-    grammar
+    #[allow(clippy::all, unused_parens)]
+    leaf,
+    "/grammar/lalrpop/leaf.rs"
 );
+
+/// The **error** produced by the LALRPOP parser.
+pub(crate) type Error = ParseError<usize, Tok, &'static str>;
+
+/// A **token** as seen by the LALRPOP parser (i.e. a non-trivia token).
+///
+/// A `>>` is split into a [`Tok::JointGt`] followed by a `>`, so that the
+/// grammar can close two generic argument lists with it (e.g. `Vec<Vec<T>>`)
+/// without the lexer having to know about generics.
+///
+/// Within a superscript group (e.g. `⁽ⁿ⁺¹⁾`), the superscript literals are
+/// told apart by [`lex`] the same way the recursive descent parser does,
+/// since the grammar can't know whether it's within a group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Tok {
+    /// A token of the given kind.
+    Kind(SyntaxKind),
+    /// The first half of a `>>`, which is always followed by a `>`.
+    JointGt,
+    /// The **sign** of a superscript literal following an operand within a
+    /// group (e.g. the `⁺` of `ⁿ⁺¹`), as the `+` or `-` of a sum.
+    Sign(SyntaxKind),
+    /// A superscript literal (of the given kind) which is an **operand**
+    /// within a group (e.g. the `⁻¹` of `⁽⁻¹⁾`, or the `¹` of `ⁿ⁺¹`).
+    GroupSup(SyntaxKind),
+}
+
+/// The syntax tree built by the actions of the LALRPOP grammar, before it is
+/// turned into [`Event`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Tree {
+    /// A node of the given kind.
+    Node(SyntaxKind, Vec<Tree>),
+    /// A token of the given kind.
+    Token(SyntaxKind),
+    /// The first character of a token, given the kind (see
+    /// [`Event::SplitToken`]).
+    Split(SyntaxKind),
+}
+
+impl Tree {
+    /// Returns the **kind** of the tree.
+    pub(crate) fn kind(&self) -> SyntaxKind {
+        match self {
+            Tree::Node(kind, _) | Tree::Token(kind) | Tree::Split(kind) => *kind,
+        }
+    }
+
+    /// Records the tree as the [`Event`]s which the recursive descent parser
+    /// would have emitted for it.
+    fn into_events(self, events: &mut Vec<Event>) {
+        match self {
+            Tree::Node(kind, children) => {
+                events.push(Event::StartNode { kind, forward_parent: None });
+                for child in children {
+                    child.into_events(events);
+                }
+                events.push(Event::FinishNode);
+            }
+            Tree::Token(kind) => events.push(Event::AddToken { kind }),
+            Tree::Split(kind) => events.push(Event::SplitToken { kind }),
+        }
+    }
+}
+
+impl From<Tok> for Tree {
+    fn from(tok: Tok) -> Self {
+        match tok {
+            Tok::Kind(kind) | Tok::GroupSup(kind) => Tree::Token(kind),
+            Tok::JointGt => Tree::Split(GT),
+            Tok::Sign(kind) => Tree::Split(kind),
+        }
+    }
+}
+
+/// Anything which can be added as (zero or more) **children** of a
+/// [`Tree::Node`] by [`node!`].
+pub(crate) trait Children {
+    fn push_to(self, children: &mut Vec<Tree>);
+}
+
+impl Children for Tree {
+    fn push_to(self, children: &mut Vec<Tree>) {
+        children.push(self);
+    }
+}
+
+impl Children for Tok {
+    fn push_to(self, children: &mut Vec<Tree>) {
+        children.push(self.into());
+    }
+}
+
+impl<T: Children> Children for Option<T> {
+    fn push_to(self, children: &mut Vec<Tree>) {
+        if let Some(child) = self {
+            child.push_to(children);
+        }
+    }
+}
+
+impl<T: Children> Children for Vec<T> {
+    fn push_to(self, children: &mut Vec<Tree>) {
+        for child in self {
+            child.push_to(children);
+        }
+    }
+}
+
+impl<A: Children, B: Children> Children for (A, B) {
+    fn push_to(self, children: &mut Vec<Tree>) {
+        self.0.push_to(children);
+        self.1.push_to(children);
+    }
+}
+
+/// Adds `first` to the **front** of the (right-recursive) list `rest`.
+fn cons(first: impl Children, mut rest: Vec<Tree>) -> Vec<Tree> {
+    let mut list = Vec::new();
+    first.push_to(&mut list);
+    list.append(&mut rest);
+    list
+}
+
+/// Adds `children` to the **front** of the children of the node `tree` (e.g.
+/// the outer attributes of an item).
+fn prepend(children: impl Children, tree: Tree) -> Tree {
+    match tree {
+        Tree::Node(kind, rest) => Tree::Node(kind, cons(children, rest)),
+        _ => unreachable!("can only prepend children to a node"),
+    }
+}
+
+/// Adds the generic argument list `args` to the **last segment** of `path`
+/// (e.g. the turbofish of `a::b::<T>`), failing if it already has one.
+fn with_generic_args(path: Tree, args: Tree) -> Result<Tree, Error> {
+    let Tree::Node(PATH, mut children) = path else {
+        unreachable!("generic arguments can only be added to a path");
+    };
+
+    match children.last_mut() {
+        Some(Tree::Node(PATH_SEGMENT, segment))
+            if !segment.iter().any(|child| child.kind() == GENERIC_ARG_LIST) =>
+        {
+            segment.push(args);
+        }
+        _ => return Err(ParseError::User { error: "a path segment has two generic arg lists" }),
+    }
+
+    Ok(Tree::Node(PATH, children))
+}
+
+/// Checks that the `qualifiers` of an item are a subsequence of `allowed` (i.e.
+/// appear in the expected order, without repetition).
+fn qualifiers(qualifiers: Vec<Tok>, allowed: &[SyntaxKind]) -> Result<Vec<Tok>, Error> {
+    let mut allowed = allowed.iter();
+    for &qualifier in &qualifiers {
+        let Tok::Kind(kind) = qualifier else { unreachable!("qualifiers are keywords") };
+        if !allowed.any(|&k| k == kind) {
+            return Err(ParseError::User { error: "unexpected or misordered qualifier" });
+        }
+    }
+    Ok(qualifiers)
+}
+
+/// Decides between a **parenthesized** node (e.g. `(a)`) and a **tuple** node
+/// (e.g. `()`, `(a,)` or `(a, b)`), given its `items` (including commas).
+fn paren_or_tuple(items: &[Tree], paren: SyntaxKind, tuple: SyntaxKind) -> SyntaxKind {
+    match items {
+        [item] if item.kind() != REST_PAT => paren,
+        _ => tuple,
+    }
+}
+
+/// Converts the (lossless) `tokens` of a source file into the tokens of the
+/// LALRPOP parser, where each token is located by its **index** in `tokens`.
+fn lex(tokens: &[Token]) -> impl Iterator<Item = (usize, Tok, usize)> + '_ {
+    // The number of superscript groups the current token is in, and whether
+    // the previous token ends an operand.
+    let mut groups = 0_usize;
+    let mut after_operand = false;
+    tokens.iter().enumerate().filter(|(_, token)| !event::is_trivia(token)).flat_map(
+        move |(i, token)| {
+            let kind = SyntaxKind::from(*token.kind());
+            let toks = match kind {
+                SHR => vec![Tok::JointGt, Tok::Kind(GT)],
+                INTEGER_SUP | FLOAT_SUP if groups > 0 => match token.lexeme().chars().next() {
+                    Some('⁺') if after_operand => vec![Tok::Sign(PLUS), Tok::GroupSup(kind)],
+                    Some('⁻') if after_operand => vec![Tok::Sign(MINUS), Tok::GroupSup(kind)],
+                    _ if after_operand => vec![Tok::Kind(kind)],
+                    _ => vec![Tok::GroupSup(kind)],
+                },
+                kind => vec![Tok::Kind(kind)],
+            };
+
+            match kind {
+                L_PAREN_SUPERSCRIPT => groups += 1,
+                R_PAREN_SUPERSCRIPT => groups = groups.saturating_sub(1),
+                _ => (),
+            }
+            after_operand = ends_operand(kind);
+            toks.into_iter().map(move |tok| (i, tok, i + 1))
+        },
+    )
+}
+
+/// Returns `true` if a token of the given kind can **end** an operand (e.g.
+/// an identifier or a `)`), after which a signed superscript literal within a
+/// group is a sum (e.g. `ⁿ⁺¹`) rather than a literal (e.g. `⁽⁻¹⁾`).
+fn ends_operand(kind: SyntaxKind) -> bool {
+    LITERAL_FIRST.contains(&kind) ||
+        matches!(
+            kind,
+            IDENTIFIER |
+                INTEGER_SUP |
+                FLOAT_SUP |
+                R_PAREN |
+                R_BRACKET |
+                R_BRACE |
+                R_PAREN_SUPERSCRIPT |
+                FORMAT_STRING_END |
+                QMARK |
+                SELF_VALUE_KW |
+                SELF_TYPE_KW |
+                SUPER_KW |
+                PACKAGE_KW
+        )
+}
+
+/// Parses the given source text with the **LALRPOP** parser into a lossless
+/// **green tree** (i.e. the root of a `SourceFile`), which is identical to the
+/// one produced by [`crate::parse`] with the same spoken `languages`.
+pub(crate) fn parse(source_text: &str, languages: &[LanguageKind]) -> Result<GreenNode, Error> {
+    let tokens = TokenStream::with_languages(source_text, true, languages);
+    let tokens = tokens.tokens().iter().cloned().collect::<Vec<_>>();
+    let tree = leaf::SourceFileParser::new().parse(lex(&tokens))?;
+
+    let mut events = Vec::new();
+    tree.into_events(&mut events);
+
+    let mut builder = SyntaxTreeBuilder::new();
+    let errors = event::process(&tokens, events, &mut builder);
+    debug_assert!(errors.is_empty(), "the LALRPOP grammar accepted an unknown token");
+
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod differential_test_suite {
+    use std::{
+        fs,
+        path::Path,
+    };
+
+    use pretty_assertions_sorted::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    /// The spoken **languages** of the examples (which aren't all written in
+    /// English).
+    const LANGUAGES: &[LanguageKind] = &[LanguageKind::Any];
+
+    /// Parses `source_text` with **both** parsers, checking that the recursive
+    /// descent parser reports no errors and that both produce the same tree.
+    fn check(source_text: &str) {
+        check_named(&format!("{source_text:?}"), source_text);
+    }
+
+    /// [Checks][check] `source_text`, which is called `name` in the failures
+    /// (e.g. the name of its file).
+    fn check_named(name: &str, source_text: &str) {
+        let (expected, errors) = crate::parse(source_text, LANGUAGES);
+        assert!(errors.is_empty(), "{name} has syntax errors: {errors:?}");
+
+        let actual = parse(source_text, LANGUAGES)
+            .unwrap_or_else(|error| panic!("the LALRPOP parser rejected {name}: {error:?}"));
+        assert_eq!(
+            format!("{:#?}", leafc_syntax::SyntaxNode::new_root(actual)),
+            format!("{:#?}", leafc_syntax::SyntaxNode::new_root(expected)),
+            "{name}"
+        );
+    }
+
+    /// Checks every program of the snapshot **corpus** which parses without
+    /// errors (i.e. `tests/data/parser/ok`).
+    #[test]
+    fn parse_ok_corpus() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data/parser/ok");
+        let mut files = fs::read_dir(&dir)
+            .unwrap_or_else(|err| panic!("can't read {}: {err}", dir.display()))
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().map_or(false, |ext| ext == "leaf"))
+            .collect::<Vec<_>>();
+        files.sort();
+        assert!(!files.is_empty(), "no programs in {}", dir.display());
+
+        for file in files {
+            let name = file.file_name().unwrap().to_string_lossy();
+            check_named(&format!("ok/{name}"), &fs::read_to_string(&file).unwrap());
+        }
+    }
+
+    #[rstest]
+    #[case(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../../examples/leaf/hello_world/hello_world.leaf")))]
+    #[case(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../../examples/leaf/hello_world/bonjour_le_monde.leaf")))]
+    fn parse_examples(#[case] source_text: &str) {
+        check(source_text);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("#![no_std]\n#[inline]\npub fn f() {}")]
+    #[case("pub(pkg) fn f() {} pub(in a::b) struct S; pub(self) mod m; pub(super) use x;")]
+    #[case(
+        "const unsafe fn f<'a, T: Clone + 'a, const N: usize = 3>(x: &'a T) -> [T; N] where T: \
+         Copy, {}"
+    )]
+    #[case("async fn f(self) {} fn g(&mut self, a: i32) {} fn h(mut self: Box<Self>) {}")]
+    #[case("fn f((a, b): (i32, i32), x: [u8; 1], _: ()) {}")]
+    #[case("fn f(mut x: i32, S { a, b: c, .. }: S, T(z, ..): T) {}")]
+    #[case(
+        "struct Unit; struct Tuple(pub i32, pub(in a) u8, ()); struct Record<T> where T: Eq { pub \
+         a: T, b: Vec<T>, }"
+    )]
+    #[case("struct S(pub (i32, u8), pub [u8; 4]);")]
+    #[case("enum E { A, B(i32), C { x: i32 }, D = 1, #[attr] E }")]
+    #[case("trait T: Sized + 'static { type A: Clone; fn f(&self); fn g() {} }")]
+    #[case("unsafe trait U {}")]
+    #[case("impl<T> Trait for Vec<T> where T: Clone { default fn f() {} type A = i32; }")]
+    #[case("impl !Send for S {} impl const Tr for S {} unsafe impl Sync for S {}")]
+    #[case("impl ! {}")]
+    #[case("type A = fn(i32, x: u8) -> !; type B = unsafe fn(); type C<'a> = &'a mut dyn Tr + 'a;")]
+    #[case(
+        "type A = impl Iterator<Item = u8>; type B = HashMap<Key, Vec<(V, V)>>; type C = \
+         Vec<Vec<i32>>;"
+    )]
+    #[case("type A = Foo<'a, 1, -1, { N }, T: Clone>; type B = a::b::<T>::C; type C = ::std::D<>;")]
+    #[case("type A = (); type B = (i32,); type C = (i32); type D = [i32]; type E = Self::Item;")]
+    #[case("mod a { #![attr] mod b; }")]
+    #[case(
+        "use a; use a::b as c; use a::{b, c::*, d as _}; use ::a; use *; use ::{a}; use \
+         a::b::{self};"
+    )]
+    #[case("thread_local! { static X: i32 = 1; } m!(a >> b); m![]; a::m! {}")]
+    #[case("#[derive(Debug, Clone)] #[cfg(all(unix, not(test)))] #[path = \"a\"] struct S;")]
+    #[case("fn f() { let x = 1; let mut w: i32 = 2; z := 3; let (a, b) = pair; }")]
+    #[case("fn f() { a; b }")]
+    #[case("fn f() { a(); b.c(d, g,); f[0].g; h::<i32>() }")]
+    #[case("fn f() { 1 + 2 * 3 - 4 / 5 % 6 ** 7 ** 8; a << b >> c & d ^ g | h; }")]
+    #[case("fn f() { a == b != c < d <= g > h >= k; a is b isnt c; a and b or c && d || g; }")]
+    #[case("fn f() { x = z += 1; a -= b *= c /= d %= g <<= h >>= k &= l |= m ^= n; ch <- v; }")]
+    #[case("fn f() { -a; !b; not c; &d; &mut g; &const h; - -k; & &l; }")]
+    #[case("fn f() { 0..10; 1..; ..2; ..; a..=b; ..=c; }")]
+    #[case("fn f() { (a); (#[attr] b); \"s\"; r\"raw\"; 'c'; 1.5; true; false; }")]
+    #[case("fn f() { if a { b } else if c { d } else { g } if x {} z }")]
+    #[case(
+        "fn f() { loop {} while a < b { break } for i in 0.. { continue } 'a: loop { break 'a 1 } \
+         }"
+    )]
+    #[case("fn f() { for x in xs {} 'outer: for i in 0..n { continue 'outer } }")]
+    #[case("fn f() { unsafe { a } async { b } const { c } 'l: { d } 'm: unsafe {} }")]
+    #[case("fn f() { match x { 1 => a, 2 | 3 => { b } 4 if c => d, _ => {} } }")]
+    #[case("fn f() { match x { | A => 1, B(..) => 2, C { a, .. } => 3, D::E => 4, } }")]
+    #[case(
+        "fn f() { match x { 0..=9 => a, 'a'..='z' => b, ..=0 => c, 10.. => d, -5..-1 => g, x @ 1 \
+         => f } }"
+    )]
+    #[case("fn f() { match x { #[attr] A => if a { b } else { c } B => match z {} } }")]
+    #[case("fn f() { return; return 1; break; defer close(f); fallthrough; }")]
+    #[case("fn f() -> i32 { return a + b }")]
+    #[case("fn f() { if a {} - 1; {} (a); x }")]
+    #[case("fn f() { {} {} ; a }")]
+    #[case("fn f() { let x = if a { 1 } else { 2 }; let w = match z { _ => 0 } }")]
+    #[case("fn f() { #[attr] let x = 1; #[attr] a; #[attr] { b } }")]
+    #[case("fn f() { fn g() {} struct S; pub use a; g() }")]
+    #[case("fn f() { println!(\"{}\", x); vec![1, 2, 3] }")]
+    #[case("fn f() { println!(\"{a} + {b(\"{c}\")}\"); x := \"{{}} {x[0] * 2}!\" + \"{}\" }")]
+    #[case("fn f() { let S { a: (b, c), d, } = s; let T(.., x) = t; let (a, ..) = u; }")]
+    #[case("fn f() { let a::B = x; let ::C(d) = z; let Self { g } = z; let super::F = w; }")]
+    #[case(
+        "fn f() { let 1 = a; let -1 = b; let 'c' = d; let \"s\" = g; let _ = f; let mut g = h; }"
+    )]
+    #[case("fn f() { a.b.c(); a[b][c]; a()(); (a)(b) }")]
+    #[case("fn f() { x = return 1; w = break 2; z = a..; }")]
+    #[case("fn f() { if a = b {} while x.. {} for i in ..n {} match a.. {} }")]
+    #[case("fn f() { foo::<Vec<u8>>(); a::<{ N }>(); b::<'a>(); }")]
+    #[case("fn f<T: Iterator<Item = Vec<u8>>>() -> impl Tr {} impl<T> Tr<T> for S<T> {}")]
+    #[case("fn f() { a >> b >>= c; x\n- 1 }")]
+    #[case("fn f() { a; ; b }")]
+    #[case("fn f() { let v = 1 let w = 2 v }")]
+    #[case("fn f() { v := 1\n w := v }")]
+    #[case("fn f() { 'a: while x {} }")]
+    #[case("fn f() { √z + 2 * π ∪ a ∩ b ⊆ c ∧ z ≠ ∞ }")]
+    #[case("fn f() { ∫f + ∠z * 2 ∝ ∮g ∴ ∯h ∵ ∰k }")]
+    #[case("fn f() { -x² + 2⁽n + 1⁾ ** f(z)²·⁵ - (a + b)⁻¹ * z[0]⁽n²⁾ }")]
+    #[case("fn f() { 2⁽ⁿ⁺¹⁾ - x⁽⁻¹⁾ + (a + b)⁽ⁿ⁻²·⁵⁾ * 2⁽⁺¹⁾ }")]
+    fn parse_snippets(#[case] source_text: &str) {
+        check(source_text);
+    }
+}
@@ -1 +1,195 @@
-// TODO: add grammar
+//! The **grammar** of the **Leaf programming language**, implemented as a set
+//! of mutually recursive functions driving the [`Parser`].
+//!
+//! Each function roughly corresponds to a production in `leaf.ungram` (e.g.
+//! [`items::use_`] parses a `Use`) and records the nodes it parses as
+//! [`Event`][crate::event::Event]s on the [`Parser`]. Functions prefixed with
+//! `opt_` parse an **optional** construct and are a no-op if the construct is
+//! not present.
+//!
+//! **NOTE**: The grammar functions never fail. On unexpected input, they
+//! report a [`SyntaxError`] and do their best to produce a tree anyway.
+
+mod attributes;
+mod expressions;
+mod generic_params;
+mod items;
+#[cfg(test)]
+mod lalrpop;
+mod params;
+mod paths;
+mod patterns;
+mod types;
+
+use leafc_diagnostics::errors::{
+    Expected,
+    SuperscriptError,
+    SyntaxError,
+};
+use leafc_syntax::{
+    SyntaxKind::{
+        self,
+        *,
+    },
+    T,
+};
+
+use crate::{
+    parser::{
+        CompletedMarker,
+        Marker,
+        Parser,
+    },
+    token_set::TokenSet,
+};
+
+/// Parses a **source file** (i.e. the root of the syntax tree).
+///
+/// ```text
+/// SourceFile =
+///   Attr*
+///   Item*
+/// ```
+pub(crate) fn source_file(p: &mut Parser) {
+    let m = p.start();
+    attributes::inner_attrs(p);
+    items::mod_contents(p, false);
+    m.complete(p, SOURCE_FILE);
+}
+
+/// Returns the grammar function which parses a node of the given `kind` on its
+/// own (i.e. independently of its parent), if there is one.
+///
+/// These are the **brace-delimited lists** (e.g. a block's `StmtList`), which
+/// can be reparsed in isolation after an edit (see
+/// [`Parse::reparse`][crate::Parse::reparse]).
+pub(crate) fn reparser(kind: SyntaxKind) -> Option<fn(&mut Parser)> {
+    let reparser = match kind {
+        STMT_LIST => expressions::stmt_list,
+        MATCH_ARM_LIST => expressions::match_arm_list,
+        ITEM_LIST => items::item_list,
+        ASSOC_ITEM_LIST => items::assoc_item_list,
+        VARIANT_LIST => items::variant_list,
+        RECORD_FIELD_LIST => items::record_field_list,
+        _ => return None,
+    };
+    Some(reparser)
+}
+
+/// ```text
+/// Name =
+///   'ident' | 'self'
+/// ```
+fn name(p: &mut Parser) {
+    if p.at_any(&[IDENTIFIER, T![self_value]]) {
+        let m = p.start();
+        p.bump_any();
+        m.complete(p, NAME);
+    } else {
+        p.error(SyntaxError::Expected(Expected::Name));
+    }
+}
+
+/// Returns `true` if the current token can start a [`name_ref`].
+fn at_name_ref(p: &Parser) -> bool {
+    p.at_any(NAME_REF_FIRST)
+}
+
+/// The tokens which can start a `NameRef`.
+const NAME_REF_FIRST: &[SyntaxKind] =
+    &[IDENTIFIER, T![self_value], T![super], T![package], T![self_type]];
+
+/// ```text
+/// NameRef =
+///   'ident' | 'self' | 'super' | 'pkg' | 'Self'
+/// ```
+fn name_ref(p: &mut Parser) {
+    if at_name_ref(p) {
+        let m = p.start();
+        p.bump_any();
+        m.complete(p, NAME_REF);
+    } else {
+        p.error(SyntaxError::Expected(Expected::Identifier));
+    }
+}
+
+/// ```text
+/// Visibility =
+///   'pub' ('(' 'in'? Path ')')?
+/// ```
+fn opt_visibility(p: &mut Parser) -> bool {
+    if !p.at(T![pub]) {
+        return false;
+    }
+
+    let m = p.start();
+    p.bump(T![pub]);
+
+    // `pub(in path)`, `pub(self)`, `pub(super)` and `pub(pkg)`, being careful
+    // not to treat a parenthesized type (e.g. in a tuple field) as part of the
+    // visibility.
+    if p.at(T!['(']) {
+        match p.nth(1) {
+            T![in] => {
+                p.bump(T!['(']);
+                p.bump(T![in]);
+                paths::path(p);
+                p.expect(T![')']);
+            }
+            T![self_value] | T![super] | T![package] if p.nth_at(2, T![')']) => {
+                p.bump(T!['(']);
+                paths::path(p);
+                p.bump(T![')']);
+            }
+            _ => (),
+        }
+    }
+
+    m.complete(p, VISIBILITY);
+    true
+}
+
+/// ```text
+/// TokenTree =
+///   '(' ')'
+/// | '{' '}'
+/// | '[' ']'
+/// ```
+///
+/// The delimiters may hold **any** sequence of tokens, as long as it is
+/// balanced (i.e. nested delimiters are parsed as nested `TokenTree`s). Format
+/// strings are parsed as a `FormatStringExpr`, so that their holes can be
+/// resolved like any other expression.
+fn token_tree(p: &mut Parser) {
+    let closing = match p.current() {
+        T!['('] => T![')'],
+        T!['{'] => T!['}'],
+        T!['['] => T![']'],
+        _ => {
+            p.error(SyntaxError::Expected(Expected::TokenTree));
+            return;
+        }
+    };
+
+    let m = p.start();
+    p.bump_any();
+
+    while !p.at(EOF) && !p.at(closing) {
+        match p.current() {
+            T!['('] | T!['{'] | T!['['] => token_tree(p),
+            FORMAT_STRING_START => {
+                expressions::format_string_expr(p);
+            }
+            // An unmatched `}` most likely closes an enclosing block, so leave
+            // it alone rather than swallowing the rest of the block.
+            T!['}'] => break,
+            T![')'] | T![']'] => {
+                p.err_and_bump(SyntaxError::Expected(Expected::Token(format!("{closing:?}").into())));
+            }
+            _ => p.bump_any(),
+        }
+    }
+
+    p.expect(closing);
+    m.complete(p, TOKEN_TREE);
+}
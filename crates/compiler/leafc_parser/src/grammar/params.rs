@@ -0,0 +1,120 @@
+use super::*;
+
+/// The kind of function a [`ParamList`][param_list] belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Flavor {
+    /// The parameters of a `fn` item (e.g. `fn f(&self, x: i32)`).
+    FnDef,
+    /// The parameters of a function pointer type (e.g. `fn(i32)`), which
+    /// can't take `self` and may omit their patterns.
+    FnPointer,
+}
+
+/// Parses the parameters of a `fn` item (see [`Flavor::FnDef`]).
+pub(super) fn param_list(p: &mut Parser) {
+    list(p, Flavor::FnDef);
+}
+
+/// Parses the parameters of a function pointer type (see
+/// [`Flavor::FnPointer`]).
+pub(super) fn fn_ptr_param_list(p: &mut Parser) {
+    list(p, Flavor::FnPointer);
+}
+
+/// ```text
+/// ParamList =
+///   '('(
+///     SelfParam
+///   | (SelfParam ',')? (Param (',' Param)* ','?)?
+///   )')'
+/// ```
+fn list(p: &mut Parser, flavor: Flavor) {
+    let m = p.start();
+    p.bump(T!['(']);
+
+    if flavor == Flavor::FnDef && at_self_param(p) {
+        self_param(p);
+        if !p.at(T![')']) {
+            p.expect(T![,]);
+        }
+    }
+
+    while !p.at(EOF) && !p.at(T![')']) {
+        let at_param = match flavor {
+            Flavor::FnDef => patterns::at_pat_start(p),
+            Flavor::FnPointer => types::at_type_start(p),
+        };
+        if !p.at(T![#]) && !at_param {
+            if p.at_ts(items::ITEM_RECOVERY_SET) {
+                break;
+            }
+            p.err_and_bump(SyntaxError::Expected(Expected::Param));
+            continue;
+        }
+
+        param(p, flavor);
+
+        if !p.at(T![')']) && !p.expect(T![,]) {
+            break;
+        }
+    }
+
+    p.expect(T![')']);
+    m.complete(p, PARAM_LIST);
+}
+
+/// Returns `true` if the current tokens start a [`SelfParam`][self_param]
+/// (i.e. `self`, `&self`, `&mut self` or `mut self`).
+fn at_self_param(p: &Parser) -> bool {
+    let n = match p.current() {
+        T![&] if p.nth_at(1, T![mut]) => 2,
+        T![&] | T![mut] => 1,
+        _ => 0,
+    };
+    p.nth_at(n, T![self_value])
+}
+
+/// ```text
+/// SelfParam =
+///   Attr* (
+///     '&'? 'mut'? Name
+///   | 'mut'? Name ':' Type
+///   )
+/// ```
+fn self_param(p: &mut Parser) {
+    let m = p.start();
+    let is_ref = p.eat(T![&]);
+    p.eat(T![mut]);
+    name(p);
+
+    // An explicitly typed `self` (e.g. `self: Box<Self>`) can't be a reference.
+    if !is_ref && p.eat(T![:]) {
+        types::type_(p);
+    }
+
+    m.complete(p, SELF_PARAM);
+}
+
+/// ```text
+/// Param =
+///   Attr* (Pat ':' Type | Type)
+/// ```
+///
+/// The pattern may only be omitted for a [`Flavor::FnPointer`], where it is
+/// only parsed if it's a name (e.g. `fn(x: i32)`).
+fn param(p: &mut Parser, flavor: Flavor) {
+    let m = p.start();
+    attributes::outer_attrs(p);
+
+    let has_pat = match flavor {
+        Flavor::FnDef => true,
+        Flavor::FnPointer => p.at_any(&[IDENTIFIER, T![_]]) && p.nth_at(1, T![:]),
+    };
+    if has_pat {
+        patterns::pattern(p);
+        p.expect(T![:]);
+    }
+    types::type_(p);
+
+    m.complete(p, PARAM);
+}
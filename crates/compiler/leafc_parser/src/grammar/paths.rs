@@ -0,0 +1,148 @@
+use super::*;
+
+/// Returns `true` if the current token can start a [`path`].
+pub(super) fn is_path_start(p: &Parser) -> bool {
+    at_name_ref(p) || (p.at(T![::]) && NAME_REF_FIRST.contains(&p.nth(1)))
+}
+
+/// Where a [`path`] is being parsed, which determines how its generic
+/// arguments are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PathMode {
+    /// Paths in expressions and patterns, whose generic arguments need a
+    /// leading `::` (e.g. `Vec::<i32>::new`).
+    Expr,
+    /// Paths in types (e.g. `Vec<i32>`).
+    Type,
+}
+
+/// Parses a path in an expression or pattern (see [`PathMode::Expr`]).
+pub(super) fn path(p: &mut Parser) {
+    path_for(p, PathMode::Expr);
+}
+
+/// Parses a path in a type (see [`PathMode::Type`]).
+pub(super) fn type_path(p: &mut Parser) {
+    path_for(p, PathMode::Type);
+}
+
+/// ```text
+/// Path =
+///   (qualifier:Path '::')? segment:PathSegment
+/// ```
+fn path_for(p: &mut Parser, mode: PathMode) {
+    let m = p.start();
+    path_segment(p, mode, true);
+    let mut qualifier = m.complete(p, PATH);
+
+    while p.at(T![::]) && NAME_REF_FIRST.contains(&p.nth(1)) {
+        let m = qualifier.precede(p);
+        p.bump(T![::]);
+        path_segment(p, mode, false);
+        qualifier = m.complete(p, PATH);
+    }
+}
+
+/// ```text
+/// PathSegment =
+///   '::'? NameRef GenericArgList?
+/// ```
+fn path_segment(p: &mut Parser, mode: PathMode, first: bool) {
+    let m = p.start();
+
+    if first {
+        p.eat(T![::]);
+    }
+    name_ref(p);
+    opt_generic_arg_list(p, mode);
+
+    m.complete(p, PATH_SEGMENT);
+}
+
+/// ```text
+/// GenericArgList =
+///   '::'? '<' (GenericArg (',' GenericArg)* ','?)? '>'
+/// ```
+fn opt_generic_arg_list(p: &mut Parser, mode: PathMode) {
+    let turbofish = p.at(T![::]) && p.nth_at(1, T![<]);
+    if !turbofish && !(mode == PathMode::Type && p.at(T![<])) {
+        return;
+    }
+
+    let m = p.start();
+    p.eat(T![::]);
+    p.bump(T![<]);
+
+    while !p.at(EOF) && !p.at(T![>]) && !p.at(T![>>]) {
+        if !at_generic_arg(p) {
+            p.err_and_bump(SyntaxError::Expected(Expected::GenericArg));
+            continue;
+        }
+
+        generic_arg(p);
+        if !p.at(T![>]) && !p.at(T![>>]) && !p.expect(T![,]) {
+            break;
+        }
+    }
+
+    p.expect_r_angle();
+    m.complete(p, GENERIC_ARG_LIST);
+}
+
+/// Returns `true` if the current token can start a [`generic_arg`].
+fn at_generic_arg(p: &Parser) -> bool {
+    p.at_any(&[LIFETIME, T!['{'], T![-]]) ||
+        expressions::LITERAL_FIRST.contains(&p.current()) ||
+        types::at_type_start(p)
+}
+
+/// Parses a generic argument, where a path is always parsed as a `TypeArg`
+/// (e.g. the `N` in `Array<T, N>`), as it can't be told apart from a const.
+///
+/// ```text
+/// GenericArg =
+///   TypeArg
+/// | AssocTypeArg
+/// | LifetimeArg
+/// | ConstArg
+///
+/// TypeArg =
+///   Type
+///
+/// AssocTypeArg =
+///   NameRef (':' TypeBoundList | '=' Type)
+///
+/// LifetimeArg =
+///   'lifetime'
+///
+/// ConstArg =
+///   Expr
+/// ```
+fn generic_arg(p: &mut Parser) {
+    let m = p.start();
+    let kind = match p.current() {
+        LIFETIME => {
+            p.bump(LIFETIME);
+            LIFETIME_ARG
+        }
+        IDENTIFIER if p.nth_at(1, T![=]) || p.nth_at(1, T![:]) => {
+            name_ref(p);
+            if p.eat(T![=]) {
+                types::type_(p);
+            } else {
+                p.bump(T![:]);
+                generic_params::type_bound_list(p);
+            }
+            ASSOC_TYPE_ARG
+        }
+        _ if types::at_type_start(p) => {
+            types::type_(p);
+            TYPE_ARG
+        }
+        _ => {
+            expressions::const_arg(p);
+            CONST_ARG
+        }
+    };
+    m.complete(p, kind);
+}
@@ -0,0 +1,293 @@
+use super::*;
+
+/// The tokens (other than literals and paths) which can start a
+/// [pattern][pattern].
+const PAT_FIRST: &[SyntaxKind] = &[T![_], T![mut], T!['('], T![..], T![..=], T![|]];
+
+/// Returns `true` if the current token can start a [pattern][pattern].
+pub(super) fn at_pat_start(p: &Parser) -> bool {
+    p.at_any(PAT_FIRST) || at_literal_pat(p) || paths::is_path_start(p)
+}
+
+/// Returns `true` if the current token can start a [`literal_pat`].
+fn at_literal_pat(p: &Parser) -> bool {
+    expressions::LITERAL_FIRST.contains(&p.current()) ||
+        (p.at(T![-]) && expressions::LITERAL_FIRST.contains(&p.nth(1)))
+}
+
+/// Parses a pattern, including a top-level **or-pattern** (e.g. `1 | 2`,
+/// which may also start with a leading `|`).
+///
+/// ```text
+/// Pat =
+///   IdentPat
+/// | LiteralPat
+/// | OrPat
+/// | ParenPat
+/// | PathPat
+/// | RangePat
+/// | RecordPat
+/// | RestPat
+/// | TuplePat
+/// | TupleStructPat
+/// | WildcardPat
+///
+/// OrPat =
+///   (Pat ('|' Pat)* '|'?)
+/// ```
+pub(super) fn pattern(p: &mut Parser) -> Option<CompletedMarker> {
+    let m = p.start();
+    let has_leading_pipe = p.eat(T![|]);
+    let pat = pattern_single(p);
+
+    if !has_leading_pipe && !p.at(T![|]) {
+        m.abandon(p);
+        return pat;
+    }
+
+    while p.eat(T![|]) {
+        pattern_single(p);
+    }
+    Some(m.complete(p, OR_PAT))
+}
+
+/// Parses a single pattern (i.e. anything but an **or-pattern**), which may
+/// be the `start` of a [`RangePat`][range_pat].
+fn pattern_single(p: &mut Parser) -> Option<CompletedMarker> {
+    let lhs = atom_pat(p)?;
+
+    if matches!(lhs.kind(), LITERAL_PAT | PATH_PAT) && p.at_any(&[T![..], T![..=]]) {
+        let m = lhs.precede(p);
+        return Some(range_pat(p, m));
+    }
+
+    Some(lhs)
+}
+
+fn atom_pat(p: &mut Parser) -> Option<CompletedMarker> {
+    let cm = match p.current() {
+        T![_] => wildcard_pat(p),
+        T![mut] => ident_pat(p),
+        T![self_value] | IDENTIFIER if !at_path_pat(p) => ident_pat(p),
+        _ if paths::is_path_start(p) => path_pat(p),
+        _ if at_literal_pat(p) => literal_pat(p),
+        T!['('] => tuple_pat(p),
+        T![..] if !at_range_pat_end_after(p) => rest_pat(p),
+        T![..] | T![..=] => {
+            let m = p.start();
+            range_pat(p, m)
+        }
+        _ => {
+            p.error(SyntaxError::Expected(Expected::Pattern));
+            return None;
+        }
+    };
+    Some(cm)
+}
+
+/// Returns `true` if the identifier at the current position starts a path
+/// based pattern (e.g. `Some(x)`, `Point { .. }`, `a::B` or `A..=B`) rather
+/// than an [`IdentPat`][ident_pat].
+fn at_path_pat(p: &Parser) -> bool {
+    matches!(p.nth(1), T!['('] | T!['{'] | T![::] | T![..] | T![..=])
+}
+
+/// Returns `true` if the `..` at the current position is followed by the
+/// `end` of a [`RangePat`][range_pat] (e.g. `..5`), rather than being a
+/// [`RestPat`][rest_pat].
+fn at_range_pat_end_after(p: &Parser) -> bool {
+    let next = p.nth(1);
+    expressions::LITERAL_FIRST.contains(&next) ||
+        next == T![-] ||
+        NAME_REF_FIRST.contains(&next) ||
+        next == T![::]
+}
+
+/// ```text
+/// IdentPat =
+///   Attr* 'mut'? Name ('@' Pat)?
+/// ```
+fn ident_pat(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    p.eat(T![mut]);
+    name(p);
+    if p.eat(T![@]) {
+        pattern_single(p);
+    }
+    m.complete(p, IDENT_PAT)
+}
+
+/// ```text
+/// WildcardPat =
+///   '_'
+/// ```
+fn wildcard_pat(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T![_]);
+    m.complete(p, WILDCARD_PAT)
+}
+
+/// ```text
+/// LiteralPat =
+///   '-'? Literal
+/// ```
+fn literal_pat(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    p.eat(T![-]);
+    expressions::literal(p);
+    m.complete(p, LITERAL_PAT)
+}
+
+/// Parses any of the patterns starting with a path.
+///
+/// ```text
+/// PathPat =
+///   Path
+///
+/// TupleStructPat =
+///   Path '(' fields:(Pat (',' Pat)* ','?)? ')'
+///
+/// RecordPat =
+///   Path RecordPatFieldList
+/// ```
+fn path_pat(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    paths::path(p);
+    let kind = match p.current() {
+        T!['('] => {
+            tuple_pat_fields(p);
+            TUPLE_STRUCT_PAT
+        }
+        T!['{'] => {
+            record_pat_field_list(p);
+            RECORD_PAT
+        }
+        _ => PATH_PAT,
+    };
+    m.complete(p, kind)
+}
+
+/// Parses a parenthesized pattern, which is a `TuplePat` unless it contains
+/// exactly one pattern without a trailing `,` (e.g. `(x)`).
+///
+/// ```text
+/// TuplePat =
+///   '(' fields:(Pat (',' Pat)* ','?)? ')'
+///
+/// ParenPat =
+///   '(' Pat ')'
+/// ```
+fn tuple_pat(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    let (n_pats, has_comma, has_rest) = tuple_pat_fields(p);
+    let kind = if n_pats == 1 && !has_comma && !has_rest { PAREN_PAT } else { TUPLE_PAT };
+    m.complete(p, kind)
+}
+
+/// Parses the parenthesized, comma-separated fields of a `TuplePat` or a
+/// `TupleStructPat`, returning the number of patterns and whether there was
+/// a `,` or a `RestPat` amongst them.
+fn tuple_pat_fields(p: &mut Parser) -> (usize, bool, bool) {
+    p.bump(T!['(']);
+
+    let (mut n_pats, mut has_comma, mut has_rest) = (0, false, false);
+    while !p.at(EOF) && !p.at(T![')']) {
+        if !at_pat_start(p) {
+            p.err_and_bump(SyntaxError::Expected(Expected::Pattern));
+            continue;
+        }
+
+        n_pats += 1;
+        has_rest |= pattern(p).map_or(false, |cm| cm.kind() == REST_PAT);
+        if !p.at(T![')']) {
+            has_comma |= p.expect(T![,]);
+        } else {
+            has_comma |= p.eat(T![,]);
+        }
+    }
+
+    p.expect(T![')']);
+    (n_pats, has_comma, has_rest)
+}
+
+/// ```text
+/// RecordPatFieldList =
+///   '{'
+///     fields:(RecordPatField (',' RecordPatField)* ','?)?
+///     RestPat?
+///   '}'
+/// ```
+fn record_pat_field_list(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T!['{']);
+
+    while !p.at(EOF) && !p.at(T!['}']) {
+        if !p.at(T![#]) && !p.at(T![..]) && !at_name_ref(p) {
+            p.err_and_bump(SyntaxError::Expected(Expected::RecordPatField));
+            continue;
+        }
+
+        record_pat_field(p);
+        if !p.at(T!['}']) {
+            p.expect(T![,]);
+        }
+    }
+
+    p.expect(T!['}']);
+    m.complete(p, RECORD_PAT_FIELD_LIST);
+}
+
+/// Parses either a field (e.g. `x: 0` or the shorthand `x`) or the trailing
+/// `RestPat` of a `RecordPatFieldList`, either of which may have attributes.
+///
+/// ```text
+/// RecordPatField =
+///   Attr* (NameRef ':')? Pat
+/// ```
+fn record_pat_field(p: &mut Parser) {
+    let m = p.start();
+    attributes::outer_attrs(p);
+
+    if p.eat(T![..]) {
+        m.complete(p, REST_PAT);
+        return;
+    }
+
+    if at_name_ref(p) && p.nth_at(1, T![:]) {
+        name_ref(p);
+        p.bump(T![:]);
+    }
+    pattern(p);
+    m.complete(p, RECORD_PAT_FIELD);
+}
+
+/// Parses a `RangePat`, where `m` either precedes the already parsed `start`
+/// of the range or is placed right before its operator.
+///
+/// ```text
+/// RangePat =
+///   start:Pat op:('..' | '..=')
+///   | start:Pat op:('..' | '..=') end:Pat
+///   | op:('..' | '..=') end:Pat
+/// ```
+fn range_pat(p: &mut Parser, m: Marker) -> CompletedMarker {
+    p.bump_any();
+    if paths::is_path_start(p) {
+        let m = p.start();
+        paths::path(p);
+        m.complete(p, PATH_PAT);
+    } else if at_literal_pat(p) {
+        literal_pat(p);
+    }
+    m.complete(p, RANGE_PAT)
+}
+
+/// ```text
+/// RestPat =
+///   Attr* '..'
+/// ```
+fn rest_pat(p: &mut Parser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T![..]);
+    m.complete(p, REST_PAT)
+}
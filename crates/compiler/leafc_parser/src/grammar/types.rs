@@ -0,0 +1,171 @@
+use super::*;
+
+/// The tokens (other than paths) which can start a [type][type_].
+const TYPE_FIRST: &[SyntaxKind] =
+    &[T![!], T!['('], T!['['], T![&], T![fn], T![unsafe], T![impl], T![dyn]];
+
+/// Returns `true` if the current token can start a [type][type_].
+pub(super) fn at_type_start(p: &Parser) -> bool {
+    // A `::` only starts a type if it's followed by a path segment (otherwise
+    // `type_` wouldn't consume it, and lists of types would never progress).
+    p.at_any(TYPE_FIRST) || paths::is_path_start(p)
+}
+
+/// ```text
+/// Type =
+///   ArrayType
+/// | DynTraitType
+/// | FnPtrType
+/// | ImplTraitType
+/// | NeverType
+/// | ParenType
+/// | PathType
+/// | RefType
+/// | SliceType
+/// | TupleType
+/// ```
+pub(super) fn type_(p: &mut Parser) {
+    match p.current() {
+        T![!] => never_type(p),
+        T!['('] => paren_or_tuple_type(p),
+        T!['['] => array_or_slice_type(p),
+        T![&] => ref_type(p),
+        T![fn] | T![unsafe] => fn_ptr_type(p),
+        T![impl] => impl_trait_type(p),
+        T![dyn] => dyn_trait_type(p),
+        _ if paths::is_path_start(p) => path_type(p),
+        _ => p.error(SyntaxError::Expected(Expected::Type)),
+    }
+}
+
+/// ```text
+/// NeverType =
+///   '!'
+/// ```
+fn never_type(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T![!]);
+    m.complete(p, NEVER_TYPE);
+}
+
+/// Parses a parenthesized type, which is a `TupleType` unless it contains
+/// exactly one type without a trailing `,` (e.g. `(T)`).
+///
+/// ```text
+/// ParenType =
+///   '(' Type ')'
+///
+/// TupleType =
+///   '(' fields:(Type (',' Type)* ','?)? ')'
+/// ```
+fn paren_or_tuple_type(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T!['(']);
+
+    let (mut n_types, mut has_comma) = (0, false);
+    while !p.at(EOF) && !p.at(T![')']) {
+        if !at_type_start(p) {
+            p.err_and_bump(SyntaxError::Expected(Expected::Type));
+            continue;
+        }
+
+        n_types += 1;
+        type_(p);
+        if !p.at(T![')']) {
+            has_comma |= p.expect(T![,]);
+        } else {
+            has_comma |= p.eat(T![,]);
+        }
+    }
+
+    p.expect(T![')']);
+    let kind = if n_types == 1 && !has_comma { PAREN_TYPE } else { TUPLE_TYPE };
+    m.complete(p, kind);
+}
+
+/// ```text
+/// ArrayType =
+///   '[' Type ';' Expr ']'
+///
+/// SliceType =
+///   '[' Type ']'
+/// ```
+fn array_or_slice_type(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T!['[']);
+    type_(p);
+
+    let kind = if p.eat(T![;]) {
+        expressions::expr(p);
+        ARRAY_TYPE
+    } else {
+        SLICE_TYPE
+    };
+
+    p.expect(T![']']);
+    m.complete(p, kind);
+}
+
+/// ```text
+/// RefType =
+///   '&' 'lifetime'? 'mut'? Type
+/// ```
+fn ref_type(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T![&]);
+    p.eat(LIFETIME);
+    p.eat(T![mut]);
+    type_(p);
+    m.complete(p, REF_TYPE);
+}
+
+/// ```text
+/// FnPtrType =
+///   'unsafe'? 'fn' ParamList RetType?
+/// ```
+fn fn_ptr_type(p: &mut Parser) {
+    let m = p.start();
+    p.eat(T![unsafe]);
+    p.expect(T![fn]);
+
+    if p.at(T!['(']) {
+        params::fn_ptr_param_list(p);
+    } else {
+        p.error(SyntaxError::Expected(Expected::ParamList));
+    }
+    items::opt_ret_type(p);
+
+    m.complete(p, FN_PTR_TYPE);
+}
+
+/// ```text
+/// ImplTraitType =
+///   'impl' TypeBoundList
+/// ```
+fn impl_trait_type(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T![impl]);
+    generic_params::type_bound_list(p);
+    m.complete(p, IMPL_TRAIT_TYPE);
+}
+
+/// ```text
+/// DynTraitType =
+///   'dyn' TypeBoundList
+/// ```
+fn dyn_trait_type(p: &mut Parser) {
+    let m = p.start();
+    p.bump(T![dyn]);
+    generic_params::type_bound_list(p);
+    m.complete(p, DYN_TRAIT_TYPE);
+}
+
+/// ```text
+/// PathType =
+///   Path
+/// ```
+fn path_type(p: &mut Parser) {
+    let m = p.start();
+    paths::type_path(p);
+    m.complete(p, PATH_TYPE);
+}
@@ -3,3 +3,35 @@ pub mod recursive_descent;
 
 pub mod event;
 pub mod parser;
+pub mod token_set;
+mod validation;
+
+use leafc_cfg::lang::LanguageKind;
+use leafc_diagnostics::errors::SyntaxError;
+use leafc_utils::Spanned;
+
+pub use crate::{
+    parser::Parser,
+    recursive_descent::{
+        Parse,
+        TextEdit,
+    },
+};
+
+/// Parses the given source text into a lossless **green tree** (i.e. the root
+/// of a `SourceFile`), along with any syntax errors encountered along the way.
+///
+/// Only the **keywords** of the given spoken `languages` are recognized (or
+/// those of the language the source text declares, e.g. `#![lang(fr)]`), so
+/// the keywords of every other language may be used as names (e.g. `vente`, the
+/// Danish spelling of `await`, within an English file).
+///
+/// A tree is **always** produced, even for completely invalid input.
+pub fn parse(
+    source_text: &str,
+    languages: &[LanguageKind],
+) -> (rowan::GreenNode, Vec<Spanned<SyntaxError>>) {
+    let mut p = Parser::new(source_text, true, languages);
+    grammar::source_file(&mut p);
+    p.finish()
+}
@@ -1,6 +1,5 @@
 use std::{
     cell::RefCell,
-    collections::VecDeque,
     rc::Rc,
 };
 
@@ -9,18 +8,38 @@ use getset::{
     MutGetters,
     Setters,
 };
-use leafc_diagnostics::errors::SyntaxError;
+use leafc_cfg::lang::LanguageKind;
+use leafc_diagnostics::errors::{
+    Expected,
+    SyntaxError,
+};
 use leafc_lexer::{
     lexer::{
         TokenOffset,
         TokenStream,
     },
     token::Token,
-    TokenKind,
 };
-use leafc_syntax::SyntaxTreeBuilder;
+use leafc_syntax::{
+    SyntaxKind::{
+        self,
+        *,
+    },
+    SyntaxNode,
+    SyntaxTreeBuilder,
+    T,
+};
+use leafc_utils::Spanned;
+use rowan::GreenNode;
 
-// use crate::event::Event;
+use crate::{
+    event::{
+        self,
+        Event,
+    },
+    token_set::TokenSet,
+    validation,
+};
 
 /// The **parser** for the **Leaf programming language**. This is the main
 /// entry point for parsing a source file. The parser is responsible for
@@ -29,15 +48,23 @@ use leafc_syntax::SyntaxTreeBuilder;
 /// during parsing. The parser is implemented as a **recursive descent parser**.
 /// This means that the parser is implemented as a set of mutually recursive
 /// functions, where each function is responsible for parsing a particular
-/// syntactic construct.
+/// syntactic construct (see [`grammar`][crate::grammar]).
+///
+/// The parser doesn't build the syntax tree directly. Instead, it records a
+/// list of [`Event`]s which are replayed into the [`SyntaxTreeBuilder`] once
+/// parsing is [finished][Parser::finish]. **Trivia** (i.e. whitespace and
+/// comments) is skipped over by the parser and only reinserted into the tree
+/// when the events are processed.
 ///
 /// # Example:
 ///
 /// ```rust,ignore
 /// use leafc_parser::Parser;
 ///
-/// let parser = Parser::new("fn main() { println!(\"Hello, world!\"); }", false);
-/// let ast = parser.parse();
+/// let mut parser = Parser::new("mod foo;", true, &[LanguageKind::English]);
+/// grammar::source_file(&mut parser);
+///
+/// let (green, errors) = parser.finish();
 /// ```
 #[derive(Debug, Clone, Getters, MutGetters, Setters)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -49,15 +76,17 @@ pub struct Parser {
     /// # Example:
     ///
     /// ```rust
+    /// use leafc_cfg::lang::LanguageKind;
     /// use leafc_parser::Parser;
     ///
-    /// let parser = Parser::new("fn main() { println!(\"Hello, world!\"); }", false);
+    /// let mut parser =
+    ///     Parser::new("fn main() { println!(\"Hello, world!\"); }", false, &[LanguageKind::English]);
     ///
     /// // Retrieve the token stream from the parser.
-    /// let tokens = parser.tokens();
+    /// let tokens = parser.tokens().clone();
     ///
     /// // Retrieve the token stream from the parser mutably.
-    /// let mut mut_tokens = parser.tokens_mut();
+    /// let _mut_tokens = parser.tokens_mut();
     ///
     /// // Set the token stream of the parser.
     /// parser.set_tokens(tokens);
@@ -73,42 +102,59 @@ pub struct Parser {
     /// # Example:
     ///
     /// ```rust
+    /// use leafc_cfg::lang::LanguageKind;
     /// use leafc_parser::Parser;
     ///
-    /// let parser = Parser::new("fn main() { println!(\"Hello, world!\"); }", false);
+    /// let mut parser =
+    ///     Parser::new("fn main() { println!(\"Hello, world!\"); }", false, &[LanguageKind::English]);
     ///
     /// // Retrieve the syntax tree builder from the parser.
-    /// let tree_builder = parser.tree_builder();
+    /// let tree_builder = parser.tree_builder().clone();
     ///
     /// // Retrieve the syntax tree builder from the parser mutably.
-    /// let mut mut_tree_builder = parser.tree_builder_mut();
+    /// let _mut_tree_builder = parser.tree_builder_mut();
     ///
     /// // Set the syntax tree builder of the parser.
     /// parser.set_tree_builder(tree_builder);
     /// ```
     tree_builder: Rc<RefCell<SyntaxTreeBuilder>>,
 
-    /// The list of **syntax errors** encountered during parsing.
+    /// The list of **events** recorded during parsing (i.e. the nodes
+    /// started and finished, the tokens consumed, and the syntax errors
+    /// encountered).
     ///
     /// # Example:
     ///
     /// ```rust
+    /// use leafc_cfg::lang::LanguageKind;
     /// use leafc_parser::Parser;
     ///
-    /// let parser = Parser::new("fn main() { println!(\"Hello, world!\"); }", false);
+    /// let mut parser =
+    ///     Parser::new("fn main() { println!(\"Hello, world!\"); }", false, &[LanguageKind::English]);
     ///
-    /// // Retrieve the list of syntax errors from the parser.
-    /// let errors = parser.errors();
+    /// // Retrieve the list of events from the parser.
+    /// let events = parser.events().clone();
     ///
-    /// // Retrieve the list of syntax errors from the parser mutably.
-    /// let mut mut_errors = parser.errors_mut();
+    /// // Retrieve the list of events from the parser mutably.
+    /// let _mut_events = parser.events_mut();
     ///
-    /// // Set the list of syntax errors of the parser.
-    /// parser.set_errors(errors);
+    /// // Set the list of events of the parser.
+    /// parser.set_events(events);
     /// ```
-    errors: VecDeque<SyntaxError>,
-    // The list of events that have occurred during parsing.
-    // events: VecDeque<Option<Event>>, // TODO: add implementation for this
+    events: Vec<Event>,
+
+    /// Whether the first `>` of the current `>>` token has already been
+    /// consumed (see [`Parser::expect_r_angle`]).
+    split_shr: bool,
+
+    /// Whether the sign of the current superscript literal has already been
+    /// consumed (see [`Parser::bump_superscript_sign`]).
+    split_sign: bool,
+
+    /// Whether the parser is inside a **superscript group** (e.g. the
+    /// `⁽ⁿ⁺¹⁾` of `2⁽ⁿ⁺¹⁾`), where a signed superscript literal following an
+    /// operand is a sum rather than an exponent.
+    in_superscript: bool,
 }
 
 impl Parser {
@@ -121,6 +167,10 @@ impl Parser {
     /// * `input_str` - The input string to parse.
     /// * `lossless` - Whether or not the parser should preserve whitespace and
     ///  comments in the token stream.
+    /// * `languages` - The spoken languages whose **keywords** are recognized
+    ///  (unless the input declares its own language, e.g. `#![lang(fr)]`), the
+    ///  keywords of every other language being parsed as identifiers (see
+    ///  [`TokenStream::with_languages`]).
     ///
     /// # Returns
     ///
@@ -129,20 +179,52 @@ impl Parser {
     /// # Example:
     ///
     /// ```rust
+    /// use leafc_cfg::lang::LanguageKind;
     /// use leafc_parser::Parser;
     ///
     /// // Create a new parser from the given input string. The parser will
     /// // preserve whitespace and comments in the token stream.
-    /// let parser = Parser::new("fn main() { println!(\"Hello, world!\"); }", true);
+    /// let parser =
+    ///     Parser::new("fn main() { println!(\"Hello, world!\"); }", true, &[LanguageKind::English]);
     /// ```
-    pub fn new(input_str: &str, lossless: bool) -> Self {
-        let tokens = TokenStream::new(input_str, lossless);
+    pub fn new(input_str: &str, lossless: bool, languages: &[LanguageKind]) -> Self {
+        let mut parser = Self {
+            tokens:         TokenStream::with_languages(input_str, lossless, languages),
+            tree_builder:   Rc::new(RefCell::new(SyntaxTreeBuilder::new())),
+            events:         Vec::new(),
+            split_shr:      false,
+            split_sign:     false,
+            in_superscript: false,
+        };
+        parser.skip_trivia();
+        parser
+    }
 
-        Self {
-            tokens,
-            tree_builder: Rc::new(RefCell::new(SyntaxTreeBuilder::new())),
-            errors: VecDeque::new(),
-        }
+    /// **Finishes** parsing, replaying the recorded [`Event`]s into the
+    /// underlying [`SyntaxTreeBuilder`].
+    ///
+    /// # Returns
+    ///
+    /// The **root** green node of the syntax tree along with the **syntax
+    /// errors** encountered during lexing, parsing and validating the tree (in
+    /// position order).
+    pub fn finish(self) -> (GreenNode, Vec<Spanned<SyntaxError>>) {
+        let mut builder = self.tree_builder.take();
+        let tokens = self.tokens.tokens().iter().cloned().collect::<Vec<_>>();
+        let parse_errors = event::process(&tokens, self.events, &mut builder);
+
+        let green = builder.finish();
+        let mut errors = self
+            .tokens
+            .errors()
+            .iter()
+            .map(|error| Spanned { span: error.span, node: error.node.clone().into() })
+            .chain(parse_errors)
+            .chain(validation::validate(&SyntaxNode::new_root(green.clone())))
+            .collect::<Vec<_>>();
+        errors.sort_by_key(|error| error.span.start());
+
+        (green, errors)
     }
 
     // -----------------------------------------------------------------------
@@ -155,13 +237,16 @@ impl Parser {
     /// # Example:
     ///
     /// ```rust
+    /// use leafc_cfg::lang::LanguageKind;
+    /// use leafc_lexer::TokenKind;
     /// use leafc_parser::Parser;
     ///
-    /// let parser = Parser::new("fn main() { println!(\"Hello, world!\"); }", false);
+    /// let parser =
+    ///     Parser::new("fn main() { println!(\"Hello, world!\"); }", false, &[LanguageKind::English]);
     ///
     /// // Retrieve the current token from the parser.
-    /// let curr_token = parser.curr_token()?;
-    /// assert_eq!(curr_token.kind(), TokenKind::FN_KW);
+    /// let curr_token = parser.curr_token().unwrap();
+    /// assert_eq!(*curr_token.kind(), TokenKind::FN_KW);
     /// ```
     #[inline]
     pub fn curr_token(&self) -> Option<&Token> {
@@ -174,10 +259,12 @@ impl Parser {
     /// # Example:
     ///
     /// ```rust
+    /// use leafc_cfg::lang::LanguageKind;
     /// use leafc_parser::Parser;
     ///
-    /// let parser = Parser::new("fn main() { println!(\"Hello, world!\"); }", false);
-    /// assert_eq!(parser.num_tokens(), 15);
+    /// let parser =
+    ///     Parser::new("fn main() { println!(\"Hello, world!\"); }", false, &[LanguageKind::English]);
+    /// assert_eq!(parser.num_tokens(), 12);
     /// ```
     #[inline]
     pub fn num_tokens(&self) -> usize {
@@ -189,73 +276,93 @@ impl Parser {
     /// # Example:
     ///
     /// ```rust
+    /// use leafc_cfg::lang::LanguageKind;
+    /// use leafc_lexer::lexer::TokenOffset;
     /// use leafc_parser::Parser;
     ///
-    /// let parser = Parser::new("fn main() { println!(\"Hello, world!\"); }", false);
+    /// let parser =
+    ///     Parser::new("fn main() { println!(\"Hello, world!\"); }", false, &[LanguageKind::English]);
     /// assert_eq!(parser.cursor(), TokenOffset::from(0));
     /// ```
     pub fn cursor(&self) -> TokenOffset {
         *self.tokens().cursor()
     }
 
-    /// Returns the next token in the [`TokenStream`] being parsed. If the
-    /// cursor is at the end of the stream, then `None` is returned.
-    ///
-    /// # Example:
-    ///
-    /// ```rust
-    /// use leafc_parser::Parser;
-    ///
-    /// let parser = Parser::new("1 + 2", false);
-    ///
-    /// // Retrieve the next token from the parser.
-    /// assert_eq!(parser.next_token()?.kind(), TokenKind::INTEGER);
+    /// Returns the **nth** non-trivia token ahead of the current token in the
+    /// [`TokenStream`] being parsed. If the cursor is at the end of the
+    /// stream, then `None` is returned.
     ///
-    /// // Retrieve the next token from the parser.
-    /// assert_eq!(parser.next_token()?.kind(), TokenKind::PLUS);
+    /// **NOTE**: This method only supports up to 4 tokens ahead.
+    /// This is because the parser only needs to look ahead by 4 tokens
+    /// at most (i.e. past all the qualifiers of a function, as in
+    /// `default const async unsafe fn`).
     ///
-    /// // Retrieve the next token from the parser.
-    /// assert_eq!(parser.next_token()?.kind(), TokenKind::INTEGER);
+    /// # Arguments
     ///
-    /// // There are no more tokens in the stream.
-    /// assert_eq!(parser.next_token(), None);
-    /// ```
-    pub fn next_token(&mut self) -> Option<Token> {
-        self.tokens_mut().next()
+    /// * `n` - The number of tokens ahead to look (_0 < n < 5_)
+    pub fn nth_token_ahead(&self, n: usize) -> Option<&Token> {
+        debug_assert!(n > 0 && n < 5);
+
+        self.tokens
+            .tokens()
+            .iter()
+            .skip(self.cursor().into())
+            .filter(|token| !event::is_trivia(token))
+            .nth(n)
     }
 
-    /// Returns the **nth** token ahead of the current token in the
-    /// [`TokenStream`] being parsed. If the cursor is at the end of the
-    /// stream, then `None` is returned.
+    /// Returns the [`SyntaxKind`] of the **nth** non-trivia token ahead of the
+    /// current token (where `0` is the current token). If the cursor is at the
+    /// end of the stream, then `EOF` is returned.
     ///
-    /// **NOTE**: This method only supports up to 3 tokens ahead.
-    /// This is because the parser only needs to look ahead by 3 tokens
-    /// at most.
+    /// # Example:
     ///
-    /// # Arguments
+    /// ```rust,ignore
+    /// let parser = Parser::new("1 + 2", false, &[LanguageKind::English]);
     ///
-    /// * `n` - The number of tokens ahead to look (_0 < n < 4_)
-    pub fn nth_token_ahead(&self, n: usize) -> Option<&Token> {
-        debug_assert!(n > 0 && n < 4);
+    /// assert_eq!(parser.nth(0), SyntaxKind::INTEGER);
+    /// assert_eq!(parser.nth(1), SyntaxKind::PLUS);
+    /// ```
+    pub fn nth(&self, n: usize) -> SyntaxKind {
+        if n == 0 && self.split_shr {
+            return T![>];
+        }
 
-        self.tokens.at((self.cursor() + n).into())
+        let token = if n == 0 { self.curr_token() } else { self.nth_token_ahead(n) };
+        token.map_or(EOF, |token| SyntaxKind::from(*token.kind()))
+    }
+
+    /// Returns the [`SyntaxKind`] of the current token (i.e. `EOF` at the end
+    /// of the stream).
+    #[inline]
+    pub fn current(&self) -> SyntaxKind {
+        self.nth(0)
     }
 
+    /// Advances the cursor past the current token and any trivia following it.
     fn inc_cursor(&mut self) {
         self.tokens_mut().inc_cursor();
+        self.skip_trivia();
+    }
+
+    /// Advances the cursor past any **trivia** (i.e. whitespace and comments).
+    fn skip_trivia(&mut self) {
+        while self.curr_token().map_or(false, event::is_trivia) {
+            self.tokens_mut().inc_cursor();
+        }
     }
 
     // -----------------------------------------------------------------------
     // Parser methods
     // -----------------------------------------------------------------------
 
-    /// Consumes the next token in the [`TokenStream`] being parsed.
-    pub fn pop(&mut self) -> Token {
-        if let Some(token) = self.next_token() {
-            token
-        } else {
-            Token::EOF()
-        }
+    /// **Starts** a new node in the syntax tree, returning a [`Marker`] which
+    /// must later be either [completed][Marker::complete] or
+    /// [abandoned][Marker::abandon].
+    pub fn start(&mut self) -> Marker {
+        let pos = self.events.len() as u32;
+        self.push_event(Event::tombstone());
+        Marker::new(pos)
     }
 
     /// Consumes the token if it **matches** the **expected token kind**.
@@ -271,51 +378,186 @@ impl Parser {
     /// # Example
     ///
     /// ```rust,ignore
-    /// let mut parser = Parser::new("fn main() { println!(\"Hello, world!\"); }", false);
+    /// let mut parser =
+    ///     Parser::new("fn main() { println!(\"Hello, world!\"); }", false, &[LanguageKind::English]);
     ///
-    /// // The token kind is `TokenKind::Fn`
-    /// let token = parser.pop();
-    /// assert_eq!(token.kind(), &TokenKind::Fn);
-    /// assert_eq!(parser.eat(&TokenKind::FN_KW), true);
+    /// // The token kind is `SyntaxKind::FN_KW`
+    /// assert!(parser.eat(SyntaxKind::FN_KW));
     ///
-    /// // The token kind is `TokenKind::IDENTIFIER`
-    /// assert!(!parser.eat(&TokenKind::FN_KW));
-    /// assert!(parser.eat(&TokenKind::IDENTIFIER));
+    /// // The token kind is `SyntaxKind::IDENTIFIER`
+    /// assert!(!parser.eat(SyntaxKind::FN_KW));
+    /// assert!(parser.eat(SyntaxKind::IDENTIFIER));
     /// ```
-    pub fn eat(&mut self, expected: &TokenKind) -> bool {
-        if self.at_end() || !self.at(expected) {
+    pub fn eat(&mut self, expected: SyntaxKind) -> bool {
+        if !self.at(expected) {
             return false;
         }
 
-        let token = self.pop();
-        self.push_token(token);
+        self.do_bump(expected);
         true
     }
 
-    /// **Inserts** the given token into the underlying syntax tree
-    /// being built by the parser.
+    /// Consumes the next token in the [`TokenStream`] being parsed, which
+    /// **must** be of the given `kind`.
     ///
-    /// # Arguments
-    ///
-    /// * `token` - The token to insert into the underlying syntax tree.
+    /// # Panics
     ///
-    /// # Example
+    /// Panics if the current token is not of the given `kind`.
+    pub fn bump(&mut self, kind: SyntaxKind) {
+        assert!(self.eat(kind), "expected {kind:?}, found {:?}", self.current());
+    }
+
+    /// Consumes the next token in the [`TokenStream`] being parsed, whatever
+    /// its kind. This is a no-op at the end of the stream.
+    pub fn bump_any(&mut self) {
+        let kind = self.current();
+        if kind != EOF {
+            self.do_bump(kind);
+        }
+    }
+
+    /// Consumes the current token, **remapping** it to the given `kind` in the
+    /// syntax tree (e.g. for contextual keywords).
+    pub fn bump_remap(&mut self, kind: SyntaxKind) {
+        if self.current() != EOF {
+            self.do_bump(kind);
+        }
+    }
+
+    fn do_bump(&mut self, kind: SyntaxKind) {
+        self.push_event(Event::AddToken { kind });
+        self.split_shr = false;
+        self.split_sign = false;
+        self.inc_cursor();
+    }
+
+    /// Consumes the `>` closing a list of generic parameters or arguments,
+    /// otherwise reports a **syntax error**.
+    ///
+    /// A `>>` token is **split** in two, so that nested lists can be closed
+    /// at once (e.g. `Vec<Vec<T>>`). Only its first `>` is consumed, and the
+    /// parser is left looking at the second.
+    pub fn expect_r_angle(&mut self) -> bool {
+        if self.at(T![>>]) {
+            self.push_event(Event::SplitToken { kind: T![>] });
+            self.split_shr = true;
+            return true;
+        }
+
+        self.expect(T![>])
+    }
+
+    /// Returns `true` if the current token is a superscript literal whose
+    /// **sign** hasn't been consumed yet (e.g. `⁺¹` or `⁻²·⁵`).
+    pub fn at_superscript_sign(&self) -> bool {
+        !self.split_sign &&
+            self.at_any(&[INTEGER_SUP, FLOAT_SUP]) &&
+            self.curr_token().map_or(false, |token| token.lexeme().starts_with(['⁺', '⁻']))
+    }
+
+    /// Consumes the **sign** of the current superscript literal as a `+` or
+    /// `-` (e.g. the `⁺` of the `ⁿ⁺¹` in `2⁽ⁿ⁺¹⁾`), leaving the parser looking
+    /// at the rest of the literal.
+    pub fn bump_superscript_sign(&mut self) {
+        debug_assert!(self.at_superscript_sign());
+
+        let kind =
+            if self.curr_token().unwrap().lexeme().starts_with('⁺') { T![+] } else { T![-] };
+        self.push_event(Event::SplitToken { kind });
+        self.split_sign = true;
+    }
+
+    /// Consumes the token if it is of the `expected` kind, otherwise reports
+    /// a **syntax error**.
     ///
-    /// ```rust,ignore
-    /// let mut parser = Parser::new("fn main() { println!(\"Hello, world!\"); }", false);
+    /// # Returns
     ///
-    /// // The token kind is `TokenKind::FN_KW`
-    /// let token = parser.pop();
-    /// parser.push_token(token);
-    /// ```
-    pub fn push_token(&mut self, token: Token) {
-        self.tree_builder().borrow_mut().add_token(&token);
+    /// `true` if the token matches the expected token kind.
+    pub fn expect(&mut self, expected: SyntaxKind) -> bool {
+        if self.eat(expected) {
+            return true;
+        }
+
+        self.error(SyntaxError::Expected(Expected::Token(format!("{expected:?}").into())));
+        false
     }
 
-    /// Consumes the next token in the [`TokenStream`] being parsed.
-    pub fn bump(&mut self, kind: &TokenKind) {
-        // pub fn bump(&mut self, kind: &SyntaxKind) {
-        debug_assert!(self.eat(kind));
+    /// Reports a **syntax error** at the current position.
+    pub fn error(&mut self, error: SyntaxError) {
+        self.push_event(Event::Error { error });
+    }
+
+    /// Reports a **syntax error** and consumes the current token, wrapping it
+    /// in an `ERROR` node.
+    pub fn err_and_bump(&mut self, error: SyntaxError) {
+        let m = self.start();
+        self.error(error);
+        self.bump_any();
+        m.complete(self, ERROR);
+    }
+
+    /// Reports a **syntax error** and [skips][Parser::skip_to] ahead to a point
+    /// where parsing can **recover**, wrapping the skipped tokens in a single
+    /// `ERROR` node.
+    pub fn err_recover(&mut self, error: SyntaxError, recovery: TokenSet) {
+        if self.at(EOF) {
+            self.error(error);
+            return;
+        }
+
+        let m = self.start();
+        self.error(error);
+        self.skip_to(recovery);
+        m.complete(self, ERROR);
+    }
+
+    /// Skips ahead to a point where parsing can **recover** (i.e. a token in
+    /// the `recovery` set, a closing `}` or the first token on a new line).
+    ///
+    /// The current token is always consumed, so that the parser makes
+    /// progress. Blocks are skipped over as a whole, so that their closing
+    /// `}` doesn't end the enclosing block.
+    pub fn skip_to(&mut self, recovery: TokenSet) {
+        let mut depth = 0_usize;
+        loop {
+            match self.current() {
+                EOF => break,
+                T!['{'] => depth += 1,
+                T!['}'] => depth = depth.saturating_sub(1),
+                _ => (),
+            }
+            self.bump_any();
+
+            let at_boundary = self.at_ts(recovery) || self.at(T!['}']) || self.at_line_start();
+            if depth == 0 && at_boundary {
+                break;
+            }
+        }
+    }
+
+    /// Returns `true` if the current token is preceded by **trivia** (i.e.
+    /// whitespace or a comment), rather than directly following the previous
+    /// token.
+    pub fn after_trivia(&self) -> bool {
+        let cursor: usize = self.cursor().into();
+        cursor.checked_sub(1).and_then(|prev| self.tokens.at(prev)).map_or(false, event::is_trivia)
+    }
+
+    /// Returns `true` if the current token is the **first** token on its line
+    /// (i.e. the trivia before it contains a newline).
+    pub fn at_line_start(&self) -> bool {
+        let cursor: usize = self.cursor().into();
+        self.tokens
+            .tokens()
+            .iter()
+            .take(cursor)
+            .rev()
+            .take_while(|token| event::is_trivia(token))
+            .any(|token| token.lexeme().contains('\n'))
+    }
+
+    fn push_event(&mut self, event: Event) {
+        self.events.push(event);
     }
 
     /// Returns `true` if the current token the parser is looking at is a
@@ -333,16 +575,26 @@ impl Parser {
     /// # Examples
     ///
     /// ```
+    /// use leafc_cfg::lang::LanguageKind;
     /// use leafc_parser::parser::Parser;
+    /// use leafc_syntax::SyntaxKind;
     ///
-    /// let mut parser = Parser::new("1 + 2", false);
+    /// let parser = Parser::new("1 + 2", false, &[LanguageKind::English]);
     ///
-    /// // The current token is a `TokenKind::INTEGER` token.
-    /// assert!(parser.at(&TokenKind::INTEGER));
-    /// assert!(!parser.at(&TokenKind::PLUS));
+    /// // The current token is a `SyntaxKind::INTEGER` token.
+    /// assert!(parser.at(SyntaxKind::INTEGER));
+    /// assert!(!parser.at(SyntaxKind::PLUS));
     /// ```
-    pub fn at(&self, kind: &TokenKind) -> bool {
-        self.curr_token().map_or(false, |token| token.kind() == kind)
+    #[inline]
+    pub fn at(&self, kind: SyntaxKind) -> bool {
+        self.current() == kind
+    }
+
+    /// Returns `true` if the **nth** non-trivia token ahead is of the given
+    /// `kind` (where `0` is the current token).
+    #[inline]
+    pub fn nth_at(&self, n: usize, kind: SyntaxKind) -> bool {
+        self.nth(n) == kind
     }
 
     /// Returns `true` if the current token the parser is looking at is any of
@@ -360,16 +612,24 @@ impl Parser {
     /// # Examples
     ///
     /// ```
+    /// use leafc_cfg::lang::LanguageKind;
     /// use leafc_parser::parser::Parser;
+    /// use leafc_syntax::SyntaxKind;
     ///
-    /// let mut parser = Parser::new("1 + 2", false);
+    /// let parser = Parser::new("1 + 2", false, &[LanguageKind::English]);
     ///
-    /// // The current token is a `TokenKind::INTEGER` token.
-    /// assert!(parser.at_any(&[TokenKind::INTEGER, TokenKind::PLUS]));
-    /// assert!(!parser.at_any(&[TokenKind::IDENTIFIER, TokenKind::MINUS]));
+    /// // The current token is a `SyntaxKind::INTEGER` token.
+    /// assert!(parser.at_any(&[SyntaxKind::INTEGER, SyntaxKind::PLUS]));
+    /// assert!(!parser.at_any(&[SyntaxKind::IDENTIFIER, SyntaxKind::MINUS]));
     /// ```
-    pub fn at_any(&self, kinds: &[TokenKind]) -> bool {
-        self.curr_token().map_or(false, |token| kinds.iter().any(|kind| token.kind() == kind))
+    pub fn at_any(&self, kinds: &[SyntaxKind]) -> bool {
+        kinds.contains(&self.current())
+    }
+
+    /// Returns `true` if the current token is in the given [`TokenSet`].
+    #[inline]
+    pub fn at_ts(&self, set: TokenSet) -> bool {
+        set.contains(self.current())
     }
 
     // -----------------------------------------------------------------------
@@ -388,9 +648,11 @@ impl Parser {
     /// # Examples
     ///
     /// ```
+    /// use leafc_cfg::lang::LanguageKind;
     /// use leafc_parser::parser::Parser;
+    /// use leafc_syntax::SyntaxKind;
     ///
-    /// let mut parser = Parser::new("1 + 2", false);
+    /// let parser = Parser::new("1 + 2", false, &[LanguageKind::English]);
     ///
     /// // Parser is initialized with a cursor at the beginning of
     /// // the token stream (not at the end) so this will return false.
@@ -414,9 +676,11 @@ impl Parser {
     /// # Examples
     ///
     /// ```
+    /// use leafc_cfg::lang::LanguageKind;
     /// use leafc_parser::parser::Parser;
+    /// use leafc_syntax::SyntaxKind;
     ///
-    /// let mut parser = Parser::new("1 + 2", false);
+    /// let parser = Parser::new("1 + 2", false, &[LanguageKind::English]);
     ///
     /// // Parser is initialized with a cursor at the beginning of
     /// // the token stream.
@@ -436,15 +700,17 @@ impl Parser {
     /// # Examples
     ///
     /// ```
+    /// use leafc_cfg::lang::LanguageKind;
     /// use leafc_parser::parser::Parser;
+    /// use leafc_syntax::SyntaxKind;
     ///
-    /// let mut parser = Parser::new("1 + 2", false);
+    /// let parser = Parser::new("1 + 2", false, &[LanguageKind::English]);
     ///
     /// // Parser is initialized with 0 errors.
     /// assert_eq!(parser.num_errors(), 0);
     /// ```
     pub fn num_errors(&self) -> usize {
-        self.errors().len()
+        self.events.iter().filter(|event| matches!(event, Event::Error { .. })).count()
     }
 
     /// Returns `true` if the parser encountered any syntax errors.
@@ -456,9 +722,11 @@ impl Parser {
     /// # Examples
     ///
     /// ```
+    /// use leafc_cfg::lang::LanguageKind;
     /// use leafc_parser::parser::Parser;
+    /// use leafc_syntax::SyntaxKind;
     ///
-    /// let mut parser = Parser::new("1 + 2", false);
+    /// let parser = Parser::new("1 + 2", false, &[LanguageKind::English]);
     ///
     /// // Parser has not encountered any errors yet.
     /// assert!(!parser.has_errors());
@@ -468,6 +736,90 @@ impl Parser {
     }
 }
 
+/// A **marker** for a node which has been **started**, but not yet
+/// **completed**, by the [`Parser`].
+///
+/// **NOTE**: A marker must either be [completed][Marker::complete] or
+/// [abandoned][Marker::abandon], dropping it otherwise is a bug.
+#[derive(Debug)]
+pub struct Marker {
+    pos:       u32,
+    completed: bool,
+}
+
+impl Marker {
+    fn new(pos: u32) -> Self {
+        Self { pos, completed: false }
+    }
+
+    /// **Completes** the node, giving it the specified `kind`.
+    pub fn complete(mut self, p: &mut Parser, kind: SyntaxKind) -> CompletedMarker {
+        self.completed = true;
+
+        match &mut p.events[self.pos as usize] {
+            Event::StartNode { kind: slot, .. } => *slot = kind,
+            _ => unreachable!("marker must point to a `StartNode` event"),
+        }
+        p.push_event(Event::FinishNode);
+
+        CompletedMarker::new(self.pos, kind)
+    }
+
+    /// **Abandons** the node, leaving any children in place (i.e. as
+    /// children of the parent node).
+    pub fn abandon(mut self, p: &mut Parser) {
+        self.completed = true;
+
+        if self.pos as usize == p.events.len() - 1 {
+            match p.events.pop() {
+                Some(Event::StartNode { kind: TOMBSTONE, forward_parent: None }) => (),
+                _ => unreachable!("abandoned marker must be a tombstone"),
+            }
+        }
+    }
+}
+
+impl Drop for Marker {
+    fn drop(&mut self) {
+        if !self.completed && !std::thread::panicking() {
+            panic!("marker must be either completed or abandoned");
+        }
+    }
+}
+
+/// A **marker** for a node which has been **completed** by the [`Parser`].
+#[derive(Debug, Clone, Copy)]
+pub struct CompletedMarker {
+    pos:  u32,
+    kind: SyntaxKind,
+}
+
+impl CompletedMarker {
+    fn new(pos: u32, kind: SyntaxKind) -> Self {
+        Self { pos, kind }
+    }
+
+    /// Starts a new node which will become the **parent** of this completed
+    /// node (e.g. wrapping the `lhs` of a binary expression in a `BIN_EXPR`).
+    pub fn precede(self, p: &mut Parser) -> Marker {
+        let new_m = p.start();
+
+        match &mut p.events[self.pos as usize] {
+            Event::StartNode { forward_parent, .. } => {
+                *forward_parent = Some(new_m.pos - self.pos);
+            }
+            _ => unreachable!("marker must point to a `StartNode` event"),
+        }
+
+        new_m
+    }
+
+    /// The **kind** of the completed node.
+    pub fn kind(&self) -> SyntaxKind {
+        self.kind
+    }
+}
+
 #[cfg(test)]
 mod parser_test_suite {
     use super::*;
@@ -475,21 +827,39 @@ mod parser_test_suite {
     #[test]
     fn test_parser_new() {
         let input_str = "1 + 2";
-        let parser = Parser::new(input_str, false);
+        let parser = Parser::new(input_str, false, &[LanguageKind::English]);
 
         assert_eq!(parser.num_tokens(), 3);
         assert_eq!(parser.cursor(), TokenOffset::from(0));
         assert_eq!(parser.num_errors(), 0);
         assert!(!parser.has_errors());
-
-        // while parser.has
     }
 
-    // #[test]
-    // fn test_parser_curr_token() {
-    //     let input_str = "1 + 2";
-    //     let parser = Parser::new(input_str, false);
+    #[test]
+    fn test_parser_skips_trivia() {
+        let parser = Parser::new("  mod // comment\n foo", true, &[LanguageKind::English]);
+
+        assert_eq!(parser.current(), MOD_KW);
+        assert_eq!(parser.nth(1), IDENTIFIER);
+        assert_eq!(parser.nth(2), EOF);
+    }
 
-    //     assert_eq!(parser.curr_token().unwrap().kind, TokenKind::Number);
-    // }
+    #[test]
+    fn test_parser_events() {
+        let mut parser = Parser::new("mod foo;", false, &[LanguageKind::English]);
+
+        let m = parser.start();
+        parser.bump(MOD_KW);
+        assert!(!parser.eat(SEMICOLON));
+        parser.bump_any();
+        assert!(parser.expect(SEMICOLON));
+        m.complete(&mut parser, MODULE);
+
+        assert_eq!(parser.events().len(), 5);
+        assert!(parser.at_end());
+
+        let (green, errors) = parser.finish();
+        assert_eq!(green.to_string(), "modfoo;");
+        assert!(errors.is_empty());
+    }
 }
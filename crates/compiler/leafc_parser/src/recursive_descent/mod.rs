@@ -1,3 +1,5 @@
+mod reparsing;
+
 use std::{
     marker::PhantomData,
     sync::Arc,
@@ -5,6 +7,7 @@ use std::{
 
 use rowan::GreenNode;
 
+use leafc_cfg::lang::LanguageKind;
 use leafc_diagnostics::errors::SyntaxError;
 use leafc_syntax::{
     ast::{
@@ -13,6 +16,9 @@ use leafc_syntax::{
     },
     SyntaxNode,
 };
+use leafc_utils::Spanned;
+
+pub use self::reparsing::TextEdit;
 
 /// `Parse` is the result of the parsing: a syntax tree and a collection of
 /// errors.
@@ -22,7 +28,7 @@ use leafc_syntax::{
 #[derive(Debug, PartialEq, Eq)]
 pub struct Parse<T> {
     green:  GreenNode,
-    errors: Arc<Vec<SyntaxError>>,
+    errors: Arc<Vec<Spanned<SyntaxError>>>,
     _ty:    PhantomData<fn() -> T>,
 }
 
@@ -33,14 +39,16 @@ impl<T> Clone for Parse<T> {
 }
 
 impl<T> Parse<T> {
-    fn new(green: GreenNode, errors: Vec<SyntaxError>) -> Parse<T> {
+    fn new(green: GreenNode, errors: Vec<Spanned<SyntaxError>>) -> Parse<T> {
         Parse { green, errors: Arc::new(errors), _ty: PhantomData }
     }
 
     pub fn syntax_node(&self) -> SyntaxNode {
         SyntaxNode::new_root(self.green.clone())
     }
-    pub fn errors(&self) -> &[SyntaxError] {
+    /// The **syntax errors** encountered while parsing, along with the span of
+    /// the source text each error was reported at.
+    pub fn errors(&self) -> &[Spanned<SyntaxError>] {
         &self.errors
     }
 }
@@ -54,7 +62,7 @@ impl<T: AstNode> Parse<T> {
         T::cast(self.syntax_node()).unwrap()
     }
 
-    pub fn ok(self) -> Result<T, Arc<Vec<SyntaxError>>> {
+    pub fn ok(self) -> Result<T, Arc<Vec<Spanned<SyntaxError>>>> {
         if self.errors.is_empty() {
             Ok(self.tree())
         } else {
@@ -63,32 +71,370 @@ impl<T: AstNode> Parse<T> {
     }
 }
 
-// impl Parse<SourceFile> {
-//     pub fn parse(source_text: &str) -> Parse<SourceFile> {
-//         let (green, errors) = leafc_syntax::parse(source_text);
-//         Parse::new(green, errors)
-//     }
-// }
+impl Parse<SourceFile> {
+    /// Parses the given source text into a [`SourceFile`], along with any
+    /// syntax errors encountered along the way, only recognizing the keywords
+    /// of the given spoken `languages` (see [`crate::parse`]).
+    // `SourceFile` is defined in `leafc_syntax`, which can't depend on the
+    // parser, so its constructor lives on `Parse<SourceFile>` instead.
+    #[allow(clippy::self_named_constructors)]
+    pub fn parse(source_text: &str, languages: &[LanguageKind]) -> Parse<SourceFile> {
+        let (green, errors) = crate::parse(source_text, languages);
+        Parse::new(green, errors)
+    }
+
+    /// Applies the given `edit` to the source text, **reparsing** as little of
+    /// it as possible (i.e. a single token or the smallest enclosing block or
+    /// item list), and falling back to a full parse when needed.
+    ///
+    /// The result is always the same as a full parse of the edited text (with
+    /// the same spoken `languages`), but the unchanged parts of the tree are
+    /// shared with `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the span deleted by the `edit` is out of bounds of the source
+    /// text (or doesn't lie on a `char` boundary).
+    pub fn reparse(&self, edit: TextEdit, languages: &[LanguageKind]) -> Parse<SourceFile> {
+        let root = self.syntax_node();
+        match reparsing::incremental_reparse(&root, &edit, &self.errors, languages) {
+            Some((green, errors)) => Parse::new(green, errors),
+            None => Parse::parse(&edit.apply(&root.text().to_string()), languages),
+        }
+    }
+}
 
 #[cfg(test)]
 mod api_walkthrough {
-    use leafc_syntax::ast::SourceFile;
+    use std::ops::Range;
+
+    use leafc_cfg::lang::LanguageKind;
+    use leafc_syntax::{
+        ast::{
+            operators::RangeOp,
+            traits::{
+                HasAttrs,
+                HasModuleItem,
+                HasName,
+                HasVisibility,
+            },
+            AstNode,
+            ElseBranch,
+            Expr,
+            GenericArgList,
+            Item,
+            Pat,
+            SourceFile,
+            Stmt,
+            TokenTree,
+            Type,
+        },
+        SyntaxKind::{
+            self,
+            *,
+        },
+    };
+
+    use super::Parse;
+
+    /// The spoken **languages** of the (English) examples.
+    const LANGUAGES: &[LanguageKind] = &[LanguageKind::English];
 
     #[test]
     fn parse_source_file() {
         let source_text = "
-        fn foo_bar() {
-            3 + 2
+        pub mod foo_bar {
+            use super::baz::{self, Qux as _};
+        }
+";
+
+        // `Parse` always contains a syntax tree (even for invalid input),
+        // which is lossless (i.e. it round-trips to the original source).
+        let parse = Parse::<SourceFile>::parse(source_text, LANGUAGES);
+        assert!(parse.errors().is_empty());
+
+        let file = parse.tree();
+        assert_eq!(file.syntax().to_string(), source_text);
+
+        let mut items = file.items();
+        let module = match items.next() {
+            Some(Item::Module(module)) => module,
+            item => panic!("expected a module, found {item:?}"),
+        };
+        assert!(items.next().is_none());
+        assert_eq!(module.name().unwrap().syntax().text(), "foo_bar");
+        assert!(module.visibility().is_some());
+    }
+
+    #[test]
+    fn parse_localized_fn() {
+        let source_text = "
+        fonction principale(nom: Chaine) -> i32 {
+            salut := nom
+            0
         }
 ";
 
-        // let parse = leafc_parser::parse(source_text); // a generic parser for
-        // any syntax tree
+        let parse = Parse::<SourceFile>::parse(source_text, &[LanguageKind::French]);
+        assert!(parse.errors().is_empty());
+
+        let func = match parse.tree().items().next() {
+            Some(Item::Fn(func)) => func,
+            item => panic!("expected a function, found {item:?}"),
+        };
+        assert_eq!(func.name().unwrap().syntax().text(), "principale");
+        assert_eq!(func.param_list().unwrap().params().count(), 1);
+        assert!(func.ret_type().is_some());
+
+        let stmt_list = func.body().unwrap().stmt_list().unwrap();
+        assert_eq!(stmt_list.statements().count(), 1);
+        assert_eq!(stmt_list.tail_expr().unwrap().syntax().text(), "0");
+    }
+
+    #[rstest::rstest]
+    #[case(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../../examples/leaf/hello_world/hello_world.leaf")))]
+    #[case(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../../../examples/leaf/hello_world/bonjour_le_monde.leaf")))]
+    fn parse_hello_world_examples(#[case] source_text: &str) {
+        let parse = Parse::<SourceFile>::parse(source_text, &[LanguageKind::Any]);
+        assert!(parse.errors().is_empty());
+        assert_eq!(parse.tree().syntax().to_string(), source_text);
+
+        // The `println!`-style call is the block's tail expression.
+        let func = match parse.tree().items().next() {
+            Some(Item::Fn(func)) => func,
+            item => panic!("expected a function, found {item:?}"),
+        };
+        let call = match func.body().unwrap().stmt_list().unwrap().tail_expr() {
+            Some(Expr::MacroExpr(expr)) => expr.macro_call().unwrap(),
+            expr => panic!("expected a macro call, found {expr:?}"),
+        };
+        assert!(call.path().is_some());
+        assert!(call.token_tree().unwrap().syntax().text().to_string().starts_with("(\""));
+    }
+
+    #[test]
+    fn parse_macro_call_items() {
+        let source_text = "#[cfg(all(unix, not(test)))]\nthread_local! { static X: i32 = 1; \
+                           }\nfmt::include!(\"a.leaf\");";
+
+        let parse = Parse::<SourceFile>::parse(source_text, LANGUAGES);
+        assert!(parse.errors().is_empty());
+
+        let calls = parse
+            .tree()
+            .items()
+            .map(|item| match item {
+                Item::MacroCall(call) => call,
+                item => panic!("expected a macro call, found {item:?}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].attrs().count(), 1);
+        assert_eq!(calls[0].path().unwrap().syntax().text(), "thread_local");
+        assert!(calls[0].semicolon_token().is_none());
+        assert_eq!(calls[1].path().unwrap().syntax().text(), "fmt::include");
+        assert!(calls[1].semicolon_token().is_some());
+
+        // The attribute's arguments are a single token tree, with nested
+        // delimiters parsed as nested token trees.
+        let meta = calls[0].attrs().next().unwrap().meta().unwrap();
+        let token_trees = meta.syntax().descendants().filter_map(TokenTree::cast).count();
+        assert_eq!(token_trees, 3);
+    }
+
+    #[test]
+    fn parse_impl_targets() {
+        let source_text = "impl Shape for Square {}\nimpl Square {}";
+
+        let parse = Parse::<SourceFile>::parse(source_text, LANGUAGES);
+        assert!(parse.errors().is_empty());
+
+        let impls = parse
+            .tree()
+            .items()
+            .map(|item| match item {
+                Item::Impl(impl_) => impl_,
+                item => panic!("expected an impl, found {item:?}"),
+            })
+            .collect::<Vec<_>>();
+
+        let text = |ty: Option<Type>| ty.map(|ty| ty.syntax().to_string());
+        assert_eq!(text(impls[0].trait_()), Some("Shape".into()));
+        assert_eq!(text(impls[0].self_ty()), Some("Square".into()));
+        assert_eq!(text(impls[1].trait_()), None);
+        assert_eq!(text(impls[1].self_ty()), Some("Square".into()));
+    }
+
+    #[test]
+    fn parse_if_else_chain() {
+        let source_text = "fn f() { if x { 1 } else if z { 2 } else { 3 } }";
+
+        let parse = Parse::<SourceFile>::parse(source_text, LANGUAGES);
+        assert!(parse.errors().is_empty());
+
+        let func = match parse.tree().items().next() {
+            Some(Item::Fn(func)) => func,
+            item => panic!("expected a function, found {item:?}"),
+        };
+        let if_expr = match func.body().unwrap().stmt_list().unwrap().tail_expr() {
+            Some(Expr::IfExpr(if_expr)) => if_expr,
+            expr => panic!("expected an if expression, found {expr:?}"),
+        };
+        assert_eq!(if_expr.condition().unwrap().syntax().text(), "x");
+        assert_eq!(if_expr.then_branch().unwrap().syntax().text(), "{ 1 }");
+
+        let elif = match if_expr.else_branch() {
+            Some(ElseBranch::IfExpr(elif)) => elif,
+            branch => panic!("expected an `else if`, found {branch:?}"),
+        };
+        assert_eq!(elif.condition().unwrap().syntax().text(), "z");
+        assert!(matches!(elif.else_branch(), Some(ElseBranch::Block(_))));
+    }
+
+    #[test]
+    fn parse_range_pattern() {
+        let source_text = "fn f() { let 0..=9 = digit }";
+
+        let parse = Parse::<SourceFile>::parse(source_text, LANGUAGES);
+        assert!(parse.errors().is_empty());
+
+        let func = match parse.tree().items().next() {
+            Some(Item::Fn(func)) => func,
+            item => panic!("expected a function, found {item:?}"),
+        };
+        let let_stmt = match func.body().unwrap().stmt_list().unwrap().statements().next() {
+            Some(Stmt::LetStmt(let_stmt)) => let_stmt,
+            stmt => panic!("expected a let statement, found {stmt:?}"),
+        };
+        let range = match let_stmt.pat() {
+            Some(Pat::RangePat(range)) => range,
+            pat => panic!("expected a range pattern, found {pat:?}"),
+        };
+        assert_eq!(range.start().unwrap().syntax().text(), "0");
+        assert_eq!(range.end().unwrap().syntax().text(), "9");
+        assert_eq!(range.op_kind(), Some(RangeOp::Inclusive));
+    }
+
+    // The spellings of other languages aren't keywords in an English file.
+    #[rstest::rstest]
+    #[case("vente")] // Danish `await`
+    #[case("fall")] // German, Swedish `case`
+    #[case("cas")] // French `case`
+    #[case("per")] // Italian `for`
+    #[case("se")] // Portuguese, Italian `if`
+    #[case("em")] // Portuguese `in`
+    #[case("lass")] // German `let`
+    #[case("standard")] // German `default`
+    fn parse_other_languages_keywords_as_identifiers(#[case] spelling: &str) {
+        let source_text = format!("fn f() {{ {spelling} := 1 }}");
+
+        let parse = Parse::<SourceFile>::parse(&source_text, LANGUAGES);
+        assert!(parse.errors().is_empty());
+
+        let func = match parse.tree().items().next() {
+            Some(Item::Fn(func)) => func,
+            item => panic!("expected a function, found {item:?}"),
+        };
+        let let_stmt = match func.body().unwrap().stmt_list().unwrap().statements().next() {
+            Some(Stmt::LetStmt(let_stmt)) => let_stmt,
+            stmt => panic!("expected a let statement, found {stmt:?}"),
+        };
+        let name = match let_stmt.pat() {
+            Some(Pat::IdentPat(pat)) => pat.name().unwrap(),
+            pat => panic!("expected an identifier pattern, found {pat:?}"),
+        };
+        let ident = name.syntax().first_token().unwrap();
+        assert_eq!(ident.kind(), IDENTIFIER);
+        assert_eq!(ident.text(), spelling);
+    }
+
+    #[test]
+    fn parse_nested_generic_args() {
+        let source_text = "type Grid = Vec<Vec<i32>>;";
+
+        // The `>>` closing both argument lists is split into two `>` tokens.
+        let parse = Parse::<SourceFile>::parse(source_text, LANGUAGES);
+        assert!(parse.errors().is_empty());
+        assert_eq!(parse.tree().syntax().to_string(), source_text);
+
+        let r_angles = parse
+            .tree()
+            .syntax()
+            .descendants()
+            .filter_map(GenericArgList::cast)
+            .map(|list| list.r_angle_token().unwrap().text().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(r_angles, [">", ">"]);
+    }
 
-        // let parse = SourceFile::parse(source_text); // a parser for a
-        // specific syntax tree
+    #[test]
+    fn parse_source_file_with_errors() {
+        let source_text = "mod foo use bar;";
+
+        let parse = Parse::<SourceFile>::parse(source_text, LANGUAGES);
+        assert_eq!(parse.errors().len(), 1);
+        assert_eq!(parse.tree().syntax().to_string(), source_text);
+        assert!(parse.ok().is_err());
+    }
+
+    /// Parses `source_text`, checking that the tree round-trips and returning
+    /// the kinds of the top-level nodes along with the source text at the
+    /// span of each error.
+    fn parse_with_recovery(source_text: &str) -> (Vec<SyntaxKind>, Vec<&str>) {
+        let parse = Parse::<SourceFile>::parse(source_text, LANGUAGES);
+        assert_eq!(parse.tree().syntax().to_string(), source_text);
+
+        let kinds = parse.tree().syntax().children().map(|node| node.kind()).collect();
+        let errors = parse
+            .errors()
+            .iter()
+            .map(|error| {
+                let range: Range<usize> = error.span.into();
+                &source_text[range]
+            })
+            .collect();
+        (kinds, errors)
+    }
+
+    #[test]
+    fn recover_from_garbage_between_items() {
+        let (kinds, errors) = parse_with_recovery("fn f() {}\n)) garbage (\nstruct S;");
+
+        // The garbage is wrapped in a single `ERROR` node, and parsing resumes
+        // at the start of the next item.
+        assert_eq!(kinds, [FN, ERROR, STRUCT]);
+        assert_eq!(errors, [")"]);
+    }
+
+    #[test]
+    fn recover_from_garbage_in_block() {
+        let (kinds, errors) =
+            parse_with_recovery("fn f() {\n    let a = 1;\n    ) )\n    let b = 2;\n}");
+        assert_eq!(kinds, [FN]);
+        assert_eq!(errors, [")"]);
+
+        let file =
+            Parse::<SourceFile>::parse("fn f() {\n    ) )\n    let b = 2;\n}", LANGUAGES).tree();
+        let lets = file.syntax().descendants().filter(|node| node.kind() == LET_STMT).count();
+        assert_eq!(lets, 1);
+    }
+
+    #[test]
+    fn recover_from_half_typed_item() {
+        // A parameter list which is still being typed doesn't swallow the
+        // items following it.
+        let (kinds, errors) = parse_with_recovery("fn f(a: i32,\nstruct S { x: i32 }\nfn g() {}");
+        assert_eq!(kinds, [FN, STRUCT, FN]);
+        assert!(!errors.is_empty());
+    }
+
+    #[test]
+    fn report_error_at_end_of_file() {
+        let (kinds, errors) = parse_with_recovery("fn f(");
+        assert_eq!(kinds, [FN]);
 
-        // let parse = leafc_parser::parse::<ast::SourceFile>(source_text); // a
-        // parser for a specific syntax tree
+        // Errors at the end of the file have an empty span.
+        assert!(errors.iter().all(|text| text.is_empty()));
+        assert!(!errors.is_empty());
     }
 }
@@ -0,0 +1,494 @@
+//! **Incremental reparsing** of a [`Parse`] after a [`TextEdit`].
+//!
+//! Rather than re-lexing and re-parsing the whole file, the edit is applied to
+//! the smallest part of the syntax tree which can be reparsed on its own:
+//!
+//! 1. A single **token** (e.g. an identifier, whitespace or a comment), if the
+//!    edited text still lexes as one token of the same kind.
+//! 2. The smallest enclosing **brace-delimited list** (e.g. a block's
+//!    `StmtList` or a module's `ItemList`), if its braces are still balanced.
+//!
+//! The new subtree is swapped into the old (persistent) tree, so the rest of
+//! the tree is shared with the original. If neither applies, the whole file is
+//! parsed again.
+//!
+//! The edited text is lexed with the **keywords** of the file's languages (i.e.
+//! the language it declares, or the given ones), so an edit to the inner
+//! attributes at the start of the file (e.g. `#![lang(fr)]`) always reparses
+//! the whole file.
+
+use std::ops::Range;
+
+use rowan::{
+    GreenNode,
+    GreenToken,
+    NodeOrToken,
+    TextRange,
+    TextSize,
+};
+
+use leafc_cfg::lang::LanguageKind;
+use leafc_diagnostics::errors::SyntaxError;
+use leafc_lexer::{
+    lexer::TokenStream,
+    LanguageChecker,
+};
+use leafc_syntax::{
+    SyntaxKind::{
+        self,
+        *,
+    },
+    SyntaxNode,
+    SyntaxToken,
+    T,
+};
+use leafc_utils::{
+    codemap::Span,
+    Spanned,
+};
+
+use crate::{
+    grammar,
+    Parser,
+};
+
+/// An **edit** of the source text, replacing the text at the `delete` span
+/// with the `insert` text.
+///
+/// An **insertion** has an empty `delete` span, and a **deletion** has an empty
+/// `insert` text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub delete: Span,
+    pub insert: String,
+}
+
+impl TextEdit {
+    /// Creates an edit replacing the text at the `delete` span with `insert`.
+    pub fn replace(delete: Span, insert: impl Into<String>) -> TextEdit {
+        TextEdit { delete, insert: insert.into() }
+    }
+
+    /// Creates an edit inserting `text` at the given `offset`.
+    pub fn insert(offset: usize, text: impl Into<String>) -> TextEdit {
+        TextEdit::replace(Span::new(offset..offset), text)
+    }
+
+    /// Creates an edit deleting the text at the given `span`.
+    pub fn delete(span: Span) -> TextEdit {
+        TextEdit::replace(span, String::new())
+    }
+
+    /// Applies the edit to the given `text`.
+    pub fn apply(&self, text: &str) -> String {
+        let Range { start, end } = self.delete.into();
+        let mut text = text.to_string();
+        text.replace_range(start..end, &self.insert);
+        text
+    }
+
+    /// The change in the length of the text caused by the edit.
+    fn delta(&self) -> isize {
+        self.insert.len() as isize - self.delete.len() as isize
+    }
+}
+
+/// Tries to **reparse** the tree rooted at `root` after the given `edit`,
+/// returning the new root along with its syntax errors (`errors` being those of
+/// the old tree), where only the keywords of the given spoken `languages` are
+/// recognized.
+///
+/// Returns `None` if a full parse is required instead.
+pub(super) fn incremental_reparse(
+    root: &SyntaxNode,
+    edit: &TextEdit,
+    errors: &[Spanned<SyntaxError>],
+    languages: &[LanguageKind],
+) -> Option<(GreenNode, Vec<Spanned<SyntaxError>>)> {
+    let languages = active_languages(root, edit, languages)?;
+
+    if let Some((green, old_range)) = reparse_token(root, edit, &languages) {
+        return Some((green, shift_errors(errors, old_range, edit.delta(), Vec::new(), true)));
+    }
+
+    let (green, new_errors, old_range) = reparse_block(root, edit, &languages)?;
+    Some((green, shift_errors(errors, old_range, edit.delta(), new_errors, false)))
+}
+
+/// Returns the spoken **languages** whose keywords are recognized within the
+/// file rooted at `root` (see [`LanguageChecker::active_languages`]).
+///
+/// Returns `None` if the edit isn't past the **inner attributes** at the start
+/// of the file, which may declare its language.
+fn active_languages(
+    root: &SyntaxNode,
+    edit: &TextEdit,
+    languages: &[LanguageKind],
+) -> Option<Vec<LanguageKind>> {
+    let attrs_end = root
+        .children()
+        .take_while(|node| node.kind() == ATTR)
+        .last()
+        .map_or(0, |attr| node_range(&attr).end);
+    let Range { start, .. } = edit.delete.into();
+    if start <= attrs_end {
+        return None;
+    }
+
+    let attrs = TokenStream::new(&root.text().slice(text_range(0..attrs_end)).to_string(), false);
+    Some(LanguageChecker::new(languages).active_languages(attrs.tokens()))
+}
+
+/// Re-lexes the single token covering the edit, if the edited text remains a
+/// token of the same kind (e.g. renaming an identifier).
+///
+/// Returns the new root and the span of the old token.
+fn reparse_token(
+    root: &SyntaxNode,
+    edit: &TextEdit,
+    languages: &[LanguageKind],
+) -> Option<(GreenNode, Range<usize>)> {
+    let token = match root.covering_element(text_range(edit.delete.into())) {
+        NodeOrToken::Token(token) => token,
+        NodeOrToken::Node(_) => return None,
+    };
+
+    let kind = token.kind();
+    if !matches!(kind, IDENTIFIER | WHITESPACE | COMMENT | DOC_COMMENT | STRING | RAW_STRING) {
+        return None;
+    }
+
+    // The errors within a token (e.g. an invalid escape in a string) can't be
+    // moved along with the edit, so they are found again by a full reparse.
+    if lex_single_token(token.text(), languages) != Some(kind) {
+        return None;
+    }
+
+    let old_range = token_range(&token);
+    let Range { start, end } = edit.delete.into();
+
+    // Newlines are significant to error recovery (see
+    // `Parser::at_line_start`) and to which item a comment is attached to, so
+    // adding or removing one always requires reparsing its surroundings.
+    if kind.is_trivia() &&
+        (token.text()[start - old_range.start..end - old_range.start].contains('\n') ||
+            edit.insert.contains('\n'))
+    {
+        return None;
+    }
+
+    let mut new_text = token.text().to_string();
+    new_text.replace_range(start - old_range.start..end - old_range.start, &edit.insert);
+    if lex_single_token(&new_text, languages) != Some(kind) {
+        return None;
+    }
+
+    // The edited token mustn't merge with its neighbours (e.g. an identifier
+    // `b` followed by a string becoming the byte string `b"str"`).
+    let prev_char = token.prev_token().and_then(|prev| prev.text().chars().next_back());
+    let next_char = token.next_token().and_then(|next| next.text().chars().next());
+    let is_token = |text: String| lex_single_token(&text, languages).is_some();
+    if prev_char.map_or(false, |c| is_token(format!("{c}{new_text}"))) ||
+        next_char.map_or(false, |c| is_token(format!("{new_text}{c}")))
+    {
+        return None;
+    }
+
+    let green = token.replace_with(GreenToken::new(rowan::SyntaxKind(kind.into()), &new_text));
+    Some((green, old_range))
+}
+
+/// Reparses the smallest **brace-delimited list** enclosing the edit.
+///
+/// Returns the new root, the syntax errors of the reparsed list (relative to
+/// the start of the file) and the span of the old list.
+fn reparse_block(
+    root: &SyntaxNode,
+    edit: &TextEdit,
+    languages: &[LanguageKind],
+) -> Option<(GreenNode, Vec<Spanned<SyntaxError>>, Range<usize>)> {
+    let Range { start, end } = edit.delete.into();
+    let (node, reparser) = root
+        .covering_element(text_range(start..end))
+        .ancestors()
+        .filter(|node| {
+            // The edit must leave the list's braces alone.
+            let range = node_range(node);
+            range.start < start && end < range.end
+        })
+        .find_map(|node| Some((node.clone(), grammar::reparser(node.kind())?)))?;
+
+    let old_range = node_range(&node);
+    let mut text = node.text().to_string();
+    text.replace_range(start - old_range.start..end - old_range.start, &edit.insert);
+    if !is_balanced(&text) {
+        return None;
+    }
+
+    let mut p = Parser::new(&text, true, languages);
+    reparser(&mut p);
+    if !p.at(EOF) {
+        return None;
+    }
+
+    // The closing brace must still close the list itself (rather than e.g. an
+    // unclosed `(` within it, which continues past the list in the full file).
+    let (green, errors) = p.finish();
+    match green.children().last() {
+        Some(NodeOrToken::Token(token)) if token.kind() == rowan::SyntaxKind(T!['}'].into()) => (),
+        _ => return None,
+    }
+
+    let errors = errors
+        .into_iter()
+        .map(|Spanned { span, node }| {
+            let Range { start, end } = span.into();
+            Spanned { span: Span::new(start + old_range.start..end + old_range.start), node }
+        })
+        .collect();
+
+    Some((node.replace_with(green), errors, old_range))
+}
+
+/// Updates the `errors` of the old tree after the text at `old_range` has been
+/// reparsed, shifting the errors following it by `delta`.
+///
+/// Errors reported **within** the range are replaced by `new_errors` when
+/// reparsing a node. When reparsing a token, the only such error is one
+/// reported at the token itself, which is resized instead.
+fn shift_errors(
+    errors: &[Spanned<SyntaxError>],
+    old_range: Range<usize>,
+    delta: isize,
+    mut new_errors: Vec<Spanned<SyntaxError>>,
+    is_token: bool,
+) -> Vec<Spanned<SyntaxError>> {
+    let shift = |offset: usize| (offset as isize + delta) as usize;
+
+    let mut res = Vec::with_capacity(errors.len() + new_errors.len());
+    for error in errors {
+        let Range { start, end } = error.span.into();
+        let span = if start >= old_range.end {
+            Span::new(shift(start)..shift(end))
+        } else if is_token && start >= old_range.start {
+            Span::new(start..shift(end))
+        } else if start > old_range.start {
+            // Replaced by the errors of the reparsed node (an error reported at
+            // its opening brace belongs to the parent).
+            continue;
+        } else {
+            error.span
+        };
+
+        if start >= old_range.end {
+            res.append(&mut new_errors);
+        }
+        res.push(Spanned { span, node: error.node.clone() });
+    }
+
+    res.append(&mut new_errors);
+    res
+}
+
+/// Returns `true` if the non-trivia tokens of `text` are a single `{ .. }`
+/// with balanced braces.
+fn is_balanced(text: &str) -> bool {
+    let tokens = TokenStream::new(text, false);
+    let mut kinds = tokens.tokens().iter().map(|token| SyntaxKind::from(*token.kind()));
+    if kinds.next() != Some(T!['{']) {
+        return false;
+    }
+
+    let mut depth = 1;
+    for kind in kinds {
+        if depth == 0 {
+            return false;
+        }
+        match kind {
+            T!['{'] => depth += 1,
+            T!['}'] => depth -= 1,
+            _ => (),
+        }
+    }
+    depth == 0
+}
+
+/// Returns the kind of the token `text` lexes as (only recognizing the keywords
+/// of the given spoken `languages`), if it is exactly one token.
+fn lex_single_token(text: &str, languages: &[LanguageKind]) -> Option<SyntaxKind> {
+    let tokens = TokenStream::with_languages(text, true, languages);
+    // A token with a lexical error (e.g. an unterminated block comment) could
+    // swallow the tokens after it, so it needs a full reparse.
+    if !tokens.errors().is_empty() {
+        return None;
+    }
+
+    match tokens.tokens().iter().collect::<Vec<_>>()[..] {
+        [token] => Some(SyntaxKind::from(*token.kind())),
+        _ => None,
+    }
+}
+
+fn node_range(node: &SyntaxNode) -> Range<usize> {
+    let range = node.text_range();
+    range.start().into()..range.end().into()
+}
+
+fn token_range(token: &SyntaxToken) -> Range<usize> {
+    let range = token.text_range();
+    range.start().into()..range.end().into()
+}
+
+fn text_range(Range { start, end }: Range<usize>) -> TextRange {
+    TextRange::new(to_text_size(start), to_text_size(end))
+}
+
+fn to_text_size(offset: usize) -> TextSize {
+    TextSize::try_from(offset).expect("source text is too large")
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions_sorted::assert_eq;
+    use rstest::rstest;
+
+    use leafc_syntax::ast::SourceFile;
+
+    use super::*;
+    use crate::Parse;
+
+    /// Which part of the tree an edit is expected to reparse.
+    #[derive(Debug, PartialEq, Eq)]
+    enum Reparsed {
+        Token,
+        Block(SyntaxKind),
+        File,
+    }
+
+    /// The spoken **languages** of the files being edited.
+    const LANGUAGES: &[LanguageKind] = &[LanguageKind::English];
+
+    /// Applies the edit marked in `before` (i.e. replacing the text between
+    /// `<|` and `|>` with `insert`), checking that reparsing gives the same
+    /// tree and errors as a full parse of the edited text.
+    fn check(before: &str, insert: &str, expected: Reparsed) {
+        let start = before.find("<|").unwrap();
+        let end = before.find("|>").unwrap() - 2;
+        let text = before.replace("<|", "").replace("|>", "");
+        let edit = TextEdit::replace(Span::new(start..end), insert);
+
+        let parse = Parse::<SourceFile>::parse(&text, LANGUAGES);
+        let root = parse.syntax_node();
+        let reparsed = match active_languages(&root, &edit, LANGUAGES) {
+            Some(languages) if reparse_token(&root, &edit, &languages).is_some() => Reparsed::Token,
+            Some(languages) => match reparse_block(&root, &edit, &languages) {
+                Some((.., old_range)) => {
+                    Reparsed::Block(root.covering_element(text_range(old_range)).kind())
+                }
+                None => Reparsed::File,
+            },
+            None => Reparsed::File,
+        };
+        assert_eq!(reparsed, expected);
+
+        let incremental = parse.reparse(edit.clone(), LANGUAGES);
+        let full = Parse::<SourceFile>::parse(&edit.apply(&text), LANGUAGES);
+        assert_eq!(
+            format!("{:#?}", incremental.syntax_node()),
+            format!("{:#?}", full.syntax_node())
+        );
+        assert_eq!(incremental.errors(), full.errors());
+    }
+
+    #[rstest]
+    #[case("fn foo() { let x = <|bar|> + 1 }", "baz_qux")]
+    #[case("fn f() {\n    // a <|comment|>\n    g()\n}", "longer comment")]
+    #[case("fn f() { /* a /* <|nested|> */ comment */ g() }", "longer")]
+    #[case("fn f() {<| |>  g() }", "\t")]
+    #[case("fn f() { g(\"<|hello|>\") }", "bonjour le monde")]
+    // The keywords of other languages are identifiers (e.g. the Danish `vente`).
+    #[case("fn f() { <|ven|> := 1 }", "vente")]
+    #[case("#![lang(fr)]\nfonction f() { <|x|> := 1 }", "while")]
+    #[case("fn f( { <|a|> } fn g() {}", "abc")]
+    #[case("fn f() { <|a|> }\n)) garbage (", "xyz")]
+    fn reparse_single_token(#[case] before: &str, #[case] insert: &str) {
+        check(before, insert, Reparsed::Token);
+    }
+
+    #[rstest]
+    #[case("fn f() {\n    let x = <|1|>;\n}", "1 + 2", STMT_LIST)]
+    #[case("fn f() { if x { <|1|> } else { 2 } }", "g(1, 2)", STMT_LIST)]
+    #[case("fn f() { g(<||>) }", "1, ", STMT_LIST)]
+    #[case("fn f() { let x = 1<||> }", ";\n    let y = ", STMT_LIST)]
+    #[case("fn f() { match x { 1 => <|a|>, _ => b } }", "{ a }", MATCH_ARM_LIST)]
+    #[case("mod m { fn <|f|>() {} }", "struct S;", ITEM_LIST)]
+    #[case("impl S {\n    fn f() {}<||>\n}", "\n    type T = i32;", ASSOC_ITEM_LIST)]
+    #[case("enum E { A, <|B|> }", "B(i32), C", VARIANT_LIST)]
+    #[case("struct S { x: <|i32|> }", "Vec<i32>", RECORD_FIELD_LIST)]
+    #[case("fn f() { ) <|)|> }\nfn g() { ) }", "", STMT_LIST)]
+    #[case("fn f() { let x = <|1|>; }\nfn g(", "(", STMT_LIST)]
+    // The old or new string has an invalid escape.
+    #[case("fn f() { g(\"a<|\\q|>b\") }", "\\n", STMT_LIST)]
+    #[case("fn f() { g(\"a<||>b\") }", "\\u{110000}", STMT_LIST)]
+    // An identifier becomes a keyword of the language the file declares.
+    #[case("#![lang(fr)]\nfonction f() { <|x|> := 1 }", "pour", STMT_LIST)]
+    fn reparse_enclosing_block(
+        #[case] before: &str,
+        #[case] insert: &str,
+        #[case] kind: SyntaxKind,
+    ) {
+        check(before, insert, Reparsed::Block(kind));
+    }
+
+    #[rstest]
+    // The braces of the enclosing block are no longer balanced.
+    #[case("fn f() { <||> }\nfn g() {}", "{")]
+    #[case("fn f() { g() <|}|>\nfn g() {}", "")]
+    // The edit isn't inside of a block.
+    #[case("fn <|f|>() {}", "fn")]
+    #[case("struct S<||>;", " { x: i32 }")]
+    #[case("<||>", "fn f() {}")]
+    // An identifier becomes a keyword.
+    #[case("mod <|m|>;", "fn")]
+    // The language the file declares changes.
+    #[case("#![lang(<|fr|>)]\nfonction f() {}", "en")]
+    #[case("fn f() { /* a <|b|> */ g() }\nfn g() {}", "/*")]
+    fn reparse_whole_file(#[case] before: &str, #[case] insert: &str) {
+        check(before, insert, Reparsed::File);
+    }
+
+    #[test]
+    fn reparse_every_position() {
+        let text =
+            "mod m {\n    /* doc */\n    fn f(a: i32) -> i32 {\n        let b = a + 1;\n        \
+             match b { 0 => g(\"s\"), _ => { b } }\n    }\n}\nstruct S { x: i32 }\nenum E { A, \
+             B(i32) }\n";
+        let parse = Parse::<SourceFile>::parse(text, LANGUAGES);
+
+        // Every single character edit gives the same result as a full parse.
+        for (offset, c) in text.char_indices() {
+            let mut edits = vec![TextEdit::delete(Span::new(offset..offset + c.len_utf8()))];
+            for insert in ["x", " ", "\n", ";", "{", "}", "(", "\"", "/", "*"] {
+                edits.push(TextEdit::insert(offset, insert));
+            }
+
+            for edit in edits {
+                let incremental = parse.reparse(edit.clone(), LANGUAGES);
+                let full = Parse::<SourceFile>::parse(&edit.apply(text), LANGUAGES);
+                assert_eq!(
+                    format!("{:#?}", incremental.syntax_node()),
+                    format!("{:#?}", full.syntax_node()),
+                    "{edit:?}"
+                );
+                assert_eq!(incremental.errors(), full.errors(), "{edit:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn reparse_newline_in_whitespace() {
+        // Newlines affect which item a comment is attached to, so inserting one
+        // reparses the enclosing block.
+        check("mod m {\n    // doc\n<||>    fn f() {}\n}", "\n", Reparsed::Block(ITEM_LIST));
+    }
+}
@@ -0,0 +1,73 @@
+//! A compact **set** of [`SyntaxKind`]s, used by the grammar to describe which
+//! tokens may start a construct, and where to **recover** after an error.
+
+use leafc_syntax::SyntaxKind;
+
+/// A **bit set** of [`SyntaxKind`]s, which can be built in a `const` context.
+///
+/// # Example:
+///
+/// ```rust
+/// use leafc_parser::token_set::TokenSet;
+/// use leafc_syntax::SyntaxKind;
+///
+/// const BRACES: TokenSet = TokenSet::new(&[SyntaxKind::L_BRACE, SyntaxKind::R_BRACE]);
+///
+/// assert!(BRACES.contains(SyntaxKind::L_BRACE));
+/// assert!(!BRACES.contains(SyntaxKind::L_PAREN));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenSet([u64; WORDS]);
+
+/// The number of **words** in the bit set.
+const WORDS: usize = 5;
+
+// Every `SyntaxKind` must fit in the bit set.
+const _: () = assert!((SyntaxKind::__LAST as usize) < WORDS * 64);
+
+impl TokenSet {
+    /// The **empty** set.
+    pub const EMPTY: TokenSet = TokenSet([0; WORDS]);
+
+    /// Creates a new set containing the given `kinds`.
+    pub const fn new(kinds: &[SyntaxKind]) -> TokenSet {
+        let mut res = [0; WORDS];
+        let mut i = 0;
+        while i < kinds.len() {
+            let kind = kinds[i] as usize;
+            res[kind / 64] |= 1 << (kind % 64);
+            i += 1;
+        }
+        TokenSet(res)
+    }
+
+    /// Returns the **union** of both sets.
+    pub const fn union(self, other: TokenSet) -> TokenSet {
+        let mut res = [0; WORDS];
+        let mut i = 0;
+        while i < res.len() {
+            res[i] = self.0[i] | other.0[i];
+            i += 1;
+        }
+        TokenSet(res)
+    }
+
+    /// Returns `true` if the set contains the given `kind`.
+    pub const fn contains(&self, kind: SyntaxKind) -> bool {
+        let kind = kind as usize;
+        self.0[kind / 64] & (1 << (kind % 64)) != 0
+    }
+}
+
+#[test]
+fn token_set_works_for_all_kinds() {
+    let set = TokenSet::new(&[SyntaxKind::TOMBSTONE, SyntaxKind::EOF, SyntaxKind::L_BRACE]);
+    assert!(set.contains(SyntaxKind::TOMBSTONE));
+    assert!(set.contains(SyntaxKind::EOF));
+    assert!(set.contains(SyntaxKind::L_BRACE));
+    assert!(!set.contains(SyntaxKind::R_BRACE));
+
+    let set = set.union(TokenSet::new(&[SyntaxKind::R_BRACE]));
+    assert!(set.contains(SyntaxKind::R_BRACE));
+    assert!(!TokenSet::EMPTY.contains(SyntaxKind::R_BRACE));
+}
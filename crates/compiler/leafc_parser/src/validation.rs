@@ -0,0 +1,94 @@
+//! **Validation** of a syntax tree once it's been built, which finds the errors
+//! that depend on more than a single token (and so can't be found by the
+//! lexer).
+//!
+//! The only such error is a signed integer which is one past its type's maximum
+//! (e.g. `128i8`), which the lexer accepts as it may be **negated** (i.e.
+//! `-128i8`, see [`NumericValue::check_negated`]).
+//!
+//! [`NumericValue::check_negated`]: leafc_lexer::number::NumericValue::check_negated
+
+use leafc_diagnostics::errors::SyntaxError;
+use leafc_lexer::{
+    number,
+    TokenKind,
+};
+use leafc_syntax::{
+    ast::{
+        self,
+        AstNode,
+    },
+    SyntaxKind,
+    SyntaxNode,
+};
+use leafc_utils::{
+    Span,
+    Spanned,
+};
+
+/// Returns the syntax **errors** in the tree rooted at `root` which the lexer
+/// and parser can't find on their own.
+pub(crate) fn validate(root: &SyntaxNode) -> Vec<Spanned<SyntaxError>> {
+    root.descendants().filter_map(ast::Literal::cast).filter_map(validate_literal).collect()
+}
+
+/// Checks that an integer `literal` fits in its type, now that whether it's
+/// negated is known.
+fn validate_literal(literal: ast::Literal) -> Option<Spanned<SyntaxError>> {
+    let token = literal.token();
+    if token.kind() != SyntaxKind::INTEGER {
+        return None;
+    }
+
+    // Any other error in the literal has been reported by the lexer already.
+    let value = number::parse_number(TokenKind::INTEGER, token.text()).ok()?;
+    let error = value.check_negated(literal.is_negated()).err()?;
+    let range = token.text_range();
+    Some(Spanned {
+        span: Span::new(usize::from(range.start())..usize::from(range.end())),
+        node: SyntaxError::InvalidNumber(error),
+    })
+}
+
+#[cfg(test)]
+mod validation_test_suite {
+    use leafc_cfg::lang::LanguageKind;
+    use leafc_diagnostics::errors::{
+        NumberError,
+        SyntaxError,
+    };
+    use pretty_assertions_sorted::assert_eq;
+    use rstest::rstest;
+
+    /// The spoken **languages** whose keywords are recognized.
+    const LANGUAGES: &[LanguageKind] = &[LanguageKind::English];
+
+    #[rstest]
+    #[case("x := -128i8;", &[])]
+    #[case("x := 128i8;", &[(5..10, "i8")])]
+    #[case("x := -9223372036854775808i64;", &[])]
+    #[case("x := 9223372036854775808i64;", &[(5..27, "i64")])]
+    #[case("x := -(128i8);", &[(7..12, "i8")])]
+    #[case("x := f(-0x80_i8, 0x80_i8);", &[(17..24, "i8")])]
+    #[case("x := - 2147483648i32 + 1;", &[])]
+    #[case("x := -128u8;", &[])]
+    fn test_negated_integers(
+        #[case] input: &str,
+        #[case] expected: &[(std::ops::Range<usize>, &'static str)],
+    ) {
+        let (_, errors) = crate::parse(&format!("fn f() {{ {input} }}"), LANGUAGES);
+        let errors = errors
+            .into_iter()
+            .map(|error| (error.span.into(), error.node))
+            .collect::<Vec<(std::ops::Range<usize>, _)>>();
+        let offset = "fn f() { ".len();
+        let expected = expected
+            .iter()
+            .map(|(range, ty)| {
+                let range = range.start + offset..range.end + offset;
+                (range, SyntaxError::InvalidNumber(NumberError::IntegerOutOfRange(ty)))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(errors, expected);
+    }
+}
@@ -0,0 +1,212 @@
+//! Property tests of the parser over **random programs**, generated from the
+//! productions of `leaf.ungram`.
+//!
+//! The programs are only _roughly_ valid (the un-grammar describes the shape of
+//! the syntax tree rather than how it's parsed), which makes them good at
+//! exercising both the happy paths and the error recovery of the parser. Each
+//! program is generated from its own seed, so a failure can be reproduced from
+//! the seed alone.
+
+use std::{
+    collections::HashMap,
+    panic,
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+
+use leafc_cfg::lang::LanguageKind;
+use leafc_lexer::lossless_lex;
+use leafc_parser::Parse;
+use leafc_syntax::ast::SourceFile;
+use pretty_assertions_sorted::assert_eq;
+use rand::{
+    rngs::StdRng,
+    seq::SliceRandom,
+    Rng,
+    SeedableRng,
+};
+use ungrammar::{
+    Grammar,
+    Node,
+    Rule,
+};
+
+/// The number of random programs to check.
+const ITERATIONS: u64 = 500;
+
+/// The depth of nested nodes after which the generator only picks the
+/// **shortest** alternatives, so the programs stay small.
+const MAX_DEPTH: usize = 8;
+
+/// How long parsing a single program may take before it's considered stuck
+/// (e.g. in a loop which never consumes a token).
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The spoken **languages** whose keywords are recognized (i.e. all of them).
+const LANGUAGES: &[LanguageKind] = &[LanguageKind::Any];
+
+fn grammar() -> Grammar {
+    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../leafc_syntax/src/ungrammar/leaf.ungram"))
+        .parse()
+        .unwrap()
+}
+
+/// Generates random programs from the productions of an un-grammar.
+struct ProgramGen<'g> {
+    grammar: &'g Grammar,
+    /// The **fewest** number of tokens each node can be made of.
+    min_len: HashMap<Node, usize>,
+}
+
+impl<'g> ProgramGen<'g> {
+    fn new(grammar: &'g Grammar) -> Self {
+        let mut gen = ProgramGen { grammar, min_len: HashMap::new() };
+
+        // Iterate to a fixpoint, as the nodes are mutually recursive.
+        loop {
+            let mut changed = false;
+            for node in grammar.iter() {
+                let len = gen.rule_min_len(&grammar[node].rule);
+                if gen.min_len.get(&node).map_or(true, |&old| len < old) {
+                    gen.min_len.insert(node, len);
+                    changed = true;
+                }
+            }
+            if !changed {
+                break gen;
+            }
+        }
+    }
+
+    fn rule_min_len(&self, rule: &Rule) -> usize {
+        match rule {
+            Rule::Labeled { rule, .. } => self.rule_min_len(rule),
+            Rule::Node(node) => self.min_len.get(node).copied().unwrap_or(usize::MAX / 4),
+            Rule::Token(_) => 1,
+            Rule::Seq(rules) => rules.iter().map(|rule| self.rule_min_len(rule)).sum(),
+            Rule::Alt(rules) => rules.iter().map(|rule| self.rule_min_len(rule)).min().unwrap(),
+            Rule::Opt(_) | Rule::Rep(_) => 0,
+        }
+    }
+
+    /// Generates the tokens of a random `SourceFile`.
+    fn source_file(&self, rng: &mut StdRng) -> Vec<String> {
+        let source_file = self.grammar.iter().find(|&node| self.grammar[node].name == "SourceFile");
+        let mut tokens = Vec::new();
+        self.generate(&Rule::Node(source_file.unwrap()), 0, rng, &mut tokens);
+        tokens
+    }
+
+    fn generate(&self, rule: &Rule, depth: usize, rng: &mut StdRng, tokens: &mut Vec<String>) {
+        let shallow = depth < MAX_DEPTH;
+        match rule {
+            Rule::Labeled { rule, .. } => self.generate(rule, depth, rng, tokens),
+            Rule::Node(node) => self.generate(&self.grammar[*node].rule, depth + 1, rng, tokens),
+            Rule::Token(token) => tokens.push(token_text(&self.grammar[*token].name, rng)),
+            Rule::Seq(rules) => {
+                for rule in rules {
+                    self.generate(rule, depth, rng, tokens);
+                }
+            }
+            Rule::Alt(rules) => {
+                let rule = if shallow {
+                    rules.choose(rng).unwrap()
+                } else {
+                    rules.iter().min_by_key(|rule| self.rule_min_len(rule)).unwrap()
+                };
+                self.generate(rule, depth, rng, tokens);
+            }
+            Rule::Opt(rule) => {
+                if shallow && rng.gen_bool(0.5) {
+                    self.generate(rule, depth, rng, tokens);
+                }
+            }
+            Rule::Rep(rule) => {
+                for _ in 0..if shallow { rng.gen_range(0..3) } else { 0 } {
+                    self.generate(rule, depth, rng, tokens);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the text of a token named `name` in the un-grammar (e.g. a random
+/// identifier for `'ident'`).
+fn token_text(name: &str, rng: &mut StdRng) -> String {
+    let choices: &[&str] = match name {
+        "ident" => &["foo", "bar", "x", "Vec", "i32", "ligne_d'impression", "🦀"],
+        "lifetime" => &["'a", "'static"],
+        "integer" | "int_number" => &["0", "42", "0x1F", "1_000u32"],
+        "float" => &["1.5", "2.0e10", ".5"],
+        "rune" => &["'c'", "'\\n'"],
+        "string" => &["\"\"", "\"s\"", "\"\\\"\""],
+        "raw_string" => &["r#\"s\"#"],
+        name => return name.to_string(),
+    };
+    choices.choose(rng).unwrap().to_string()
+}
+
+/// Joins the `tokens` with random trivia in between (including none at all,
+/// so neighbouring tokens may run together).
+fn join_tokens(tokens: &[String], rng: &mut StdRng) -> String {
+    let mut text = String::new();
+    for token in tokens {
+        text.push_str(token);
+        text.push_str(
+            [" ", " ", " ", "", "\n", "\n\n", "\t", " // comment\n", " /* comment */ "]
+                .choose(rng)
+                .unwrap(),
+        );
+    }
+    text
+}
+
+/// Checks that lexing and parsing `text` neither panics nor gets stuck, and
+/// that both are **lossless** (i.e. the tokens and the syntax tree give back
+/// the original `text`).
+fn check_round_trip(seed: u64, text: String) {
+    let tokens = lossless_lex(&text);
+    let lexemes = tokens.tokens().iter().map(|token| token.lexeme().as_str()).collect::<String>();
+    assert_eq!(lexemes, text, "seed {seed}");
+
+    let (sender, receiver) = mpsc::channel();
+    let input = text.clone();
+    thread::spawn(move || {
+        let parse =
+            panic::catch_unwind(|| Parse::<SourceFile>::parse(&input, LANGUAGES).to_syntax());
+        sender.send(parse.map(|parse| parse.syntax_node().to_string())).unwrap();
+    });
+
+    match receiver.recv_timeout(TIMEOUT) {
+        Ok(Ok(tree_text)) => assert_eq!(tree_text, text, "seed {seed}"),
+        Ok(Err(_)) => panic!("the parser panicked on {text:?} (seed {seed})"),
+        Err(_) => panic!("the parser got stuck on {text:?} (seed {seed})"),
+    }
+}
+
+#[test]
+fn parse_random_programs() {
+    let grammar = grammar();
+    let gen = ProgramGen::new(&grammar);
+
+    for seed in 0..ITERATIONS {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let tokens = gen.source_file(&mut rng);
+        check_round_trip(seed, join_tokens(&tokens, &mut rng));
+    }
+}
+
+#[test]
+fn parse_random_token_streams() {
+    let grammar = grammar();
+    let names = grammar.tokens().map(|token| grammar[token].name.clone()).collect::<Vec<_>>();
+
+    for seed in 0..ITERATIONS {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let tokens = (0..rng.gen_range(0..64))
+            .map(|_| token_text(names.choose(&mut rng).unwrap(), &mut rng))
+            .collect::<Vec<_>>();
+        check_round_trip(seed, join_tokens(&tokens, &mut rng));
+    }
+}
@@ -0,0 +1,104 @@
+//! Snapshot tests of the parser over the **corpus** in `tests/data/parser`.
+//!
+//! Each `.leaf` file is parsed, and the debug dump of its syntax tree (followed
+//! by its errors) is compared against the `.rast` file next to it. Programs in
+//! `ok/` must parse without any errors, while programs in `err/` must have at
+//! least one.
+//!
+//! Run the tests with `UPDATE_SNAPSHOTS=1` to (re)write the `.rast` files
+//! instead, e.g. after adding a new program or changing the parser on purpose.
+
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use leafc_cfg::lang::LanguageKind;
+use leafc_parser::Parse;
+use leafc_syntax::ast::SourceFile;
+use pretty_assertions_sorted::assert_eq;
+
+/// The environment variable which makes the tests update the snapshots.
+const UPDATE_SNAPSHOTS: &str = "UPDATE_SNAPSHOTS";
+
+/// The spoken **languages** of the programs (which aren't all written in
+/// English).
+const LANGUAGES: &[LanguageKind] = &[LanguageKind::Any];
+
+fn data_dir(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data/parser").join(name)
+}
+
+/// Returns the `.leaf` files in `dir`, in order.
+fn leaf_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = fs::read_dir(dir)
+        .unwrap_or_else(|err| panic!("can't read {}: {err}", dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().map_or(false, |ext| ext == "leaf"))
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+
+/// Dumps the syntax tree of `parse`, followed by one line for each error.
+fn dump(parse: &Parse<SourceFile>) -> String {
+    let mut dump = format!("{:#?}", parse.syntax_node());
+    for error in parse.errors() {
+        let range: std::ops::Range<usize> = error.span.into();
+        writeln!(dump, "error {range:?}: {:?}", error.node).unwrap();
+    }
+    dump
+}
+
+/// Checks every program in `dir` against its snapshot, and that it has errors
+/// if (and only if) `expect_errors` is set.
+fn check_dir(dir: &str, expect_errors: bool) {
+    let update = env::var_os(UPDATE_SNAPSHOTS).is_some();
+    let files = leaf_files(&data_dir(dir));
+    assert!(!files.is_empty(), "no programs in tests/data/parser/{dir}");
+
+    for file in files {
+        let text = fs::read_to_string(&file).unwrap();
+        let parse = Parse::<SourceFile>::parse(&text, LANGUAGES);
+        let name = file.file_name().unwrap().to_string_lossy();
+
+        assert_eq!(
+            !parse.errors().is_empty(),
+            expect_errors,
+            "{dir}/{name} has errors {:?}",
+            parse.errors()
+        );
+
+        let actual = dump(&parse);
+        let snapshot = file.with_extension("rast");
+        if update {
+            fs::write(&snapshot, &actual).unwrap();
+            continue;
+        }
+
+        let expected = fs::read_to_string(&snapshot).unwrap_or_else(|_| {
+            panic!("{dir}/{name} has no snapshot, run the tests with {UPDATE_SNAPSHOTS}=1")
+        });
+        assert_eq!(
+            expected.replace("\r\n", "\n"),
+            actual,
+            "{dir}/{name} doesn't match its snapshot, run the tests with {UPDATE_SNAPSHOTS}=1 if \
+             the change is intended"
+        );
+    }
+}
+
+#[test]
+fn parse_ok_programs() {
+    check_dir("ok", false);
+}
+
+#[test]
+fn parse_err_programs() {
+    check_dir("err", true);
+}
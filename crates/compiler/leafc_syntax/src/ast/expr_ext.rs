@@ -0,0 +1,423 @@
+//! Various extension methods to **expressions** which can't be generated from
+//! `leaf.ungram` (e.g. the labeled `lhs`, `op` and `rhs` of a `BinExpr`).
+
+use leafc_lexer::{
+    number::{
+        self,
+        NumericValue,
+    },
+    TokenKind,
+};
+use rowan::NodeOrToken;
+
+use crate::{
+    ast::{
+        self,
+        operators::{
+            ArithOp,
+            BinaryOp,
+            CmpOp,
+            LogicOp,
+            Ordering,
+            RangeOp,
+            SetOp,
+            UnaryOp,
+        },
+        support,
+        traits::HasLoopBody,
+        AstChildren,
+        AstNode,
+        AstToken,
+    },
+    SyntaxKind,
+    SyntaxToken,
+    T,
+};
+
+impl ast::PrefixExpr {
+    pub fn op_kind(&self) -> Option<UnaryOp> {
+        let res = match self.op_token()?.kind() {
+            T![-] => UnaryOp::Neg,
+            T![!] | T![not] => UnaryOp::Not,
+            T!['√'] => UnaryOp::Sqrt,
+            T!['∛'] => UnaryOp::Cbrt,
+            T!['∜'] => UnaryOp::FourthRoot,
+            T!['∠'] => UnaryOp::Angle,
+            T!['∫'] => UnaryOp::Integral,
+            T!['∮'] => UnaryOp::ContourIntegral,
+            T!['∯'] => UnaryOp::SurfaceIntegral,
+            T!['∰'] => UnaryOp::VolumeIntegral,
+            _ => return None,
+        };
+        Some(res)
+    }
+
+    pub fn op_token(&self) -> Option<SyntaxToken> {
+        self.syntax().first_child_or_token()?.into_token()
+    }
+}
+
+impl ast::BinExpr {
+    pub fn op_details(&self) -> Option<(SyntaxToken, BinaryOp)> {
+        self.syntax().children_with_tokens().filter_map(|it| it.into_token()).find_map(|c| {
+            #[rustfmt::skip]
+            let bin_op = match c.kind() {
+                T![or] | T![||] => BinaryOp::LogicOp(LogicOp::Or),
+                T![and] | T![&&] => BinaryOp::LogicOp(LogicOp::And),
+                T!['∴'] => BinaryOp::LogicOp(LogicOp::Therefore),
+                T!['∵'] => BinaryOp::LogicOp(LogicOp::Because),
+
+                T![==] => BinaryOp::CmpOp(CmpOp::Eq { negated: false }),
+                T![!=] => BinaryOp::CmpOp(CmpOp::Eq { negated: true }),
+                T![<=] => BinaryOp::CmpOp(CmpOp::Ord { ordering: Ordering::Less,    strict: false }),
+                T![>=] => BinaryOp::CmpOp(CmpOp::Ord { ordering: Ordering::Greater, strict: false }),
+                T![<]  => BinaryOp::CmpOp(CmpOp::Ord { ordering: Ordering::Less,    strict: true }),
+                T![>]  => BinaryOp::CmpOp(CmpOp::Ord { ordering: Ordering::Greater, strict: true }),
+                T![is] => BinaryOp::CmpOp(CmpOp::Is { negated: false }),
+                T![isnt] => BinaryOp::CmpOp(CmpOp::Is { negated: true }),
+                T!['⊂'] => BinaryOp::CmpOp(CmpOp::Subset   { strict: true,  negated: false }),
+                T!['⊄'] => BinaryOp::CmpOp(CmpOp::Subset   { strict: true,  negated: true }),
+                T!['⊆'] => BinaryOp::CmpOp(CmpOp::Subset   { strict: false, negated: false }),
+                T!['⊃'] => BinaryOp::CmpOp(CmpOp::Superset { strict: true,  negated: false }),
+                T!['⊅'] => BinaryOp::CmpOp(CmpOp::Superset { strict: true,  negated: true }),
+                T!['⊇'] => BinaryOp::CmpOp(CmpOp::Superset { strict: false, negated: false }),
+                T!['≈'] => BinaryOp::CmpOp(CmpOp::Approx),
+                T!['≡'] => BinaryOp::CmpOp(CmpOp::Identical),
+                T!['∝'] => BinaryOp::CmpOp(CmpOp::Proportional),
+
+                T![+]  => BinaryOp::ArithOp(ArithOp::Add),
+                T![*]  => BinaryOp::ArithOp(ArithOp::Mul),
+                T![-]  => BinaryOp::ArithOp(ArithOp::Sub),
+                T![/]  => BinaryOp::ArithOp(ArithOp::Div),
+                T![%]  => BinaryOp::ArithOp(ArithOp::Rem),
+                T![**] => BinaryOp::ArithOp(ArithOp::Pow),
+                T![<<] => BinaryOp::ArithOp(ArithOp::Shl),
+                T![>>] => BinaryOp::ArithOp(ArithOp::Shr),
+                T![^]  => BinaryOp::ArithOp(ArithOp::BitXor),
+                T![|]  => BinaryOp::ArithOp(ArithOp::BitOr),
+                T![&]  => BinaryOp::ArithOp(ArithOp::BitAnd),
+
+                T!['∩'] => BinaryOp::SetOp(SetOp::Intersection),
+                T!['∪'] => BinaryOp::SetOp(SetOp::Union),
+
+                T![=]   => BinaryOp::Assignment { op: None },
+                T![+=]  => BinaryOp::Assignment { op: Some(ArithOp::Add) },
+                T![*=]  => BinaryOp::Assignment { op: Some(ArithOp::Mul) },
+                T![-=]  => BinaryOp::Assignment { op: Some(ArithOp::Sub) },
+                T![/=]  => BinaryOp::Assignment { op: Some(ArithOp::Div) },
+                T![%=]  => BinaryOp::Assignment { op: Some(ArithOp::Rem) },
+                T![<<=] => BinaryOp::Assignment { op: Some(ArithOp::Shl) },
+                T![>>=] => BinaryOp::Assignment { op: Some(ArithOp::Shr) },
+                T![^=]  => BinaryOp::Assignment { op: Some(ArithOp::BitXor) },
+                T![|=]  => BinaryOp::Assignment { op: Some(ArithOp::BitOr) },
+                T![&=]  => BinaryOp::Assignment { op: Some(ArithOp::BitAnd) },
+
+                T![<-] => BinaryOp::Send,
+
+                _ => return None,
+            };
+            Some((c, bin_op))
+        })
+    }
+
+    pub fn op_kind(&self) -> Option<BinaryOp> {
+        match self.op_details() {
+            Some((_, op)) => Some(op),
+            None if self.is_superscript_pow() => Some(BinaryOp::ArithOp(ArithOp::Pow)),
+            None => None,
+        }
+    }
+
+    /// Returns the operator token, or `None` for a **superscript exponent**
+    /// (which has none).
+    pub fn op_token(&self) -> Option<SyntaxToken> {
+        self.op_details().map(|t| t.0)
+    }
+
+    /// Returns `true` if the binary expression is a **superscript exponent**
+    /// (e.g. `x²` or `2⁽n + 1⁾`), which is a power without an operator token.
+    pub fn is_superscript_pow(&self) -> bool {
+        self.op_details().is_none() &&
+            match self.rhs() {
+                Some(ast::Expr::SuperscriptExpr(_)) => true,
+                Some(ast::Expr::Literal(literal)) => {
+                    matches!(literal.kind(), LiteralKind::IntegerSup(_) | LiteralKind::FloatSup(_))
+                }
+                _ => false,
+            }
+    }
+
+    pub fn lhs(&self) -> Option<ast::Expr> {
+        support::children(self.syntax()).next()
+    }
+
+    pub fn rhs(&self) -> Option<ast::Expr> {
+        support::children(self.syntax()).nth(1)
+    }
+
+    pub fn sub_exprs(&self) -> (Option<ast::Expr>, Option<ast::Expr>) {
+        let mut children: AstChildren<ast::Expr> = support::children(self.syntax());
+        let first = children.next();
+        let second = children.next();
+        (first, second)
+    }
+}
+
+impl ast::RangeExpr {
+    fn op_details(&self) -> Option<(usize, SyntaxToken, RangeOp)> {
+        self.syntax().children_with_tokens().enumerate().find_map(|(ix, child)| {
+            let token = child.into_token()?;
+            let bin_op = match token.kind() {
+                T![..] => RangeOp::Exclusive,
+                T![..=] => RangeOp::Inclusive,
+                _ => return None,
+            };
+            Some((ix, token, bin_op))
+        })
+    }
+
+    pub fn op_kind(&self) -> Option<RangeOp> {
+        self.op_details().map(|t| t.2)
+    }
+
+    pub fn op_token(&self) -> Option<SyntaxToken> {
+        self.op_details().map(|t| t.1)
+    }
+
+    pub fn start(&self) -> Option<ast::Expr> {
+        let op_ix = self.op_details()?.0;
+        self.syntax()
+            .children_with_tokens()
+            .take(op_ix)
+            .find_map(|it| ast::Expr::cast(it.into_node()?))
+    }
+
+    pub fn end(&self) -> Option<ast::Expr> {
+        let op_ix = self.op_details()?.0;
+        self.syntax()
+            .children_with_tokens()
+            .skip(op_ix + 1)
+            .find_map(|it| ast::Expr::cast(it.into_node()?))
+    }
+}
+
+impl ast::IndexExpr {
+    pub fn base(&self) -> Option<ast::Expr> {
+        support::children(self.syntax()).next()
+    }
+
+    pub fn index(&self) -> Option<ast::Expr> {
+        support::children(self.syntax()).nth(1)
+    }
+}
+
+/// The `else` branch of an [`IfExpr`][ast::IfExpr] (i.e. either a block or an
+/// `else if`).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ElseBranch {
+    Block(ast::BlockExpr),
+    IfExpr(ast::IfExpr),
+}
+
+impl ast::IfExpr {
+    pub fn condition(&self) -> Option<ast::Expr> {
+        support::children(self.syntax()).next()
+    }
+
+    pub fn then_branch(&self) -> Option<ast::BlockExpr> {
+        self.children_after_condition().next()
+    }
+
+    pub fn else_branch(&self) -> Option<ElseBranch> {
+        let res = match self.children_after_condition().nth(1) {
+            Some(block) => ElseBranch::Block(block),
+            None => {
+                let elif = self.children_after_condition().next()?;
+                ElseBranch::IfExpr(elif)
+            }
+        };
+        Some(res)
+    }
+
+    /// The children after the condition (which may itself be a `BlockExpr`).
+    fn children_after_condition<N: AstNode>(&self) -> impl Iterator<Item = N> {
+        self.syntax().children().skip(1).filter_map(N::cast)
+    }
+}
+
+impl ast::ForExpr {
+    pub fn iterable(&self) -> Option<ast::Expr> {
+        // If the iterable is a `BlockExpr`, check that it's not the loop body.
+        let mut exprs = support::children(self.syntax());
+        let first = exprs.next();
+        match first {
+            Some(ast::Expr::BlockExpr(_)) => exprs.next().and(first),
+            first => first,
+        }
+    }
+}
+
+impl ast::WhileExpr {
+    pub fn condition(&self) -> Option<ast::Expr> {
+        // If the condition is a `BlockExpr`, check that it's not the loop body.
+        let mut exprs = support::children(self.syntax());
+        let first = exprs.next();
+        match first {
+            Some(ast::Expr::BlockExpr(_)) => exprs.next().and(first),
+            first => first,
+        }
+    }
+}
+
+/// A **part** of a [format string](ast::FormatStringExpr).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FormatStringPart {
+    /// A **literal fragment** (e.g. the `Bonjour, ` of `"Bonjour, {name}!"`).
+    Fragment(SyntaxToken),
+    /// A **hole** (e.g. the `{name}` of `"Bonjour, {name}!"`).
+    Arg(ast::FormatArg),
+}
+
+impl ast::FormatStringExpr {
+    /// The literal fragments and holes of the format string, in order.
+    pub fn parts(&self) -> impl Iterator<Item = FormatStringPart> {
+        self.syntax().children_with_tokens().filter_map(|it| match it {
+            NodeOrToken::Token(token) if token.kind() == SyntaxKind::STRING_FRAGMENT => {
+                Some(FormatStringPart::Fragment(token))
+            }
+            NodeOrToken::Token(_) => None,
+            NodeOrToken::Node(node) => ast::FormatArg::cast(node).map(FormatStringPart::Arg),
+        })
+    }
+
+    /// The expressions in the holes of the format string.
+    pub fn args(&self) -> impl Iterator<Item = ast::Expr> {
+        self.format_args().filter_map(|arg| arg.expr())
+    }
+}
+
+// The loop body of a `ForExpr` or `WhileExpr` is the **last** `BlockExpr`,
+// since the iterable or condition may be a block too.
+impl HasLoopBody for ast::ForExpr {
+    fn loop_body(&self) -> Option<ast::BlockExpr> {
+        let mut exprs = support::children(self.syntax());
+        let first = exprs.next();
+        let second = exprs.next();
+        second.or(first)
+    }
+}
+
+impl HasLoopBody for ast::WhileExpr {
+    fn loop_body(&self) -> Option<ast::BlockExpr> {
+        let mut exprs = support::children(self.syntax());
+        let first = exprs.next();
+        let second = exprs.next();
+        second.or(first)
+    }
+}
+
+impl ast::MatchGuard {
+    pub fn condition(&self) -> Option<ast::Expr> {
+        support::child(self.syntax())
+    }
+}
+
+/// The **kind** of a [literal][ast::Literal] (i.e. its typed token).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LiteralKind {
+    Integer(ast::Integer),
+    Float(ast::Float),
+    IntegerSup(ast::IntegerSup),
+    FloatSup(ast::FloatSup),
+    Rune(ast::Rune),
+    String(ast::String),
+    RawString(ast::RawString),
+    Bool(bool),
+    /// A **mathematical constant** (e.g. `π`, `∞` or `NaN`).
+    MathConstant(SyntaxToken),
+}
+
+impl ast::Literal {
+    pub fn token(&self) -> SyntaxToken {
+        self.syntax()
+            .children_with_tokens()
+            .find(|e| !e.kind().is_trivia())
+            .and_then(|e| e.into_token())
+            .unwrap()
+    }
+
+    pub fn kind(&self) -> LiteralKind {
+        let token = self.token();
+
+        if let Some(t) = ast::Integer::cast(token.clone()) {
+            return LiteralKind::Integer(t);
+        }
+        if let Some(t) = ast::Float::cast(token.clone()) {
+            return LiteralKind::Float(t);
+        }
+        if let Some(t) = ast::IntegerSup::cast(token.clone()) {
+            return LiteralKind::IntegerSup(t);
+        }
+        if let Some(t) = ast::FloatSup::cast(token.clone()) {
+            return LiteralKind::FloatSup(t);
+        }
+        if let Some(t) = ast::Rune::cast(token.clone()) {
+            return LiteralKind::Rune(t);
+        }
+        if let Some(t) = ast::String::cast(token.clone()) {
+            return LiteralKind::String(t);
+        }
+        if let Some(t) = ast::RawString::cast(token.clone()) {
+            return LiteralKind::RawString(t);
+        }
+
+        match token.kind() {
+            T![true] => LiteralKind::Bool(true),
+            T![false] => LiteralKind::Bool(false),
+            SyntaxKind::PI |
+            SyntaxKind::EULER |
+            SyntaxKind::PHI |
+            SyntaxKind::TAU |
+            SyntaxKind::CATALAN |
+            SyntaxKind::EULERGAMMA |
+            SyntaxKind::INF |
+            SyntaxKind::NAN => LiteralKind::MathConstant(token),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns `true` if the literal is **negated** (i.e. it's the operand of a
+    /// `-`), which e.g. `-128i8` has to be to fit in its type.
+    pub fn is_negated(&self) -> bool {
+        self.syntax()
+            .parent()
+            .and_then(ast::PrefixExpr::cast)
+            .map_or(false, |expr| expr.op_kind() == Some(UnaryOp::Neg))
+    }
+
+    /// Returns the **value** of a numeric literal (i.e. an integer, a float or
+    /// a mathematical constant), or `None` if the literal isn't numeric or its
+    /// value is out of range (taking into account whether it's
+    /// [negated][Self::is_negated]).
+    pub fn numeric_value(&self) -> Option<NumericValue> {
+        let token = self.token();
+        let kind = match token.kind() {
+            SyntaxKind::INTEGER => TokenKind::INTEGER,
+            SyntaxKind::FLOAT => TokenKind::FLOAT,
+            SyntaxKind::INTEGER_SUP => TokenKind::INTEGER_SUP,
+            SyntaxKind::FLOAT_SUP => TokenKind::FLOAT_SUP,
+            SyntaxKind::PI => TokenKind::PI,
+            SyntaxKind::EULER => TokenKind::EULER,
+            SyntaxKind::PHI => TokenKind::PHI,
+            SyntaxKind::TAU => TokenKind::TAU,
+            SyntaxKind::CATALAN => TokenKind::CATALAN,
+            SyntaxKind::EULERGAMMA => TokenKind::EULERGAMMA,
+            SyntaxKind::INF => TokenKind::INF,
+            SyntaxKind::NAN => TokenKind::NAN,
+            _ => return None,
+        };
+        let value = number::parse_number(kind, token.text()).ok()?;
+        value.check_negated(self.is_negated()).ok()?;
+        Some(value)
+    }
+}
@@ -24,9 +24,22 @@ pub enum SyntaxKind {
     RUNE,
     STRING,
     RAW_STRING,
+    FORMAT_STRING_START,
+    STRING_FRAGMENT,
+    FORMAT_STRING_END,
     INTEGER,
     FLOAT,
     LIFETIME,
+    INTEGER_SUP,
+    FLOAT_SUP,
+    PI,
+    EULER,
+    PHI,
+    TAU,
+    CATALAN,
+    EULERGAMMA,
+    INF,
+    NAN,
     ///Tokens (e.g. WHITESPACE, ERROR, IDENTIFIER, COMMENT, DOC_COMMENT)
     WHITESPACE,
     ERROR,
@@ -45,12 +58,16 @@ pub enum SyntaxKind {
     AND_KW,
     AS_KW,
     BREAK_KW,
+    CASE_KW,
     CONST_KW,
     CONTINUE_KW,
+    DEFAULT_KW,
+    DEFER_KW,
     DO_KW,
     DYN_KW,
     ELSE_KW,
     ENUM_KW,
+    FALLTHROUGH_KW,
     FALSE_KW,
     FN_KW,
     FOR_KW,
@@ -58,6 +75,7 @@ pub enum SyntaxKind {
     IMPL_KW,
     IMPORT_KW,
     IN_KW,
+    ISNT_KW,
     LET_KW,
     LOOP_KW,
     MATCH_KW,
@@ -138,34 +156,134 @@ pub enum SyntaxKind {
     R_PAREN,
     L_BRACE,
     R_BRACE,
+    L_PAREN_SUPERSCRIPT,
+    R_PAREN_SUPERSCRIPT,
     L_ARROW,
     DOUBLE_STAR,
+    SQRT,
+    CBRT,
+    FOURTH_ROOT,
+    INTERSECTION,
+    UNION,
+    SUBSET,
+    SUPERSET,
+    NOT_SUBSET,
+    NOT_SUPERSET,
+    SUBSET_EQ,
+    SUPERSET_EQ,
+    APPROX_EQ,
+    IDENTICAL,
+    PROPORTIONAL,
+    ANGLE,
+    INTEGRAL,
+    CONTOUR_INTEGRAL,
+    SURFACE_INTEGRAL,
+    VOLUME_INTEGRAL,
+    THEREFORE,
+    BECAUSE,
     ///Nodes (e.g. VarDecl, etc.)
     SOURCE_FILE,
+    STRUCT,
+    ENUM,
+    FN,
+    RET_TYPE,
     MODULE,
     USE,
+    TRAIT,
+    IMPL,
+    TYPE_ALIAS,
+    MACRO_CALL,
     TOKEN_TREE,
     PAREN_TYPE,
+    TUPLE_TYPE,
     NEVER_TYPE,
     PATH_TYPE,
+    ARRAY_TYPE,
+    SLICE_TYPE,
+    REF_TYPE,
+    FN_PTR_TYPE,
+    IMPL_TRAIT_TYPE,
+    DYN_TRAIT_TYPE,
+    OR_PAT,
+    PAREN_PAT,
+    IDENT_PAT,
+    WILDCARD_PAT,
+    REST_PAT,
+    PATH_PAT,
+    RECORD_PAT,
+    RECORD_PAT_FIELD_LIST,
+    RECORD_PAT_FIELD,
+    TUPLE_STRUCT_PAT,
+    TUPLE_PAT,
+    RANGE_PAT,
+    LITERAL_PAT,
+    PAREN_EXPR,
+    PATH_EXPR,
+    FORMAT_STRING_EXPR,
+    FORMAT_ARG,
+    IF_EXPR,
+    WHILE_EXPR,
+    LOOP_EXPR,
+    FOR_EXPR,
+    CONTINUE_EXPR,
+    BREAK_EXPR,
+    LABEL,
+    BLOCK_EXPR,
+    STMT_LIST,
+    RETURN_EXPR,
+    DEFER_EXPR,
+    FALLTHROUGH_EXPR,
+    MACRO_EXPR,
+    MATCH_EXPR,
+    MATCH_ARM_LIST,
+    MATCH_ARM,
+    MATCH_GUARD,
+    CALL_EXPR,
+    INDEX_EXPR,
     FIELD_EXPR,
     REF_EXPR,
+    PREFIX_EXPR,
+    RANGE_EXPR,
+    BIN_EXPR,
+    SUPERSCRIPT_EXPR,
+    VARIANT,
     RECORD_FIELD_LIST,
     RECORD_FIELD,
     TUPLE_FIELD_LIST,
     TUPLE_FIELD,
+    VARIANT_LIST,
     ITEM_LIST,
+    ASSOC_ITEM_LIST,
     ATTR,
     META,
     USE_TREE,
     USE_TREE_LIST,
     PATH,
     PATH_SEGMENT,
+    LITERAL,
     RENAME,
     VISIBILITY,
+    WHERE_CLAUSE,
+    WHERE_PRED,
     NAME,
     NAME_REF,
+    LET_STMT,
     EXPR_STMT,
+    GENERIC_PARAM_LIST,
+    LIFETIME_PARAM,
+    TYPE_PARAM,
+    CONST_PARAM,
+    GENERIC_ARG_LIST,
+    LIFETIME_ARG,
+    TYPE_ARG,
+    ASSOC_TYPE_ARG,
+    CONST_ARG,
+    PARAM_LIST,
+    PARAM,
+    SELF_PARAM,
+    ARG_LIST,
+    TYPE_BOUND,
+    TYPE_BOUND_LIST,
     #[doc(hidden)]
     __LAST,
 }
@@ -189,12 +307,16 @@ impl SyntaxKind {
                 AND_KW |
                 AS_KW |
                 BREAK_KW |
+                CASE_KW |
                 CONST_KW |
                 CONTINUE_KW |
+                DEFAULT_KW |
+                DEFER_KW |
                 DO_KW |
                 DYN_KW |
                 ELSE_KW |
                 ENUM_KW |
+                FALLTHROUGH_KW |
                 FALSE_KW |
                 FN_KW |
                 FOR_KW |
@@ -202,6 +324,7 @@ impl SyntaxKind {
                 IMPL_KW |
                 IMPORT_KW |
                 IN_KW |
+                ISNT_KW |
                 LET_KW |
                 LOOP_KW |
                 MATCH_KW |
@@ -284,12 +407,55 @@ impl SyntaxKind {
                 R_PAREN |
                 L_BRACE |
                 R_BRACE |
+                L_PAREN_SUPERSCRIPT |
+                R_PAREN_SUPERSCRIPT |
                 L_ARROW |
-                DOUBLE_STAR
+                DOUBLE_STAR |
+                SQRT |
+                CBRT |
+                FOURTH_ROOT |
+                INTERSECTION |
+                UNION |
+                SUBSET |
+                SUPERSET |
+                NOT_SUBSET |
+                NOT_SUPERSET |
+                SUBSET_EQ |
+                SUPERSET_EQ |
+                APPROX_EQ |
+                IDENTICAL |
+                PROPORTIONAL |
+                ANGLE |
+                INTEGRAL |
+                CONTOUR_INTEGRAL |
+                SURFACE_INTEGRAL |
+                VOLUME_INTEGRAL |
+                THEREFORE |
+                BECAUSE
         )
     }
     pub fn is_literal(self) -> bool {
-        matches!(self, RUNE | STRING | RAW_STRING | INTEGER | FLOAT | LIFETIME)
+        matches!(
+            self,
+            RUNE | STRING |
+                RAW_STRING |
+                FORMAT_STRING_START |
+                STRING_FRAGMENT |
+                FORMAT_STRING_END |
+                INTEGER |
+                FLOAT |
+                LIFETIME |
+                INTEGER_SUP |
+                FLOAT_SUP |
+                PI |
+                EULER |
+                PHI |
+                TAU |
+                CATALAN |
+                EULERGAMMA |
+                INF |
+                NAN
+        )
     }
     pub fn from_keyword(ident: &str) -> Option<SyntaxKind> {
         let kw = match ident {
@@ -302,12 +468,16 @@ impl SyntaxKind {
             "and" => AND_KW,
             "as" => AS_KW,
             "break" => BREAK_KW,
+            "case" => CASE_KW,
             "const" => CONST_KW,
             "continue" => CONTINUE_KW,
+            "default" => DEFAULT_KW,
+            "defer" => DEFER_KW,
             "do" => DO_KW,
             "dyn" => DYN_KW,
             "else" => ELSE_KW,
             "enum" => ENUM_KW,
+            "fallthrough" => FALLTHROUGH_KW,
             "false" => FALSE_KW,
             "fn" => FN_KW,
             "for" => FOR_KW,
@@ -315,6 +485,7 @@ impl SyntaxKind {
             "impl" => IMPL_KW,
             "import" => IMPORT_KW,
             "in" => IN_KW,
+            "isnt" => ISNT_KW,
             "let" => LET_KW,
             "loop" => LOOP_KW,
             "match" => MATCH_KW,
@@ -384,6 +555,31 @@ impl SyntaxKind {
 impl From<TokenKind> for SyntaxKind {
     fn from(kind: TokenKind) -> SyntaxKind {
         match kind {
+            TokenKind::EOF => EOF,
+            TokenKind::RUNE => RUNE,
+            TokenKind::STRING => STRING,
+            TokenKind::RAW_STRING => RAW_STRING,
+            TokenKind::FORMAT_STRING_START => FORMAT_STRING_START,
+            TokenKind::STRING_FRAGMENT => STRING_FRAGMENT,
+            TokenKind::FORMAT_STRING_END => FORMAT_STRING_END,
+            TokenKind::INTEGER => INTEGER,
+            TokenKind::FLOAT => FLOAT,
+            TokenKind::LIFETIME => LIFETIME,
+            TokenKind::INTEGER_SUP => INTEGER_SUP,
+            TokenKind::FLOAT_SUP => FLOAT_SUP,
+            TokenKind::PI => PI,
+            TokenKind::EULER => EULER,
+            TokenKind::PHI => PHI,
+            TokenKind::TAU => TAU,
+            TokenKind::CATALAN => CATALAN,
+            TokenKind::EULERGAMMA => EULERGAMMA,
+            TokenKind::INF => INF,
+            TokenKind::NAN => NAN,
+            TokenKind::WHITESPACE => WHITESPACE,
+            TokenKind::ERROR => ERROR,
+            TokenKind::IDENTIFIER => IDENTIFIER,
+            TokenKind::COMMENT => COMMENT,
+            TokenKind::DOC_COMMENT => DOC_COMMENT,
             TokenKind::DEFINE => DEFINE,
             TokenKind::PLUS => PLUS,
             TokenKind::MINUS => MINUS,
@@ -436,8 +632,31 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::R_PAREN => R_PAREN,
             TokenKind::L_BRACE => L_BRACE,
             TokenKind::R_BRACE => R_BRACE,
+            TokenKind::L_PAREN_SUPERSCRIPT => L_PAREN_SUPERSCRIPT,
+            TokenKind::R_PAREN_SUPERSCRIPT => R_PAREN_SUPERSCRIPT,
             TokenKind::L_ARROW => L_ARROW,
             TokenKind::DOUBLE_STAR => DOUBLE_STAR,
+            TokenKind::SQRT => SQRT,
+            TokenKind::CBRT => CBRT,
+            TokenKind::FOURTH_ROOT => FOURTH_ROOT,
+            TokenKind::INTERSECTION => INTERSECTION,
+            TokenKind::UNION => UNION,
+            TokenKind::SUBSET => SUBSET,
+            TokenKind::SUPERSET => SUPERSET,
+            TokenKind::NOT_SUBSET => NOT_SUBSET,
+            TokenKind::NOT_SUPERSET => NOT_SUPERSET,
+            TokenKind::SUBSET_EQ => SUBSET_EQ,
+            TokenKind::SUPERSET_EQ => SUPERSET_EQ,
+            TokenKind::APPROX_EQ => APPROX_EQ,
+            TokenKind::IDENTICAL => IDENTICAL,
+            TokenKind::PROPORTIONAL => PROPORTIONAL,
+            TokenKind::ANGLE => ANGLE,
+            TokenKind::INTEGRAL => INTEGRAL,
+            TokenKind::CONTOUR_INTEGRAL => CONTOUR_INTEGRAL,
+            TokenKind::SURFACE_INTEGRAL => SURFACE_INTEGRAL,
+            TokenKind::VOLUME_INTEGRAL => VOLUME_INTEGRAL,
+            TokenKind::THEREFORE => THEREFORE,
+            TokenKind::BECAUSE => BECAUSE,
             TokenKind::ABSTRACT_KW => ABSTRACT_KW,
             TokenKind::ASYNC_KW => ASYNC_KW,
             TokenKind::AWAIT_KW => AWAIT_KW,
@@ -447,12 +666,16 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::AND_KW => AND_KW,
             TokenKind::AS_KW => AS_KW,
             TokenKind::BREAK_KW => BREAK_KW,
+            TokenKind::CASE_KW => CASE_KW,
             TokenKind::CONST_KW => CONST_KW,
             TokenKind::CONTINUE_KW => CONTINUE_KW,
+            TokenKind::DEFAULT_KW => DEFAULT_KW,
+            TokenKind::DEFER_KW => DEFER_KW,
             TokenKind::DO_KW => DO_KW,
             TokenKind::DYN_KW => DYN_KW,
             TokenKind::ELSE_KW => ELSE_KW,
             TokenKind::ENUM_KW => ENUM_KW,
+            TokenKind::FALLTHROUGH_KW => FALLTHROUGH_KW,
             TokenKind::FALSE_KW => FALSE_KW,
             TokenKind::FN_KW => FN_KW,
             TokenKind::FOR_KW => FOR_KW,
@@ -460,6 +683,7 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::IMPL_KW => IMPL_KW,
             TokenKind::IMPORT_KW => IMPORT_KW,
             TokenKind::IN_KW => IN_KW,
+            TokenKind::ISNT_KW => ISNT_KW,
             TokenKind::LET_KW => LET_KW,
             TokenKind::LOOP_KW => LOOP_KW,
             TokenKind::MATCH_KW => MATCH_KW,
@@ -485,10 +709,9 @@ impl From<TokenKind> for SyntaxKind {
             TokenKind::WHERE_KW => WHERE_KW,
             TokenKind::WHILE_KW => WHILE_KW,
             TokenKind::YIELD_KW => YIELD_KW,
-            _ => unreachable!(),
         }
     }
 }
 #[macro_export]
-macro_rules ! T { [:=] => { $ crate :: SyntaxKind :: DEFINE } ; [+] => { $ crate :: SyntaxKind :: PLUS } ; [-] => { $ crate :: SyntaxKind :: MINUS } ; [*] => { $ crate :: SyntaxKind :: STAR } ; [/] => { $ crate :: SyntaxKind :: SLASH } ; [%] => { $ crate :: SyntaxKind :: PERCENT } ; [^] => { $ crate :: SyntaxKind :: CARET } ; [!] => { $ crate :: SyntaxKind :: BANG } ; [&] => { $ crate :: SyntaxKind :: AMPERSAND } ; [|] => { $ crate :: SyntaxKind :: PIPE } ; [&&] => { $ crate :: SyntaxKind :: DOUBLE_AMPERSAND } ; [||] => { $ crate :: SyntaxKind :: DOUBLE_PIPE } ; [<<] => { $ crate :: SyntaxKind :: SHL } ; [>>] => { $ crate :: SyntaxKind :: SHR } ; [+=] => { $ crate :: SyntaxKind :: PLUS_EQ } ; [-=] => { $ crate :: SyntaxKind :: MINUS_EQ } ; [*=] => { $ crate :: SyntaxKind :: STAR_EQ } ; [/=] => { $ crate :: SyntaxKind :: SLASH_EQ } ; [%=] => { $ crate :: SyntaxKind :: PERCENT_EQ } ; [^=] => { $ crate :: SyntaxKind :: CARET_EQ } ; [&=] => { $ crate :: SyntaxKind :: AMPERSAND_EQ } ; [|=] => { $ crate :: SyntaxKind :: PIPE_EQ } ; [<<=] => { $ crate :: SyntaxKind :: SHL_EQ } ; [>>=] => { $ crate :: SyntaxKind :: SHR_EQ } ; [=] => { $ crate :: SyntaxKind :: EQ } ; [==] => { $ crate :: SyntaxKind :: EQEQ } ; [!=] => { $ crate :: SyntaxKind :: NE } ; [>] => { $ crate :: SyntaxKind :: GT } ; [<] => { $ crate :: SyntaxKind :: LT } ; [>=] => { $ crate :: SyntaxKind :: GE } ; [<=] => { $ crate :: SyntaxKind :: LE } ; [@] => { $ crate :: SyntaxKind :: AT } ; [_] => { $ crate :: SyntaxKind :: UNDERSCORE } ; [.] => { $ crate :: SyntaxKind :: DOT } ; [..] => { $ crate :: SyntaxKind :: DOTDOT } ; [..=] => { $ crate :: SyntaxKind :: DOTDOTEQ } ; [,] => { $ crate :: SyntaxKind :: COMMA } ; [;] => { $ crate :: SyntaxKind :: SEMICOLON } ; [:] => { $ crate :: SyntaxKind :: COLON } ; [::] => { $ crate :: SyntaxKind :: PATHSEP } ; [->] => { $ crate :: SyntaxKind :: RARROW } ; [=>] => { $ crate :: SyntaxKind :: FATARROW } ; [#] => { $ crate :: SyntaxKind :: HASH } ; [$] => { $ crate :: SyntaxKind :: DOLLAR } ; [?] => { $ crate :: SyntaxKind :: QMARK } ; [~] => { $ crate :: SyntaxKind :: TILDE } ; ['['] => { $ crate :: SyntaxKind :: L_BRACKET } ; [']'] => { $ crate :: SyntaxKind :: R_BRACKET } ; ['('] => { $ crate :: SyntaxKind :: L_PAREN } ; [')'] => { $ crate :: SyntaxKind :: R_PAREN } ; ['{'] => { $ crate :: SyntaxKind :: L_BRACE } ; ['}'] => { $ crate :: SyntaxKind :: R_BRACE } ; [<-] => { $ crate :: SyntaxKind :: L_ARROW } ; [**] => { $ crate :: SyntaxKind :: DOUBLE_STAR } ; [abstract] => { $ crate :: SyntaxKind :: ABSTRACT_KW } ; [async] => { $ crate :: SyntaxKind :: ASYNC_KW } ; [await] => { $ crate :: SyntaxKind :: AWAIT_KW } ; [extern] => { $ crate :: SyntaxKind :: EXTERN_KW } ; [final] => { $ crate :: SyntaxKind :: FINAL_KW } ; [is] => { $ crate :: SyntaxKind :: IS_KW } ; [and] => { $ crate :: SyntaxKind :: AND_KW } ; [as] => { $ crate :: SyntaxKind :: AS_KW } ; [break] => { $ crate :: SyntaxKind :: BREAK_KW } ; [const] => { $ crate :: SyntaxKind :: CONST_KW } ; [continue] => { $ crate :: SyntaxKind :: CONTINUE_KW } ; [do] => { $ crate :: SyntaxKind :: DO_KW } ; [dyn] => { $ crate :: SyntaxKind :: DYN_KW } ; [else] => { $ crate :: SyntaxKind :: ELSE_KW } ; [enum] => { $ crate :: SyntaxKind :: ENUM_KW } ; [false] => { $ crate :: SyntaxKind :: FALSE_KW } ; [fn] => { $ crate :: SyntaxKind :: FN_KW } ; [for] => { $ crate :: SyntaxKind :: FOR_KW } ; [if] => { $ crate :: SyntaxKind :: IF_KW } ; [impl] => { $ crate :: SyntaxKind :: IMPL_KW } ; [import] => { $ crate :: SyntaxKind :: IMPORT_KW } ; [in] => { $ crate :: SyntaxKind :: IN_KW } ; [let] => { $ crate :: SyntaxKind :: LET_KW } ; [loop] => { $ crate :: SyntaxKind :: LOOP_KW } ; [match] => { $ crate :: SyntaxKind :: MATCH_KW } ; [missing] => { $ crate :: SyntaxKind :: MISSING_KW } ; [mod] => { $ crate :: SyntaxKind :: MOD_KW } ; [move] => { $ crate :: SyntaxKind :: MOVE_KW } ; [mut] => { $ crate :: SyntaxKind :: MUT_KW } ; [not] => { $ crate :: SyntaxKind :: NOT_KW } ; [or] => { $ crate :: SyntaxKind :: OR_KW } ; [package] => { $ crate :: SyntaxKind :: PACKAGE_KW } ; [pub] => { $ crate :: SyntaxKind :: PUB_KW } ; [return] => { $ crate :: SyntaxKind :: RETURN_KW } ; [self_type] => { $ crate :: SyntaxKind :: SELF_TYPE_KW } ; [self_value] => { $ crate :: SyntaxKind :: SELF_VALUE_KW } ; [static] => { $ crate :: SyntaxKind :: STATIC_KW } ; [struct] => { $ crate :: SyntaxKind :: STRUCT_KW } ; [super] => { $ crate :: SyntaxKind :: SUPER_KW } ; [trait] => { $ crate :: SyntaxKind :: TRAIT_KW } ; [true] => { $ crate :: SyntaxKind :: TRUE_KW } ; [type] => { $ crate :: SyntaxKind :: TYPE_KW } ; [unsafe] => { $ crate :: SyntaxKind :: UNSAFE_KW } ; [use] => { $ crate :: SyntaxKind :: USE_KW } ; [where] => { $ crate :: SyntaxKind :: WHERE_KW } ; [while] => { $ crate :: SyntaxKind :: WHILE_KW } ; [yield] => { $ crate :: SyntaxKind :: YIELD_KW } ; [ident] => { $ crate :: SyntaxKind :: IDENTIFIER } ; ['⁽'] => { $ crate :: SyntaxKind :: L_PAREN_SUPERSCRIPT } ; ['⁾'] => { $ crate :: SyntaxKind :: R_PAREN_SUPERSCRIPT } ; }
+macro_rules ! T { [:=] => { $ crate :: SyntaxKind :: DEFINE } ; [+] => { $ crate :: SyntaxKind :: PLUS } ; [-] => { $ crate :: SyntaxKind :: MINUS } ; [*] => { $ crate :: SyntaxKind :: STAR } ; [/] => { $ crate :: SyntaxKind :: SLASH } ; [%] => { $ crate :: SyntaxKind :: PERCENT } ; [^] => { $ crate :: SyntaxKind :: CARET } ; [!] => { $ crate :: SyntaxKind :: BANG } ; [&] => { $ crate :: SyntaxKind :: AMPERSAND } ; [|] => { $ crate :: SyntaxKind :: PIPE } ; [&&] => { $ crate :: SyntaxKind :: DOUBLE_AMPERSAND } ; [||] => { $ crate :: SyntaxKind :: DOUBLE_PIPE } ; [<<] => { $ crate :: SyntaxKind :: SHL } ; [>>] => { $ crate :: SyntaxKind :: SHR } ; [+=] => { $ crate :: SyntaxKind :: PLUS_EQ } ; [-=] => { $ crate :: SyntaxKind :: MINUS_EQ } ; [*=] => { $ crate :: SyntaxKind :: STAR_EQ } ; [/=] => { $ crate :: SyntaxKind :: SLASH_EQ } ; [%=] => { $ crate :: SyntaxKind :: PERCENT_EQ } ; [^=] => { $ crate :: SyntaxKind :: CARET_EQ } ; [&=] => { $ crate :: SyntaxKind :: AMPERSAND_EQ } ; [|=] => { $ crate :: SyntaxKind :: PIPE_EQ } ; [<<=] => { $ crate :: SyntaxKind :: SHL_EQ } ; [>>=] => { $ crate :: SyntaxKind :: SHR_EQ } ; [=] => { $ crate :: SyntaxKind :: EQ } ; [==] => { $ crate :: SyntaxKind :: EQEQ } ; [!=] => { $ crate :: SyntaxKind :: NE } ; [>] => { $ crate :: SyntaxKind :: GT } ; [<] => { $ crate :: SyntaxKind :: LT } ; [>=] => { $ crate :: SyntaxKind :: GE } ; [<=] => { $ crate :: SyntaxKind :: LE } ; [@] => { $ crate :: SyntaxKind :: AT } ; [_] => { $ crate :: SyntaxKind :: UNDERSCORE } ; [.] => { $ crate :: SyntaxKind :: DOT } ; [..] => { $ crate :: SyntaxKind :: DOTDOT } ; [..=] => { $ crate :: SyntaxKind :: DOTDOTEQ } ; [,] => { $ crate :: SyntaxKind :: COMMA } ; [;] => { $ crate :: SyntaxKind :: SEMICOLON } ; [:] => { $ crate :: SyntaxKind :: COLON } ; [::] => { $ crate :: SyntaxKind :: PATHSEP } ; [->] => { $ crate :: SyntaxKind :: RARROW } ; [=>] => { $ crate :: SyntaxKind :: FATARROW } ; [#] => { $ crate :: SyntaxKind :: HASH } ; [$] => { $ crate :: SyntaxKind :: DOLLAR } ; [?] => { $ crate :: SyntaxKind :: QMARK } ; [~] => { $ crate :: SyntaxKind :: TILDE } ; ['['] => { $ crate :: SyntaxKind :: L_BRACKET } ; [']'] => { $ crate :: SyntaxKind :: R_BRACKET } ; ['('] => { $ crate :: SyntaxKind :: L_PAREN } ; [')'] => { $ crate :: SyntaxKind :: R_PAREN } ; ['{'] => { $ crate :: SyntaxKind :: L_BRACE } ; ['}'] => { $ crate :: SyntaxKind :: R_BRACE } ; ['⁽'] => { $ crate :: SyntaxKind :: L_PAREN_SUPERSCRIPT } ; ['⁾'] => { $ crate :: SyntaxKind :: R_PAREN_SUPERSCRIPT } ; [<-] => { $ crate :: SyntaxKind :: L_ARROW } ; [**] => { $ crate :: SyntaxKind :: DOUBLE_STAR } ; ['√'] => { $ crate :: SyntaxKind :: SQRT } ; ['∛'] => { $ crate :: SyntaxKind :: CBRT } ; ['∜'] => { $ crate :: SyntaxKind :: FOURTH_ROOT } ; ['∩'] => { $ crate :: SyntaxKind :: INTERSECTION } ; ['∪'] => { $ crate :: SyntaxKind :: UNION } ; ['⊂'] => { $ crate :: SyntaxKind :: SUBSET } ; ['⊃'] => { $ crate :: SyntaxKind :: SUPERSET } ; ['⊄'] => { $ crate :: SyntaxKind :: NOT_SUBSET } ; ['⊅'] => { $ crate :: SyntaxKind :: NOT_SUPERSET } ; ['⊆'] => { $ crate :: SyntaxKind :: SUBSET_EQ } ; ['⊇'] => { $ crate :: SyntaxKind :: SUPERSET_EQ } ; ['≈'] => { $ crate :: SyntaxKind :: APPROX_EQ } ; ['≡'] => { $ crate :: SyntaxKind :: IDENTICAL } ; ['∝'] => { $ crate :: SyntaxKind :: PROPORTIONAL } ; ['∠'] => { $ crate :: SyntaxKind :: ANGLE } ; ['∫'] => { $ crate :: SyntaxKind :: INTEGRAL } ; ['∮'] => { $ crate :: SyntaxKind :: CONTOUR_INTEGRAL } ; ['∯'] => { $ crate :: SyntaxKind :: SURFACE_INTEGRAL } ; ['∰'] => { $ crate :: SyntaxKind :: VOLUME_INTEGRAL } ; ['∴'] => { $ crate :: SyntaxKind :: THEREFORE } ; ['∵'] => { $ crate :: SyntaxKind :: BECAUSE } ; [abstract] => { $ crate :: SyntaxKind :: ABSTRACT_KW } ; [async] => { $ crate :: SyntaxKind :: ASYNC_KW } ; [await] => { $ crate :: SyntaxKind :: AWAIT_KW } ; [extern] => { $ crate :: SyntaxKind :: EXTERN_KW } ; [final] => { $ crate :: SyntaxKind :: FINAL_KW } ; [is] => { $ crate :: SyntaxKind :: IS_KW } ; [and] => { $ crate :: SyntaxKind :: AND_KW } ; [as] => { $ crate :: SyntaxKind :: AS_KW } ; [break] => { $ crate :: SyntaxKind :: BREAK_KW } ; [case] => { $ crate :: SyntaxKind :: CASE_KW } ; [const] => { $ crate :: SyntaxKind :: CONST_KW } ; [continue] => { $ crate :: SyntaxKind :: CONTINUE_KW } ; [default] => { $ crate :: SyntaxKind :: DEFAULT_KW } ; [defer] => { $ crate :: SyntaxKind :: DEFER_KW } ; [do] => { $ crate :: SyntaxKind :: DO_KW } ; [dyn] => { $ crate :: SyntaxKind :: DYN_KW } ; [else] => { $ crate :: SyntaxKind :: ELSE_KW } ; [enum] => { $ crate :: SyntaxKind :: ENUM_KW } ; [fallthrough] => { $ crate :: SyntaxKind :: FALLTHROUGH_KW } ; [false] => { $ crate :: SyntaxKind :: FALSE_KW } ; [fn] => { $ crate :: SyntaxKind :: FN_KW } ; [for] => { $ crate :: SyntaxKind :: FOR_KW } ; [if] => { $ crate :: SyntaxKind :: IF_KW } ; [impl] => { $ crate :: SyntaxKind :: IMPL_KW } ; [import] => { $ crate :: SyntaxKind :: IMPORT_KW } ; [in] => { $ crate :: SyntaxKind :: IN_KW } ; [isnt] => { $ crate :: SyntaxKind :: ISNT_KW } ; [let] => { $ crate :: SyntaxKind :: LET_KW } ; [loop] => { $ crate :: SyntaxKind :: LOOP_KW } ; [match] => { $ crate :: SyntaxKind :: MATCH_KW } ; [missing] => { $ crate :: SyntaxKind :: MISSING_KW } ; [mod] => { $ crate :: SyntaxKind :: MOD_KW } ; [move] => { $ crate :: SyntaxKind :: MOVE_KW } ; [mut] => { $ crate :: SyntaxKind :: MUT_KW } ; [not] => { $ crate :: SyntaxKind :: NOT_KW } ; [or] => { $ crate :: SyntaxKind :: OR_KW } ; [package] => { $ crate :: SyntaxKind :: PACKAGE_KW } ; [pub] => { $ crate :: SyntaxKind :: PUB_KW } ; [return] => { $ crate :: SyntaxKind :: RETURN_KW } ; [self_type] => { $ crate :: SyntaxKind :: SELF_TYPE_KW } ; [self_value] => { $ crate :: SyntaxKind :: SELF_VALUE_KW } ; [static] => { $ crate :: SyntaxKind :: STATIC_KW } ; [struct] => { $ crate :: SyntaxKind :: STRUCT_KW } ; [super] => { $ crate :: SyntaxKind :: SUPER_KW } ; [trait] => { $ crate :: SyntaxKind :: TRAIT_KW } ; [true] => { $ crate :: SyntaxKind :: TRUE_KW } ; [type] => { $ crate :: SyntaxKind :: TYPE_KW } ; [unsafe] => { $ crate :: SyntaxKind :: UNSAFE_KW } ; [use] => { $ crate :: SyntaxKind :: USE_KW } ; [where] => { $ crate :: SyntaxKind :: WHERE_KW } ; [while] => { $ crate :: SyntaxKind :: WHILE_KW } ; [yield] => { $ crate :: SyntaxKind :: YIELD_KW } ; [ident] => { $ crate :: SyntaxKind :: IDENTIFIER } ; [lifetime] => { $ crate :: SyntaxKind :: LIFETIME } ; [format_string_start] => { $ crate :: SyntaxKind :: FORMAT_STRING_START } ; [format_string_end] => { $ crate :: SyntaxKind :: FORMAT_STRING_END } ; }
 pub use T;
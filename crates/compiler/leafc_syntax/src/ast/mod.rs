@@ -6,19 +6,25 @@
 //! `AstNode` type is used to provide a **strongly-typed** API for tree
 //! traversal and manipulation.
 
+mod expr_ext;
 pub mod generated;
 mod node_ext;
 pub mod node_id;
+pub mod operators;
 mod token_ext;
 pub mod traits;
 
 pub use generated::nodes::*;
 use generated::tokens::*;
 use traits::{
+    HasArgList,
     HasAttrs,
     HasDocComments,
+    HasGenericParams,
+    HasLoopBody,
     HasModuleItem,
     HasName,
+    HasTypeBounds,
     HasVisibility,
 };
 
@@ -27,6 +33,11 @@ use std::marker::PhantomData;
 use itertools::Either;
 
 // use self::generated::kinds::SyntaxKind;
+pub use self::expr_ext::{
+    ElseBranch,
+    FormatStringPart,
+    LiteralKind,
+};
 pub use self::generated::{
     kinds::*,
     nodes::*,
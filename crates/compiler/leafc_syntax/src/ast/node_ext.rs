@@ -1,7 +1,12 @@
 use crate::{
-    ast,
+    ast::{
+        self,
+        operators::RangeOp,
+    },
     SyntaxKind,
     SyntaxNode,
+    SyntaxToken,
+    T,
 };
 use smol_str::SmolStr;
 
@@ -9,8 +14,10 @@ use super::{
     generated::nodes::{
         ExprStmt,
         Item,
+        LetStmt,
         Stmt,
     },
+    support,
     AstNode,
 };
 
@@ -82,16 +89,14 @@ impl ast::HasAttrs for ast::AnyHasDocComments {}
 impl AstNode for Stmt {
     fn can_cast(kind: SyntaxKind) -> bool {
         match kind {
-            EXPR_STMT => true,
-            // LET_STMT | EXPR_STMT => true,
-            _ => false,
-            // _ => Item::can_cast(kind),
+            SyntaxKind::LET_STMT | SyntaxKind::EXPR_STMT => true,
+            _ => Item::can_cast(kind),
         }
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
-            // LET_STMT => Stmt::LetStmt(LetStmt { syntax }),
-            EXPR_STMT => Stmt::ExprStmt(ExprStmt { syntax }),
+            SyntaxKind::LET_STMT => Stmt::LetStmt(LetStmt { syntax }),
+            SyntaxKind::EXPR_STMT => Stmt::ExprStmt(ExprStmt { syntax }),
             _ => {
                 let item = Item::cast(syntax)?;
                 Stmt::Item(item)
@@ -101,9 +106,73 @@ impl AstNode for Stmt {
     }
     fn syntax(&self) -> &SyntaxNode {
         match self {
-            // Stmt::LetStmt(it) => &it.syntax,
+            Stmt::LetStmt(it) => &it.syntax,
             Stmt::ExprStmt(it) => &it.syntax,
             Stmt::Item(it) => it.syntax(),
         }
     }
 }
+
+impl ast::Impl {
+    /// The type the `impl` block is for (e.g. `Foo` in both `impl Foo` and
+    /// `impl Bar for Foo`).
+    pub fn self_ty(&self) -> Option<ast::Type> {
+        match self.target() {
+            (Some(t), None) | (_, Some(t)) => Some(t),
+            _ => None,
+        }
+    }
+
+    /// The trait being implemented (e.g. `Bar` in `impl Bar for Foo`), if any.
+    pub fn trait_(&self) -> Option<ast::Type> {
+        match self.target() {
+            (Some(t), Some(_)) => Some(t),
+            _ => None,
+        }
+    }
+
+    fn target(&self) -> (Option<ast::Type>, Option<ast::Type>) {
+        let mut types = support::children(self.syntax());
+        let first = types.next();
+        let second = types.next();
+        (first, second)
+    }
+}
+
+impl ast::RangePat {
+    fn op_details(&self) -> Option<(usize, SyntaxToken, RangeOp)> {
+        self.syntax().children_with_tokens().enumerate().find_map(|(ix, child)| {
+            let token = child.into_token()?;
+            let op = match token.kind() {
+                T![..] => RangeOp::Exclusive,
+                T![..=] => RangeOp::Inclusive,
+                _ => return None,
+            };
+            Some((ix, token, op))
+        })
+    }
+
+    pub fn op_kind(&self) -> Option<RangeOp> {
+        self.op_details().map(|t| t.2)
+    }
+
+    pub fn op_token(&self) -> Option<SyntaxToken> {
+        self.op_details().map(|t| t.1)
+    }
+
+    pub fn start(&self) -> Option<ast::Pat> {
+        let op_ix = self.op_details()?.0;
+        self.syntax()
+            .children_with_tokens()
+            .take(op_ix)
+            .find_map(|it| ast::Pat::cast(it.into_node()?))
+    }
+
+    pub fn end(&self) -> Option<ast::Pat> {
+        let op_ix = self.op_details()?.0;
+        self.syntax()
+            .children_with_tokens()
+            .skip(op_ix + 1)
+            .find_map(|it| ast::Pat::cast(it.into_node()?))
+    }
+}
@@ -0,0 +1,214 @@
+//! Defines the **operators** of the **Leaf programming language** (e.g. the
+//! operators of `PrefixExpr`s, `BinExpr`s and `RangeExpr`s).
+//!
+//! These are a **typed** view over the operator tokens of the syntax tree
+//! (e.g. both `!` and `not` are a [`UnaryOp::Not`]).
+
+use std::fmt;
+
+/// The operator of a **range expression** (e.g. `0..10` or `0..=9`).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RangeOp {
+    /// `..`
+    Exclusive,
+    /// `..=`
+    Inclusive,
+}
+
+/// The operator of a **prefix expression** (e.g. `-x` or `not x`).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UnaryOp {
+    /// `-`
+    Neg,
+    /// `!` or `not`
+    Not,
+    /// `√`
+    Sqrt,
+    /// `∛`
+    Cbrt,
+    /// `∜`
+    FourthRoot,
+    /// `∠`
+    Angle,
+    /// `∫`
+    Integral,
+    /// `∮`
+    ContourIntegral,
+    /// `∯`
+    SurfaceIntegral,
+    /// `∰`
+    VolumeIntegral,
+}
+
+/// The operator of a **binary expression** (e.g. `a + b` or `a and b`).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BinaryOp {
+    LogicOp(LogicOp),
+    ArithOp(ArithOp),
+    CmpOp(CmpOp),
+    SetOp(SetOp),
+    /// `=` (if `op` is `None`) or a **compound assignment** (e.g. `+=`).
+    Assignment {
+        op: Option<ArithOp>,
+    },
+    /// `<-`
+    Send,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LogicOp {
+    /// `and`, `&&` or `∧`
+    And,
+    /// `or`, `||` or `∨`
+    Or,
+    /// `∴` (i.e. the left operand implies the right one)
+    Therefore,
+    /// `∵` (i.e. the right operand implies the left one)
+    Because,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CmpOp {
+    /// `==` (or `!=`/`≠` if `negated`)
+    Eq { negated: bool },
+    /// `<`, `<=`/`≤`, `>` or `>=`/`≥`
+    Ord { ordering: Ordering, strict: bool },
+    /// `is` (or `isnt` if `negated`)
+    Is { negated: bool },
+    /// `⊂` or `⊆` (or `⊄` if `negated`)
+    Subset { strict: bool, negated: bool },
+    /// `⊃` or `⊇` (or `⊅` if `negated`)
+    Superset { strict: bool, negated: bool },
+    /// `≈`
+    Approx,
+    /// `≡`
+    Identical,
+    /// `∝`
+    Proportional,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SetOp {
+    /// `∩`
+    Intersection,
+    /// `∪`
+    Union,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Ordering {
+    Less,
+    Greater,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ArithOp {
+    /// `+`
+    Add,
+    /// `*`
+    Mul,
+    /// `-`
+    Sub,
+    /// `/`
+    Div,
+    /// `%`
+    Rem,
+    /// `**`
+    Pow,
+    /// `<<`
+    Shl,
+    /// `>>`
+    Shr,
+    /// `^`
+    BitXor,
+    /// `|`
+    BitOr,
+    /// `&`
+    BitAnd,
+}
+
+impl fmt::Display for LogicOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let res = match self {
+            LogicOp::And => "and",
+            LogicOp::Or => "or",
+            LogicOp::Therefore => "∴",
+            LogicOp::Because => "∵",
+        };
+        f.write_str(res)
+    }
+}
+
+impl fmt::Display for ArithOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let res = match self {
+            ArithOp::Add => "+",
+            ArithOp::Mul => "*",
+            ArithOp::Sub => "-",
+            ArithOp::Div => "/",
+            ArithOp::Rem => "%",
+            ArithOp::Pow => "**",
+            ArithOp::Shl => "<<",
+            ArithOp::Shr => ">>",
+            ArithOp::BitXor => "^",
+            ArithOp::BitOr => "|",
+            ArithOp::BitAnd => "&",
+        };
+        f.write_str(res)
+    }
+}
+
+impl fmt::Display for CmpOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let res = match self {
+            CmpOp::Eq { negated: false } => "==",
+            CmpOp::Eq { negated: true } => "!=",
+            CmpOp::Ord { ordering: Ordering::Less, strict: false } => "<=",
+            CmpOp::Ord { ordering: Ordering::Less, strict: true } => "<",
+            CmpOp::Ord { ordering: Ordering::Greater, strict: false } => ">=",
+            CmpOp::Ord { ordering: Ordering::Greater, strict: true } => ">",
+            CmpOp::Is { negated: false } => "is",
+            CmpOp::Is { negated: true } => "isnt",
+            CmpOp::Subset { strict: true, negated: false } => "⊂",
+            CmpOp::Subset { strict: true, negated: true } => "⊄",
+            CmpOp::Subset { strict: false, negated: false } => "⊆",
+            CmpOp::Subset { strict: false, negated: true } => "⊈",
+            CmpOp::Superset { strict: true, negated: false } => "⊃",
+            CmpOp::Superset { strict: true, negated: true } => "⊅",
+            CmpOp::Superset { strict: false, negated: false } => "⊇",
+            CmpOp::Superset { strict: false, negated: true } => "⊉",
+            CmpOp::Approx => "≈",
+            CmpOp::Identical => "≡",
+            CmpOp::Proportional => "∝",
+        };
+        f.write_str(res)
+    }
+}
+
+impl fmt::Display for SetOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let res = match self {
+            SetOp::Intersection => "∩",
+            SetOp::Union => "∪",
+        };
+        f.write_str(res)
+    }
+}
+
+impl fmt::Display for BinaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryOp::LogicOp(op) => fmt::Display::fmt(op, f),
+            BinaryOp::ArithOp(op) => fmt::Display::fmt(op, f),
+            BinaryOp::CmpOp(op) => fmt::Display::fmt(op, f),
+            BinaryOp::SetOp(op) => fmt::Display::fmt(op, f),
+            BinaryOp::Assignment { op } => {
+                if let Some(op) = op {
+                    fmt::Display::fmt(op, f)?;
+                }
+                f.write_str("=")
+            }
+            BinaryOp::Send => f.write_str("<-"),
+        }
+    }
+}
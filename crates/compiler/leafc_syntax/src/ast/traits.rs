@@ -31,21 +31,21 @@ pub trait HasVisibility: AstNode {
 
 // ---
 
-// pub trait HasLoopBody: AstNode {
-//     fn loop_body(&self) -> Option<ast::BlockExpr> {
-//         support::child(self.syntax())
-//     }
-
-//     fn label(&self) -> Option<ast::Label> {
-//         support::child(self.syntax())
-//     }
-// }
-
-// pub trait HasArgList: AstNode {
-//     fn arg_list(&self) -> Option<ast::ArgList> {
-//         support::child(self.syntax())
-//     }
-// }
+pub trait HasLoopBody: AstNode {
+    fn loop_body(&self) -> Option<ast::BlockExpr> {
+        support::child(self.syntax())
+    }
+
+    fn label(&self) -> Option<ast::Label> {
+        support::child(self.syntax())
+    }
+}
+
+pub trait HasArgList: AstNode {
+    fn arg_list(&self) -> Option<ast::ArgList> {
+        support::child(self.syntax())
+    }
+}
 
 pub trait HasModuleItem: AstNode {
     fn items(&self) -> AstChildren<ast::Item> {
@@ -53,25 +53,25 @@ pub trait HasModuleItem: AstNode {
     }
 }
 
-// pub trait HasGenericParams: AstNode {
-//     fn generic_param_list(&self) -> Option<ast::GenericParamList> {
-//         support::child(self.syntax())
-//     }
-
-//     fn where_clause(&self) -> Option<ast::WhereClause> {
-//         support::child(self.syntax())
-//     }
-// }
-
-// pub trait HasTypeBounds: AstNode {
-//     fn type_bound_list(&self) -> Option<ast::TypeBoundList> {
-//         support::child(self.syntax())
-//     }
-
-//     fn colon_token(&self) -> Option<SyntaxToken> {
-//         support::token(self.syntax(), T![:])
-//     }
-// }
+pub trait HasGenericParams: AstNode {
+    fn generic_param_list(&self) -> Option<ast::GenericParamList> {
+        support::child(self.syntax())
+    }
+
+    fn where_clause(&self) -> Option<ast::WhereClause> {
+        support::child(self.syntax())
+    }
+}
+
+pub trait HasTypeBounds: AstNode {
+    fn type_bound_list(&self) -> Option<ast::TypeBoundList> {
+        support::child(self.syntax())
+    }
+
+    fn colon_token(&self) -> Option<SyntaxToken> {
+        support::token(self.syntax(), T![:])
+    }
+}
 
 // ----
 
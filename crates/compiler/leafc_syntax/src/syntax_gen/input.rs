@@ -75,10 +75,31 @@ pub(crate) const KINDS_SRC: SyntaxKinds<'_> = SyntaxKinds {
         (")", "R_PAREN"),
         ("{", "L_BRACE"),
         ("}", "R_BRACE"),
-        // ("⁽", "L_PAREN_SUPERSCRIPT"),
-        // ("⁾", "R_PAREN_SUPERSCRIPT"),
+        ("⁽", "L_PAREN_SUPERSCRIPT"),
+        ("⁾", "R_PAREN_SUPERSCRIPT"),
         ("<-", "L_ARROW"),
         ("**", "DOUBLE_STAR"),
+        ("√", "SQRT"),
+        ("∛", "CBRT"),
+        ("∜", "FOURTH_ROOT"),
+        ("∩", "INTERSECTION"),
+        ("∪", "UNION"),
+        ("⊂", "SUBSET"),
+        ("⊃", "SUPERSET"),
+        ("⊄", "NOT_SUBSET"),
+        ("⊅", "NOT_SUPERSET"),
+        ("⊆", "SUBSET_EQ"),
+        ("⊇", "SUPERSET_EQ"),
+        ("≈", "APPROX_EQ"),
+        ("≡", "IDENTICAL"),
+        ("∝", "PROPORTIONAL"),
+        ("∠", "ANGLE"),
+        ("∫", "INTEGRAL"),
+        ("∮", "CONTOUR_INTEGRAL"),
+        ("∯", "SURFACE_INTEGRAL"),
+        ("∰", "VOLUME_INTEGRAL"),
+        ("∴", "THEREFORE"),
+        ("∵", "BECAUSE"),
     ],
     keywords:            &[
         "abstract",
@@ -90,12 +111,16 @@ pub(crate) const KINDS_SRC: SyntaxKinds<'_> = SyntaxKinds {
         "and",
         "as",
         "break",
+        "case",
         "const",
         "continue",
+        "default",
+        "defer",
         "do",
         "dyn",
         "else",
         "enum",
+        "fallthrough",
         "false",
         "fn",
         "for",
@@ -103,6 +128,7 @@ pub(crate) const KINDS_SRC: SyntaxKinds<'_> = SyntaxKinds {
         "impl",
         "import",
         "in",
+        "isnt",
         "let",
         "loop",
         "match",
@@ -132,7 +158,27 @@ pub(crate) const KINDS_SRC: SyntaxKinds<'_> = SyntaxKinds {
     // contextual_keywords: &["auto", "default", "existential", "union", "raw", "macro_rules",
     // "yeet"], // TODO: update this
     contextual_keywords: &[], // TODO: update this
-    literals:            &["RUNE", "STRING", "RAW_STRING", "INTEGER", "FLOAT", "LIFETIME"],
+    literals:            &[
+        "RUNE",
+        "STRING",
+        "RAW_STRING",
+        "FORMAT_STRING_START",
+        "STRING_FRAGMENT",
+        "FORMAT_STRING_END",
+        "INTEGER",
+        "FLOAT",
+        "LIFETIME",
+        "INTEGER_SUP",
+        "FLOAT_SUP",
+        "PI",
+        "EULER",
+        "PHI",
+        "TAU",
+        "CATALAN",
+        "EULERGAMMA",
+        "INF",
+        "NAN",
+    ],
     tokens:              &[
         "WHITESPACE",
         "ERROR",
@@ -144,88 +190,92 @@ pub(crate) const KINDS_SRC: SyntaxKinds<'_> = SyntaxKinds {
     ],
     nodes:               &[
         "SOURCE_FILE",
-        // "STRUCT",
+        "STRUCT",
         // "UNION",
-        // "ENUM",
-        // "FN",
-        // "RET_TYPE",
+        "ENUM",
+        "FN",
+        "RET_TYPE",
         // "EXTERN_CRATE",
         "MODULE",
         "USE",
         // "STATIC",
         // "CONST",
-        // "TRAIT",
-        // "IMPL",
-        // "TYPE_ALIAS",
-        // "MACRO_CALL",
+        "TRAIT",
+        "IMPL",
+        "TYPE_ALIAS",
+        "MACRO_CALL",
         // "MACRO_RULES",
         // "MACRO_ARM",
         "TOKEN_TREE",
         // "MACRO_DEF",
         "PAREN_TYPE",
-        // "TUPLE_TYPE",
+        "TUPLE_TYPE",
         // "MACRO_TYPE",
         "NEVER_TYPE",
         "PATH_TYPE",
         // "PTR_TYPE",
-        // "ARRAY_TYPE",
-        // "SLICE_TYPE",
-        // "REF_TYPE",
+        "ARRAY_TYPE",
+        "SLICE_TYPE",
+        "REF_TYPE",
         // "INFER_TYPE",
-        // "FN_PTR_TYPE",
+        "FN_PTR_TYPE",
         // "FOR_TYPE",
-        // "IMPL_TRAIT_TYPE",
-        // "DYN_TRAIT_TYPE",
-        // "OR_PAT",
-        // "PAREN_PAT",
+        "IMPL_TRAIT_TYPE",
+        "DYN_TRAIT_TYPE",
+        "OR_PAT",
+        "PAREN_PAT",
         // "REF_PAT",
         // "BOX_PAT",
-        // "IDENT_PAT",
-        // "WILDCARD_PAT",
-        // "REST_PAT",
-        // "PATH_PAT",
-        // "RECORD_PAT",
-        // "RECORD_PAT_FIELD_LIST",
-        // "RECORD_PAT_FIELD",
-        // "TUPLE_STRUCT_PAT",
-        // "TUPLE_PAT",
+        "IDENT_PAT",
+        "WILDCARD_PAT",
+        "REST_PAT",
+        "PATH_PAT",
+        "RECORD_PAT",
+        "RECORD_PAT_FIELD_LIST",
+        "RECORD_PAT_FIELD",
+        "TUPLE_STRUCT_PAT",
+        "TUPLE_PAT",
         // "SLICE_PAT",
-        // "RANGE_PAT",
-        // "LITERAL_PAT",
+        "RANGE_PAT",
+        "LITERAL_PAT",
         // "MACRO_PAT",
         // "CONST_BLOCK_PAT",
         // // atoms
         // "TUPLE_EXPR",
         // "ARRAY_EXPR",
-        // "PAREN_EXPR",
-        // "PATH_EXPR",
+        "PAREN_EXPR",
+        "PATH_EXPR",
+        "FORMAT_STRING_EXPR",
+        "FORMAT_ARG",
         // "CLOSURE_EXPR",
-        // "IF_EXPR",
-        // "WHILE_EXPR",
-        // "LOOP_EXPR",
-        // "FOR_EXPR",
-        // "CONTINUE_EXPR",
-        // "BREAK_EXPR",
-        // "LABEL",
-        // "BLOCK_EXPR",
-        // "STMT_LIST",
-        // "RETURN_EXPR",
+        "IF_EXPR",
+        "WHILE_EXPR",
+        "LOOP_EXPR",
+        "FOR_EXPR",
+        "CONTINUE_EXPR",
+        "BREAK_EXPR",
+        "LABEL",
+        "BLOCK_EXPR",
+        "STMT_LIST",
+        "RETURN_EXPR",
+        "DEFER_EXPR",
+        "FALLTHROUGH_EXPR",
         // "YIELD_EXPR",
         // "YEET_EXPR",
         // "LET_EXPR",
         // "UNDERSCORE_EXPR",
-        // "MACRO_EXPR",
-        // "MATCH_EXPR",
-        // "MATCH_ARM_LIST",
-        // "MATCH_ARM",
-        // "MATCH_GUARD",
+        "MACRO_EXPR",
+        "MATCH_EXPR",
+        "MATCH_ARM_LIST",
+        "MATCH_ARM",
+        "MATCH_GUARD",
         // "RECORD_EXPR",
         // "RECORD_EXPR_FIELD_LIST",
         // "RECORD_EXPR_FIELD",
         // "BOX_EXPR",
         // // postfix
-        // "CALL_EXPR",
-        // "INDEX_EXPR",
+        "CALL_EXPR",
+        "INDEX_EXPR",
         // "METHOD_CALL_EXPR",
         "FIELD_EXPR",
         // "AWAIT_EXPR",
@@ -233,53 +283,54 @@ pub(crate) const KINDS_SRC: SyntaxKinds<'_> = SyntaxKinds {
         // "CAST_EXPR",
         // // unary
         "REF_EXPR",
-        // "PREFIX_EXPR",
-        // "RANGE_EXPR", // just weird
-        // "BIN_EXPR",
+        "PREFIX_EXPR",
+        "RANGE_EXPR", // just weird
+        "BIN_EXPR",
+        "SUPERSCRIPT_EXPR",
         // "EXTERN_BLOCK",
         // "EXTERN_ITEM_LIST",
-        // "VARIANT",
+        "VARIANT",
         "RECORD_FIELD_LIST",
         "RECORD_FIELD",
         "TUPLE_FIELD_LIST",
         "TUPLE_FIELD",
-        // "VARIANT_LIST",
+        "VARIANT_LIST",
         "ITEM_LIST",
-        // "ASSOC_ITEM_LIST",
+        "ASSOC_ITEM_LIST",
         "ATTR",
         "META",
         "USE_TREE",
         "USE_TREE_LIST",
         "PATH",
         "PATH_SEGMENT",
-        // "LITERAL",
+        "LITERAL",
         "RENAME",
         "VISIBILITY",
-        // "WHERE_CLAUSE",
-        // "WHERE_PRED",
+        "WHERE_CLAUSE",
+        "WHERE_PRED",
         // "ABI",
         "NAME",
         "NAME_REF",
-        // "LET_STMT",
+        "LET_STMT",
         // "LET_ELSE",
         "EXPR_STMT",
-        // "GENERIC_PARAM_LIST",
+        "GENERIC_PARAM_LIST",
         // "GENERIC_PARAM",
-        // "LIFETIME_PARAM",
-        // "TYPE_PARAM",
-        // "CONST_PARAM",
-        // "GENERIC_ARG_LIST",
+        "LIFETIME_PARAM",
+        "TYPE_PARAM",
+        "CONST_PARAM",
+        "GENERIC_ARG_LIST",
         // "LIFETIME",
-        // "LIFETIME_ARG",
-        // "TYPE_ARG",
-        // "ASSOC_TYPE_ARG",
-        // "CONST_ARG",
-        // "PARAM_LIST",
-        // "PARAM",
-        // "SELF_PARAM",
-        // "ARG_LIST",
-        // "TYPE_BOUND",
-        // "TYPE_BOUND_LIST",
+        "LIFETIME_ARG",
+        "TYPE_ARG",
+        "ASSOC_TYPE_ARG",
+        "CONST_ARG",
+        "PARAM_LIST",
+        "PARAM",
+        "SELF_PARAM",
+        "ARG_LIST",
+        "TYPE_BOUND",
+        "TYPE_BOUND_LIST",
         // // macro related
         // "MACRO_ITEMS",
         // "MACRO_STMTS",
@@ -83,6 +83,13 @@
 //! construction" approach while also having the infrastructure for really nice
 //! APIs for the syntax tree system.
 //!
+//! As a first step, `leafc_parser` has a **second parser** generated by LALRPOP
+//! from `leafc_parser/src/grammar/lalrpop/leaf.lalrpop`, which **rejects** any
+//! ambiguity (i.e. any conflict in the LR(1) tables) at build time, and whose
+//! trees are checked to be **identical** to those of the recursive descent
+//! parser by a differential test suite. That grammar is still written by hand
+//! rather than generated from `leaf.ungram`.
+//!
 //! ------------------
 //!
 //!  This includes both the
@@ -441,9 +441,9 @@ fn generate_syntax_kinds(grammar: SyntaxKinds<'_>) -> String {
         .unzip();
 
     let punctuation_values = grammar.punct.iter().map(|(token, _name)| {
-        // TODO: change to this: {}[]()⁽⁾
-        // if "{}[]()".contains(token) {
-        if "{}[]()".contains(token) {
+        // Non-ASCII punctuation (e.g. `⁽` or `√`) isn't a Rust token, so it's
+        // quoted like the delimiters.
+        if "{}[]()".contains(token) || !token.is_ascii() {
             let c = token.chars().next().unwrap();
             quote! { #c }
         } else {
@@ -559,9 +559,11 @@ fn generate_syntax_kinds(grammar: SyntaxKinds<'_>) -> String {
         impl From<TokenKind> for SyntaxKind {
             fn from(kind: TokenKind) -> SyntaxKind {
                 match kind {
+                    TokenKind::EOF => EOF,
+                    #(TokenKind::#literals => #literals,)*
+                    #(TokenKind::#tokens => #tokens,)*
                     #(TokenKind::#punctuation => #punctuation,)*
                     #(TokenKind::#all_keywords => #all_keywords,)*
-                    _ => unreachable!(),
                 }
             }
         }
@@ -571,8 +573,9 @@ fn generate_syntax_kinds(grammar: SyntaxKinds<'_>) -> String {
             #([#punctuation_values] => { $crate::SyntaxKind::#punctuation };)*
             #([#all_keywords_idents] => { $crate::SyntaxKind::#all_keywords };)*
             [ident] => { $crate::SyntaxKind::IDENTIFIER };
-            ['⁽'] => { $crate::SyntaxKind::L_PAREN_SUPERSCRIPT };
-            ['⁾'] => { $crate::SyntaxKind::R_PAREN_SUPERSCRIPT };
+            [lifetime] => { $crate::SyntaxKind::LIFETIME };
+            [format_string_start] => { $crate::SyntaxKind::FORMAT_STRING_START };
+            [format_string_end] => { $crate::SyntaxKind::FORMAT_STRING_END };
         }
         pub use T;
     };
@@ -655,10 +658,13 @@ impl Field {
                     "')'" => "r_paren",
                     "'['" => "l_brack",
                     "']'" => "r_brack",
+                    "'⁽'" => "l_paren_superscript",
+                    "'⁾'" => "r_paren_superscript",
                     "<" => "l_angle",
                     ">" => "r_angle",
                     "=" => "eq",
                     "!" => "excl",
+                    "-" => "minus",
                     "*" => "star",
                     "&" => "amp",
                     "_" => "underscore",
@@ -669,6 +675,7 @@ impl Field {
                     "=>" => "fat_arrow",
                     "@" => "at",
                     ":" => "colon",
+                    ":=" => "define",
                     "::" => "coloncolon",
                     "#" => "pound",
                     "?" => "question_mark",
@@ -698,7 +705,8 @@ impl Field {
 
 fn lower(grammar: &Grammar) -> AstSrc {
     let mut res = AstSrc {
-        tokens: "Whitespace Comment Rune String RawString Integer Float Lifetime Identifier"
+        tokens: "Whitespace Comment Rune String RawString Integer Float IntegerSup FloatSup \
+                 Lifetime Identifier"
             // tokens: "Whitespace Comment String ByteString IntNumber FloatNumber Char Byte Ident"
             .split_ascii_whitespace()
             .map(|it| it.to_string())
@@ -763,7 +771,7 @@ fn lower_rule(acc: &mut Vec<Field>, grammar: &Grammar, label: Option<&String>, r
             assert!(label.is_none());
             let mut name = grammar[*token].name.clone();
             if name != "int_number" && name != "string" {
-                if "[]{}()⁽⁾".contains(&name) {
+                if "[]{}()".contains(&name) || !name.is_ascii() {
                     name = format!("'{name}'");
                 }
                 let field = Field::Token(name);
@@ -18,3 +18,12 @@ impl From<SyntaxKind> for u16 {
         kind as u16
     }
 }
+
+impl SyntaxKind {
+    /// Returns `true` if the syntax kind is **trivia** (i.e. whitespace or
+    /// comments), which is insignificant to the parser.
+    #[inline]
+    pub fn is_trivia(self) -> bool {
+        matches!(self, SyntaxKind::WHITESPACE | SyntaxKind::COMMENT | SyntaxKind::DOC_COMMENT)
+    }
+}
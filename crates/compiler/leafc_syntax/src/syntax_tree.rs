@@ -365,6 +365,16 @@ impl SyntaxTreeBuilder {
     pub fn finish_node(&mut self) {
         self.builder.finish_node()
     }
+
+    /// Completes the tree building process, returning the **root** green node
+    /// of the syntax tree.
+    ///
+    /// **NOTE**: Every call to `start_node` must have been matched by a call to
+    /// `finish_node` before calling this.
+    #[inline]
+    pub fn finish(self) -> rowan::GreenNode {
+        self.builder.finish()
+    }
 }
 
 #[cfg(test)]
@@ -82,10 +82,8 @@ pub use {
         Span,
         Spanned,
     },
-    //  regex_gen::{
-    //     RegexGen,
-    //     RegexGenBuilder,
-    // }, string::{
+    regex_gen::RegexGen,
+    // string::{
     //     StringExt,
     //     StringExtMut,
     // }, time::{
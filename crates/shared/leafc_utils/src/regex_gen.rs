@@ -1,24 +1,75 @@
-// // use regex_generate::{Generator, DEFAULT_MAX_REPEAT};
-// extern crate rand;
-// extern crate regex_generate;
-
-// #[test]
-// fn test_regex_gen() {
-//     //     let mut gen = Generator::new(
-//     //         r"(?x)
-//     // (?P<year>[0-9]{4})  # the year
-//     // -
-//     // (?P<month>[0-9]{2}) # the month
-//     // -
-//     // (?P<day>[0-9]{2})   # the day
-//     // ",
-//     //         rand::thread_rng(),
-//     //         DEFAULT_MAX_REPEAT,
-//     //     )
-//     //     .unwrap();
-//     //     let mut buffer = vec![];
-//     //     gen.generate(&mut buffer).unwrap();
-//     //     let output = String::from_utf8(buffer).unwrap();
-
-//     //     println!("Random Date: {}", output);
-// }
+use std::fmt;
+
+use rand::Rng;
+
+/// The default **maximum** number of times an unbounded repetition (e.g. `*`
+/// or `+`) is repeated when generating a string.
+pub const DEFAULT_MAX_REPEAT: u32 = 8;
+
+/// A **generator** of random strings which match a given regular expression.
+///
+/// This is useful for **fuzzing** (e.g. generating random lexemes for each
+/// kind of token from the same regular expressions the lexer is defined by).
+///
+/// # Example:
+///
+/// ```rust
+/// use leafc_utils::regex_gen::RegexGen;
+/// use rand::SeedableRng;
+///
+/// let gen = RegexGen::new("[0-9]{4}-[0-9]{2}-[0-9]{2}").unwrap();
+/// let date = gen.generate(&mut rand::rngs::StdRng::seed_from_u64(0));
+///
+/// assert_eq!(date.len(), 10);
+/// assert_eq!(&date[4..5], "-");
+/// ```
+#[derive(Clone)]
+pub struct RegexGen {
+    /// The **source** of the regular expression.
+    pattern: String,
+    /// The compiled **distribution** of matching strings.
+    regex:   rand_regex::Regex,
+}
+
+impl fmt::Debug for RegexGen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RegexGen").field("pattern", &self.pattern).finish_non_exhaustive()
+    }
+}
+
+impl RegexGen {
+    /// Creates a new [`RegexGen`] for the given `pattern`, repeating unbounded
+    /// repetitions at most [`DEFAULT_MAX_REPEAT`] times.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `pattern` is not a valid regular expression, or
+    /// uses a feature which can't be generated from (e.g. anchors). The error
+    /// is boxed, as a syntax error in the `pattern` is rather large.
+    pub fn new(pattern: &str) -> Result<Self, Box<rand_regex::Error>> {
+        Self::with_max_repeat(pattern, DEFAULT_MAX_REPEAT)
+    }
+
+    /// Creates a new [`RegexGen`] for the given `pattern`, repeating unbounded
+    /// repetitions at most `max_repeat` times.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `pattern` is not a valid regular expression, or
+    /// uses a feature which can't be generated from (e.g. anchors).
+    pub fn with_max_repeat(pattern: &str, max_repeat: u32) -> Result<Self, Box<rand_regex::Error>> {
+        let regex = rand_regex::Regex::compile(pattern, max_repeat).map_err(Box::new)?;
+        Ok(Self { pattern: pattern.to_string(), regex })
+    }
+
+    /// The **source** of the regular expression.
+    #[must_use]
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Generates a random string matching the regular expression.
+    pub fn generate<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        rng.sample(&self.regex)
+    }
+}